@@ -1,7 +1,15 @@
 import { describe, bench as vitestBench } from "vitest";
 import { fakerKO as faker } from "@faker-js/faker";
 
-import { Hangul } from "../../node/index";
+import {
+	Hangul,
+	disassembleBatch,
+	disassembleBatchAsync,
+	getChoseongBatch,
+	getChoseongBatchAsync,
+	normalizeBatch,
+	normalizeBatchAsync,
+} from "../../node/index";
 import * as esHangul from "es-hangul";
 
 const benchOption = {
@@ -9,7 +17,16 @@ const benchOption = {
 	time: 0,
 };
 
-const bench = (name: string, fn: () => void, options = benchOption) =>
+const batchBenchOption = {
+	iterations: 100,
+	time: 0,
+};
+
+const bench = (
+	name: string,
+	fn: () => void | Promise<void>,
+	options = benchOption,
+) =>
 	vitestBench(name, fn, options);
 
 describe("한글 이름 분석", async () => {
@@ -83,3 +100,85 @@ describe("한글 긴 문단 분석", async () => {
 		rustyHangul.getChoseong();
 	});
 });
+
+describe("한글 이름 배치 분석", async () => {
+	const texts = faker.helpers.multiple(() => faker.person.fullName(), {
+		count: 10000,
+	});
+
+	bench(
+		"es-hangul",
+		() => {
+			for (const text of texts) {
+				esHangul.disassemble(text);
+				esHangul.getChoseong(text);
+			}
+		},
+		batchBenchOption,
+	);
+
+	bench(
+		"rusty-hangul/node",
+		() => {
+			for (const text of texts) {
+				const rustyHangul = new Hangul(text);
+				rustyHangul.disassemble();
+				rustyHangul.getChoseong();
+			}
+		},
+		batchBenchOption,
+	);
+
+	bench(
+		"rusty-hangul/node (batch)",
+		() => {
+			disassembleBatch(texts);
+			getChoseongBatch(texts);
+		},
+		batchBenchOption,
+	);
+
+	bench(
+		"rusty-hangul/node (batch async)",
+		async () => {
+			await Promise.all([
+				disassembleBatchAsync(texts),
+				getChoseongBatchAsync(texts),
+			]);
+		},
+		batchBenchOption,
+	);
+});
+
+describe("한글 이름 배치 정규화", async () => {
+	const texts = faker.helpers.multiple(
+		() => faker.person.fullName().normalize("NFD"),
+		{ count: 10000 },
+	);
+
+	bench(
+		"String.prototype.normalize",
+		() => {
+			for (const text of texts) {
+				text.normalize("NFC");
+			}
+		},
+		batchBenchOption,
+	);
+
+	bench(
+		"rusty-hangul/node (batch)",
+		() => {
+			normalizeBatch(texts, "NFC");
+		},
+		batchBenchOption,
+	);
+
+	bench(
+		"rusty-hangul/node (batch async)",
+		async () => {
+			await normalizeBatchAsync(texts, "NFC");
+		},
+		batchBenchOption,
+	);
+});
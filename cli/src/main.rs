@@ -0,0 +1,285 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use hangul::Hangul;
+
+#[derive(Parser)]
+#[command(name = "hangul", version, about = "한글 문자열을 줄 단위로 변환합니다")]
+struct Cli {
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// 한글을 자모로 분해 (안녕 → ㅇㅏㄴㄴㅕㅇ)
+  Disassemble(LineOptions),
+  /// 초성 추출 (안녕하세요 → ㅇㄴㅎㅅㅇ)
+  Choseong(LineOptions),
+  /// 한글 음절을 완성형(NFC)으로 정규화
+  Nfc(LineOptions),
+  /// 한글 음절을 조합형 자모(NFD)로 정규화
+  Nfd(LineOptions),
+  /// 자모를 음절로 조립 (ㅇㅏㄴㄴㅕㅇ → 안녕)
+  Assemble(LineOptions),
+  /// 국어의 로마자 표기법으로 변환 (한국어 → hangugeo)
+  Romanize(LineOptions),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operation {
+  Disassemble,
+  Choseong,
+  Nfc,
+  Nfd,
+  Assemble,
+  Romanize,
+}
+
+#[derive(Args, Default)]
+struct LineOptions {
+  /// 입력 파일, 생략하거나 `-`이면 표준 입력
+  files: Vec<PathBuf>,
+
+  /// 결과를 기록할 파일, 생략하면 표준 출력
+  #[arg(short, long)]
+  output: Option<PathBuf>,
+
+  /// 각 줄에서 N번째 필드(1부터 시작)만 변환
+  #[arg(short, long)]
+  field: Option<NonZeroUsize>,
+
+  /// 필드 구분자, 빈 문자열은 허용하지 않음
+  #[arg(short, long, default_value = "\t", value_parser = parse_delimiter)]
+  delimiter: String,
+
+  /// 줄마다 {"input": ..., "output": ...} JSON으로 출력, `--field`와 함께 쓰면 줄 전체를 담음
+  #[arg(long)]
+  json: bool,
+}
+
+impl Command {
+  fn into_parts(self) -> (Operation, LineOptions) {
+    match self {
+      Command::Disassemble(options) => (Operation::Disassemble, options),
+      Command::Choseong(options) => (Operation::Choseong, options),
+      Command::Nfc(options) => (Operation::Nfc, options),
+      Command::Nfd(options) => (Operation::Nfd, options),
+      Command::Assemble(options) => (Operation::Assemble, options),
+      Command::Romanize(options) => (Operation::Romanize, options),
+    }
+  }
+}
+
+impl Operation {
+  fn apply(self, text: &str) -> String {
+    match self {
+      Operation::Disassemble => Hangul::new(text).disassemble(),
+      Operation::Choseong => Hangul::new(text).get_choseong(),
+      Operation::Nfc => Hangul::new(text).to_nfc(),
+      Operation::Nfd => Hangul::new(text).to_nfd(),
+      Operation::Assemble => hangul::assemble(text),
+      Operation::Romanize => hangul::romanize(text),
+    }
+  }
+}
+
+fn main() -> ExitCode {
+  let (operation, options) = Cli::parse().command.into_parts();
+
+  match run(operation, &options) {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+    Err(error) => {
+      eprintln!("hangul: {}", error);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+fn run(operation: Operation, options: &LineOptions) -> io::Result<()> {
+  let mut writer: BufWriter<Box<dyn Write>> = BufWriter::new(match &options.output {
+    Some(path) => Box::new(File::create(path)?),
+    None => Box::new(io::stdout().lock()),
+  });
+
+  if options.files.is_empty() {
+    process(operation, options, io::stdin().lock(), &mut writer)?;
+  }
+
+  for path in &options.files {
+    if path == Path::new("-") {
+      process(operation, options, io::stdin().lock(), &mut writer)?;
+    } else {
+      let file = File::open(path)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))?;
+      process(operation, options, BufReader::new(file), &mut writer)?;
+    }
+  }
+
+  writer.flush()
+}
+
+fn process<R: BufRead, W: Write>(
+  operation: Operation,
+  options: &LineOptions,
+  reader: R,
+  writer: &mut W,
+) -> io::Result<()> {
+  for line in reader.lines() {
+    writeln!(writer, "{}", convert_line(operation, options, &line?))?;
+  }
+
+  Ok(())
+}
+
+fn convert_line(operation: Operation, options: &LineOptions, line: &str) -> String {
+  let output = match options.field {
+    Some(field) => convert_field(operation, options, field, line),
+    None => operation.apply(line),
+  };
+
+  format_output(options, line, output)
+}
+
+// N번째 필드만 변환하고 나머지 필드는 그대로 둠, 필드가 없는 줄은 그대로 출력
+fn convert_field(
+  operation: Operation,
+  options: &LineOptions,
+  field: NonZeroUsize,
+  line: &str,
+) -> String {
+  let mut fields: Vec<String> = line
+    .split(options.delimiter.as_str())
+    .map(String::from)
+    .collect();
+
+  let Some(target) = fields.get_mut(field.get() - 1) else {
+    return line.to_string();
+  };
+
+  *target = operation.apply(target);
+  fields.join(&options.delimiter)
+}
+
+fn parse_delimiter(value: &str) -> Result<String, String> {
+  if value.is_empty() {
+    Err("구분자는 비어 있을 수 없습니다".to_string())
+  } else {
+    Ok(value.to_string())
+  }
+}
+
+fn format_output(options: &LineOptions, input: &str, output: String) -> String {
+  if options.json {
+    serde_json::json!({ "input": input, "output": output }).to_string()
+  } else {
+    output
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options() -> LineOptions {
+    LineOptions {
+      delimiter: "\t".to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_operations() {
+    assert_eq!(Operation::Disassemble.apply("안녕"), "ㅇㅏㄴㄴㅕㅇ");
+    assert_eq!(Operation::Choseong.apply("안녕하세요"), "ㅇㄴㅎㅅㅇ");
+    assert_eq!(
+      Operation::Nfd.apply("한"),
+      "\u{1112}\u{1161}\u{11AB}".to_string()
+    );
+    assert_eq!(Operation::Nfc.apply("\u{1112}\u{1161}\u{11AB}"), "한");
+    assert_eq!(Operation::Assemble.apply("ㅇㅏㄴㄴㅕㅇ"), "안녕");
+    assert_eq!(Operation::Romanize.apply("한국어"), "hangugeo");
+  }
+
+  #[test]
+  fn test_convert_line() {
+    let options = options();
+    assert_eq!(
+      convert_line(Operation::Choseong, &options, "Hello 안녕!"),
+      "Hello ㅇㄴ!"
+    );
+
+    let json = LineOptions {
+      json: true,
+      ..options
+    };
+    assert_eq!(
+      convert_line(Operation::Choseong, &json, "안녕"),
+      r#"{"input":"안녕","output":"ㅇㄴ"}"#
+    );
+  }
+
+  #[test]
+  fn test_convert_field() {
+    let options = LineOptions {
+      field: NonZeroUsize::new(2),
+      delimiter: ",".to_string(),
+      ..Default::default()
+    };
+    assert_eq!(
+      convert_line(Operation::Choseong, &options, "1,홍길동,서울"),
+      "1,ㅎㄱㄷ,서울"
+    );
+    assert_eq!(convert_line(Operation::Choseong, &options, "1"), "1");
+
+    let json = LineOptions {
+      json: true,
+      ..options
+    };
+    assert_eq!(
+      convert_line(Operation::Disassemble, &json, "1,한,서울"),
+      r#"{"input":"1,한,서울","output":"1,ㅎㅏㄴ,서울"}"#
+    );
+    assert_eq!(
+      convert_line(Operation::Disassemble, &json, "1"),
+      r#"{"input":"1","output":"1"}"#
+    );
+  }
+
+  #[test]
+  fn test_process() {
+    let mut output = Vec::new();
+    process(
+      Operation::Disassemble,
+      &options(),
+      "안녕\n한글\n".as_bytes(),
+      &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+      String::from_utf8(output).unwrap(),
+      "ㅇㅏㄴㄴㅕㅇ\nㅎㅏㄴㄱㅡㄹ\n"
+    );
+  }
+
+  #[test]
+  fn test_cli_parsing() {
+    let cli = Cli::try_parse_from([
+      "hangul", "choseong", "--json", "-f", "2", "-d", ",", "a.csv",
+    ]);
+    let (operation, options) = cli.unwrap().command.into_parts();
+
+    assert_eq!(operation, Operation::Choseong);
+    assert!(options.json);
+    assert_eq!(options.field, NonZeroUsize::new(2));
+    assert_eq!(options.delimiter, ",");
+    assert_eq!(options.files, vec![PathBuf::from("a.csv")]);
+
+    assert!(Cli::try_parse_from(["hangul", "choseong", "-f", "2", "-d", ""]).is_err());
+  }
+}
@@ -0,0 +1,197 @@
+use alloc::string::String;
+
+use crate::choseong::Choseong;
+use crate::halfwidth::halfwidth_to_compatibility;
+use crate::hangul_letter::HangulLetter;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+
+// 조립 중인 글자 상태
+#[derive(Clone, Copy)]
+enum State {
+  Empty,
+  Choseong(Choseong),
+  Jungseong(Jungseong),
+  Letter(HangulLetter),
+}
+
+// 자모(호환형·조합형)와 완성형 음절이 섞인 문자열을 음절로 조립 ("ㅇㅏㄴㄴㅕㅇ" → "안녕")
+// 반각 자모(ﾡ)는 호환형 자모로 취급하고, 한글 자모가 아닌 문자는 그대로 유지하며 조립 중인 글자를 끊음
+pub fn assemble(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut state = State::Empty;
+
+  for ch in text.chars() {
+    let unicode = halfwidth_to_compatibility(ch).map_or(ch as u32, |compat| compat as u32);
+
+    if let Some(letter) = HangulLetter::parse_from_char(ch) {
+      flush(state, &mut result);
+      state = State::Letter(letter);
+    } else if let Some(jungseong) = Jungseong::try_new(unicode) {
+      state = push_jungseong(state, jungseong, &mut result);
+    } else if let Some(consonant) = consonant(unicode) {
+      state = push_consonant(state, consonant, &mut result);
+    } else {
+      flush(state, &mut result);
+      state = State::Empty;
+      result.push(ch);
+    }
+  }
+
+  flush(state, &mut result);
+  result
+}
+
+// 자음을 호환형 유니코드로 통일
+fn consonant(unicode: u32) -> Option<u32> {
+  if Jongseong::is_conjoining_jongseong(unicode) {
+    return Some(Jongseong::new(unicode).compatibility_unicode());
+  }
+
+  Choseong::try_new(unicode)
+    .map(|choseong| choseong.compatibility_unicode())
+    .or_else(|| Jongseong::try_new(unicode).map(|jongseong| jongseong.compatibility_unicode()))
+}
+
+fn push_consonant(state: State, consonant: u32, result: &mut String) -> State {
+  if let State::Letter(letter) = state {
+    let jongseong = match letter.jongseong {
+      None => Jongseong::try_new(consonant),
+      Some(jongseong) => Jongseong::try_new(consonant).and_then(|next| jongseong.combine(&next)),
+    };
+
+    if let Some(jongseong) = jongseong {
+      return State::Letter(HangulLetter {
+        jongseong: Some(jongseong),
+        ..letter
+      });
+    }
+  }
+
+  flush(state, result);
+  match Choseong::try_new(consonant) {
+    Some(choseong) => State::Choseong(choseong),
+    // 초성이 될 수 없는 겹자음(ㄳ 등)은 그대로 출력
+    None => {
+      result.push(unsafe { core::char::from_u32_unchecked(consonant) });
+      State::Empty
+    }
+  }
+}
+
+fn push_jungseong(state: State, jungseong: Jungseong, result: &mut String) -> State {
+  match state {
+    State::Choseong(choseong) => State::Letter(HangulLetter {
+      choseong,
+      jungseong,
+      jongseong: None,
+    }),
+    State::Jungseong(previous) => match previous.combine(&jungseong) {
+      Some(combined) => State::Jungseong(combined),
+      None => {
+        flush(state, result);
+        State::Jungseong(jungseong)
+      }
+    },
+    State::Letter(letter) => match letter.jongseong {
+      // 종성을 다음 음절의 초성으로 옮김 (겹받침은 뒤 자음만 옮김)
+      Some(jongseong) => {
+        let (remaining, moved) = match jongseong.split() {
+          (first, Some(second)) => (Some(first), second),
+          (single, None) => (None, single),
+        };
+
+        flush(
+          State::Letter(HangulLetter {
+            jongseong: remaining,
+            ..letter
+          }),
+          result,
+        );
+
+        State::Letter(HangulLetter {
+          choseong: Choseong::new(moved.compatibility_unicode()),
+          jungseong,
+          jongseong: None,
+        })
+      }
+      None => match letter.jungseong.combine(&jungseong) {
+        Some(combined) => State::Letter(HangulLetter {
+          jungseong: combined,
+          ..letter
+        }),
+        None => {
+          flush(state, result);
+          State::Jungseong(jungseong)
+        }
+      },
+    },
+    State::Empty => State::Jungseong(jungseong),
+  }
+}
+
+fn flush(state: State, result: &mut String) {
+  match state {
+    State::Empty => {}
+    State::Choseong(choseong) => result.push(choseong.compatibility_value()),
+    State::Jungseong(jungseong) => result.push(jungseong.compatibility_value()),
+    State::Letter(letter) => result.push(letter.value()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::hangul::Hangul;
+
+  #[test]
+  fn test_assemble() {
+    assert_eq!(assemble("ㅇㅏㄴㄴㅕㅇ"), "안녕");
+    assert_eq!(assemble("ㅎㅏㄴㄱㅡㄹ"), "한글");
+    assert_eq!(assemble("ㄱㅏㅂㅅ"), "값");
+    assert_eq!(assemble("ㄷㅏㄹㄱㄱㅗㄱㅣ"), "닭고기");
+    assert_eq!(assemble("ㄱㅗㅏㅇ"), "광");
+    assert_eq!(assemble("ㅇㅡㅣ"), "의");
+  }
+
+  #[test]
+  fn test_assemble_moves_jongseong() {
+    assert_eq!(assemble("ㅇㅏㄴㅏ"), "아나");
+    assert_eq!(assemble("ㄷㅏㄹㄱㅇㅣ"), "닭이");
+    assert_eq!(assemble("ㄷㅏㄹㄱㅣ"), "달기");
+    assert_eq!(assemble("ㅇㅓㅂㅅㅓ"), "업서");
+  }
+
+  #[test]
+  fn test_assemble_with_syllables_and_others() {
+    assert_eq!(assemble("아ㄴ녀ㅇ"), "안녕");
+    assert_eq!(assemble("ㅇㅏㄴ ㄴㅕㅇ!"), "안 녕!");
+    assert_eq!(assemble("Hello ㅎㅏㄴ"), "Hello 한");
+    assert_eq!(assemble("\u{1112}\u{1161}\u{11AB}"), "한");
+  }
+
+  #[test]
+  fn test_assemble_incomplete() {
+    assert_eq!(assemble("ㄱ"), "ㄱ");
+    assert_eq!(assemble("ㅏ"), "ㅏ");
+    assert_eq!(assemble("ㄱㄴ"), "ㄱㄴ");
+    assert_eq!(assemble("ㅏㅏ"), "ㅏㅏ");
+    assert_eq!(assemble("ㄸㅏㄸ"), "따ㄸ");
+    assert_eq!(assemble("ㄳ"), "ㄳ");
+    assert_eq!(assemble(""), "");
+  }
+
+  #[test]
+  fn test_assemble_disassemble_roundtrip() {
+    for text in [
+      "안녕하세요",
+      "닭고기",
+      "값어치",
+      "의사",
+      "꿇어앉다",
+      "괜찮아요",
+    ] {
+      assert_eq!(assemble(&Hangul::new(text).disassemble()), text);
+    }
+  }
+}
@@ -1,3 +1,6 @@
+use crate::halfwidth::halfwidth_to_compatibility;
+use crate::hangul_letter::JamoError;
+
 // 조합형 초성 범위
 const CHOSEONG_BASE: u32 = 0x1100;
 const CHOSEONG_LAST: u32 = 0x1112;
@@ -12,55 +15,105 @@ const COMPATIBILITY_CHOSEONG_MAPPING: [u32; 19] = [
   0x3148, 0x3149, 0x314A, 0x314B, 0x314C, 0x314D, 0x314E,
 ];
 
-#[derive(Debug)]
-pub struct Choseong {
-  pub conjoining_value: char,
-  pub conjoining_unicode: u32,
-  pub compatibility_value: char,
-  pub compatibility_unicode: u32,
-}
+// 자음과 모양이 비슷한 문자와 그 호환형 자음, UTS #39 confusables.txt가 아닌 직접 고른 목록
+// 라틴 문자는 대소문자를 접어 찾으므로 소문자로 적음
+const CONFUSABLE_CHOSEONG: [(char, char); 11] = [
+  ('o', 'ㅇ'),
+  ('0', 'ㅇ'),
+  ('○', 'ㅇ'),
+  ('〇', 'ㅇ'),
+  ('7', 'ㄱ'),
+  ('匚', 'ㄷ'),
+  ('己', 'ㄹ'),
+  ('口', 'ㅁ'),
+  ('ロ', 'ㅁ'),
+  ('人', 'ㅅ'),
+  ('工', 'ㅍ'),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Choseong(u8);
 
 impl Choseong {
+  pub(crate) const NIEUN: Self = Self(2);
+  pub(crate) const RIEUL: Self = Self(5);
+  pub(crate) const IEUNG: Self = Self(11);
+
   pub fn new(unicode: u32) -> Self {
-    if CHOSEONG_BASE <= unicode && unicode <= CHOSEONG_LAST {
-      let offset = unicode - CHOSEONG_BASE;
-      let compatibility_jamo = COMPATIBILITY_CHOSEONG_MAPPING[offset as usize];
-
-      return Self {
-        conjoining_value: unsafe { std::char::from_u32_unchecked(unicode) },
-        conjoining_unicode: unicode,
-        compatibility_value: unsafe { std::char::from_u32_unchecked(compatibility_jamo) },
-        compatibility_unicode: compatibility_jamo,
-      };
+    Self::try_new(unicode).unwrap_or_else(|| panic!("유효한 초성 유니코드가 아닙니다: {}", unicode))
+  }
+
+  pub(crate) fn try_new(unicode: u32) -> Option<Self> {
+    if Self::is_conjoining_choseong(unicode) {
+      return Some(Self((unicode - CHOSEONG_BASE) as u8));
     }
 
-    if COMPAT_CHOSEONG_BASE <= unicode && unicode <= COMPAT_CHOSEONG_LAST {
-      if let Some(position) = COMPATIBILITY_CHOSEONG_MAPPING
+    if Self::is_compatibility_choseong(unicode) {
+      return COMPATIBILITY_CHOSEONG_MAPPING
         .iter()
         .position(|&x| x == unicode)
-      {
-        let conjoining_jamo = CHOSEONG_BASE + position as u32;
-
-        return Self {
-          conjoining_value: unsafe { std::char::from_u32_unchecked(conjoining_jamo) },
-          conjoining_unicode: conjoining_jamo,
-          compatibility_value: unsafe { std::char::from_u32_unchecked(unicode) },
-          compatibility_unicode: unicode,
-        };
-      }
+        .map(|position| Self(position as u8));
     }
 
-    panic!("유효한 초성 유니코드가 아닙니다: {}", unicode);
+    None
+  }
+
+  // 조합형, 호환형, 반각 자모와 모양이 비슷한 문자(o, O → ㅇ, 人 → ㅅ)로 생성
+  pub fn from_confusable(ch: char) -> Option<Self> {
+    let ch = halfwidth_to_compatibility(ch).unwrap_or(ch.to_ascii_lowercase());
+
+    Self::try_new(ch as u32).or_else(|| {
+      CONFUSABLE_CHOSEONG
+        .iter()
+        .find(|&&(confusable, _)| confusable == ch)
+        .and_then(|&(_, jamo)| Self::try_new(jamo as u32))
+    })
+  }
+
+  // 초성 인덱스(0–18)로 생성
+  pub(crate) fn from_index(index: u8) -> Option<Self> {
+    (index < COMPATIBILITY_CHOSEONG_MAPPING.len() as u8).then_some(Self(index))
+  }
+
+  // 초성 인덱스(0–18)
+  pub fn index(&self) -> u8 {
+    self.0
+  }
+
+  pub fn conjoining_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.conjoining_unicode()) }
+  }
+
+  pub fn conjoining_unicode(&self) -> u32 {
+    CHOSEONG_BASE + self.0 as u32
+  }
+
+  pub fn compatibility_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.compatibility_unicode()) }
+  }
+
+  pub fn compatibility_unicode(&self) -> u32 {
+    COMPATIBILITY_CHOSEONG_MAPPING[self.0 as usize]
   }
 
   // 조합형 초성 확인(Conjoining Choseong)
   pub fn is_conjoining_choseong(choseong_code: u32) -> bool {
-    CHOSEONG_BASE <= choseong_code && choseong_code <= CHOSEONG_LAST
+    (CHOSEONG_BASE..=CHOSEONG_LAST).contains(&choseong_code)
   }
 
-  // 호환형 초성 확인(Compatibility Choseong)
+  // 호환형 초성 확인(Compatibility Choseong), 겹받침 자음(ㄳ)은 제외
   pub fn is_compatibility_choseong(unicode: u32) -> bool {
-    COMPAT_CHOSEONG_BASE <= unicode && unicode <= COMPAT_CHOSEONG_LAST
+    (COMPAT_CHOSEONG_BASE..=COMPAT_CHOSEONG_LAST).contains(&unicode)
+      && COMPATIBILITY_CHOSEONG_MAPPING.contains(&unicode)
+  }
+}
+
+// 조합형 또는 호환형 자모로 생성, 초성이 될 수 없으면 (ㄳ, ㅏ) 오류
+impl TryFrom<char> for Choseong {
+  type Error = JamoError;
+
+  fn try_from(ch: char) -> Result<Self, Self::Error> {
+    Self::try_new(ch as u32).ok_or(JamoError::InvalidChoseong(ch))
   }
 }
 
@@ -97,36 +150,38 @@ mod tests {
     ));
     assert!(!Choseong::is_compatibility_choseong(0x314F));
     assert!(!Choseong::is_compatibility_choseong(0xAC00));
+    assert!(!Choseong::is_compatibility_choseong('ㄳ' as u32));
+    assert!(!Choseong::is_compatibility_choseong('ㅀ' as u32));
   }
 
   #[test]
   fn test_choseong_construction_from_conjoining() {
     let choseong = Choseong::new(0x1100);
-    assert_eq!(choseong.conjoining_unicode, 0x1100);
-    assert_eq!(choseong.conjoining_value, 'ᄀ');
-    assert_eq!(choseong.compatibility_unicode, 0x3131);
-    assert_eq!(choseong.compatibility_value, 'ㄱ');
+    assert_eq!(choseong.conjoining_unicode(), 0x1100);
+    assert_eq!(choseong.conjoining_value(), 'ᄀ');
+    assert_eq!(choseong.compatibility_unicode(), 0x3131);
+    assert_eq!(choseong.compatibility_value(), 'ㄱ');
 
     let choseong = Choseong::new(0x1112);
-    assert_eq!(choseong.conjoining_unicode, 0x1112);
-    assert_eq!(choseong.conjoining_value, 'ᄒ');
-    assert_eq!(choseong.compatibility_unicode, 0x314E);
-    assert_eq!(choseong.compatibility_value, 'ㅎ');
+    assert_eq!(choseong.conjoining_unicode(), 0x1112);
+    assert_eq!(choseong.conjoining_value(), 'ᄒ');
+    assert_eq!(choseong.compatibility_unicode(), 0x314E);
+    assert_eq!(choseong.compatibility_value(), 'ㅎ');
   }
 
   #[test]
   fn test_choseong_construction_from_compatibility() {
     let choseong = Choseong::new(0x3131);
-    assert_eq!(choseong.conjoining_unicode, 0x1100);
-    assert_eq!(choseong.conjoining_value, 'ᄀ');
-    assert_eq!(choseong.compatibility_unicode, 0x3131);
-    assert_eq!(choseong.compatibility_value, 'ㄱ');
+    assert_eq!(choseong.conjoining_unicode(), 0x1100);
+    assert_eq!(choseong.conjoining_value(), 'ᄀ');
+    assert_eq!(choseong.compatibility_unicode(), 0x3131);
+    assert_eq!(choseong.compatibility_value(), 'ㄱ');
 
     let choseong = Choseong::new(0x314E);
-    assert_eq!(choseong.conjoining_unicode, 0x1112);
-    assert_eq!(choseong.conjoining_value, 'ᄒ');
-    assert_eq!(choseong.compatibility_unicode, 0x314E);
-    assert_eq!(choseong.compatibility_value, 'ㅎ');
+    assert_eq!(choseong.conjoining_unicode(), 0x1112);
+    assert_eq!(choseong.conjoining_value(), 'ᄒ');
+    assert_eq!(choseong.compatibility_unicode(), 0x314E);
+    assert_eq!(choseong.compatibility_value(), 'ㅎ');
   }
 
   #[test]
@@ -134,4 +189,37 @@ mod tests {
   fn test_invalid_unicode_should_panic() {
     Choseong::new(0xAC00);
   }
+
+  #[test]
+  fn test_try_from_char() {
+    assert_eq!(Choseong::try_from('ㄸ'), Ok(Choseong::new(0x1104)));
+    assert_eq!(Choseong::try_from('ᄁ'), Ok(Choseong::new(0x3132)));
+    assert_eq!(
+      Choseong::try_from('ㄳ'),
+      Err(JamoError::InvalidChoseong('ㄳ'))
+    );
+    assert_eq!(
+      Choseong::try_from('ㅏ'),
+      Err(JamoError::InvalidChoseong('ㅏ'))
+    );
+    assert_eq!(Choseong::from_index(18), Some(Choseong::new('ㅎ' as u32)));
+    assert_eq!(Choseong::from_index(19), None);
+  }
+
+  #[test]
+  fn test_from_confusable() {
+    assert_eq!(Choseong::from_confusable('o'), Choseong::try_new(0x3147));
+    assert_eq!(Choseong::from_confusable('O'), Choseong::try_new(0x3147));
+    assert_eq!(
+      Choseong::from_confusable('\u{110B}'),
+      Choseong::try_new(0x3147)
+    );
+    assert_eq!(
+      Choseong::from_confusable('\u{FFB7}'),
+      Choseong::try_new(0x3147)
+    );
+    assert_eq!(Choseong::from_confusable('人'), Choseong::try_new(0x3145));
+    assert_eq!(Choseong::from_confusable('ㄳ'), None);
+    assert_eq!(Choseong::from_confusable('x'), None);
+  }
 }
@@ -0,0 +1,186 @@
+use crate::choseong::Choseong;
+use crate::halfwidth::halfwidth_to_compatibility_unicode;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+
+// 한글 자모 유니코드 블록
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JamoBlock {
+  // 한글 자모 (U+1100–11FF, "ᄀ")
+  Conjoining,
+  // 한글 호환 자모 (U+3130–318F, "ㄱ")
+  Compatibility,
+  // 반각 한글 자모 (U+FFA0–FFDC, "ﾡ")
+  Halfwidth,
+  // 한글 자모 확장-A (U+A960–A97F), 옛한글 초성
+  ExtendedA,
+  // 한글 자모 확장-B (U+D7B0–D7FF), 옛한글 중성·종성
+  ExtendedB,
+}
+
+// 문자가 속한 자모 블록, 자모가 아니면 `None`
+pub fn jamo_block(ch: char) -> Option<JamoBlock> {
+  match ch as u32 {
+    0x1100..=0x11FF => Some(JamoBlock::Conjoining),
+    0x3130..=0x318F => Some(JamoBlock::Compatibility),
+    0xFFA0..=0xFFDC => Some(JamoBlock::Halfwidth),
+    0xA960..=0xA97F => Some(JamoBlock::ExtendedA),
+    0xD7B0..=0xD7FF => Some(JamoBlock::ExtendedB),
+    _ => None,
+  }
+}
+
+// 자음 자모 (채움 문자 제외)
+pub fn is_consonant(ch: char) -> bool {
+  match ch as u32 {
+    0x1100..=0x115E | 0x11A8..=0x11FF | 0xA960..=0xA97C | 0xD7CB..=0xD7FB => true,
+    0x3131..=0x314E | 0x3165..=0x3186 => true,
+    unicode => halfwidth_to_compatibility_unicode(unicode)
+      .is_some_and(|compat| (0x3131..=0x314E).contains(&compat)),
+  }
+}
+
+// 모음 자모 (채움 문자 제외)
+pub fn is_vowel(ch: char) -> bool {
+  match ch as u32 {
+    0x1161..=0x11A7 | 0xD7B0..=0xD7C6 => true,
+    0x314F..=0x3163 | 0x3187..=0x318E => true,
+    unicode => halfwidth_to_compatibility_unicode(unicode)
+      .is_some_and(|compat| (0x314F..=0x3163).contains(&compat)),
+  }
+}
+
+pub fn is_jamo(ch: char) -> bool {
+  is_consonant(ch) || is_vowel(ch)
+}
+
+// 초성 자리에 올 수 있는 자음
+// 조합형은 옛한글 초성까지 포함하고, 호환형·반각은 현대 초성 19자만 해당 (ㄳ 제외)
+pub fn can_be_choseong(ch: char) -> bool {
+  match ch as u32 {
+    0x1100..=0x115E | 0xA960..=0xA97C => true,
+    unicode => compatibility_form(unicode).is_some_and(Choseong::is_compatibility_choseong),
+  }
+}
+
+// 종성 자리에 올 수 있는 자음
+// 조합형은 옛한글 종성까지 포함하고, 호환형·반각은 현대 종성 27자만 해당 (ㄸ, ㅃ, ㅉ 제외)
+pub fn can_be_jongseong(ch: char) -> bool {
+  match ch as u32 {
+    0x11A8..=0x11FF | 0xD7CB..=0xD7FB => true,
+    unicode => compatibility_form(unicode).is_some_and(Jongseong::is_compatibility_jongseong),
+  }
+}
+
+// 된소리 자음 (ㄲ, ㄸ, ㅃ, ㅆ, ㅉ)
+pub fn is_double_consonant(ch: char) -> bool {
+  modern_compatibility(ch as u32)
+    .is_some_and(|compat| matches!(compat, 0x3132 | 0x3138 | 0x3143 | 0x3146 | 0x3149))
+}
+
+// 겹받침 (ㄳ, ㄺ, ㅄ 등)
+pub fn is_complex_jongseong(ch: char) -> bool {
+  modern_compatibility(ch as u32)
+    .filter(|&compat| Jongseong::is_compatibility_jongseong(compat))
+    .is_some_and(|compat| Jongseong::new(compat).is_complex_jongseong())
+}
+
+// 이중 모음 (ㅘ, ㅙ, ㅚ, ㅝ, ㅞ, ㅟ, ㅢ)
+pub fn is_complex_vowel(ch: char) -> bool {
+  modern_compatibility(ch as u32)
+    .filter(|&compat| Jungseong::is_compatibility_jungseong(compat))
+    .is_some_and(|compat| Jungseong::new(compat).is_complex_jungseong())
+}
+
+// 현대 한글 자모를 호환형 유니코드로 변환, 옛한글 자모와 채움 문자는 `None`
+fn modern_compatibility(unicode: u32) -> Option<u32> {
+  match unicode {
+    0x1100..=0x1112 => Some(Choseong::new(unicode).compatibility_unicode()),
+    0x1161..=0x1175 => Some(Jungseong::new(unicode).compatibility_unicode()),
+    0x11A8..=0x11C2 => Some(Jongseong::new(unicode).compatibility_unicode()),
+    _ => compatibility_form(unicode),
+  }
+}
+
+// 위치 정보가 없는 현대 호환형·반각 자모를 호환형 유니코드로 변환
+fn compatibility_form(unicode: u32) -> Option<u32> {
+  match unicode {
+    0x3131..=0x3163 => Some(unicode),
+    _ => halfwidth_to_compatibility_unicode(unicode),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_jamo_block() {
+    assert_eq!(jamo_block('ᄀ'), Some(JamoBlock::Conjoining));
+    assert_eq!(jamo_block('ᆨ'), Some(JamoBlock::Conjoining));
+    assert_eq!(jamo_block('ㄱ'), Some(JamoBlock::Compatibility));
+    assert_eq!(jamo_block('ﾡ'), Some(JamoBlock::Halfwidth));
+    assert_eq!(jamo_block('\u{A960}'), Some(JamoBlock::ExtendedA));
+    assert_eq!(jamo_block('\u{D7B0}'), Some(JamoBlock::ExtendedB));
+    assert_eq!(jamo_block('가'), None);
+    assert_eq!(jamo_block('a'), None);
+  }
+
+  #[test]
+  fn test_consonant_and_vowel() {
+    for ch in [
+      'ㄱ', 'ㅎ', 'ㄳ', 'ㅿ', 'ᄀ', 'ᇹ', 'ﾡ', 'ﾾ', '\u{A960}', '\u{D7FB}',
+    ] {
+      assert!(is_consonant(ch), "{}", ch);
+      assert!(!is_vowel(ch), "{}", ch);
+    }
+    for ch in ['ㅏ', 'ㅣ', 'ㆍ', 'ᅡ', 'ᆢ', 'ￂ', 'ￜ', '\u{D7B0}'] {
+      assert!(is_vowel(ch), "{}", ch);
+      assert!(!is_consonant(ch), "{}", ch);
+    }
+    for ch in [
+      '\u{3164}', '\u{115F}', '\u{1160}', '\u{FFA0}', '\u{FFC0}', '가', 'a',
+    ] {
+      assert!(!is_jamo(ch), "{}", ch);
+    }
+  }
+
+  #[test]
+  fn test_position() {
+    assert!(can_be_choseong('ㄸ'));
+    assert!(can_be_choseong('ᄄ'));
+    assert!(can_be_choseong('\u{FFB9}'));
+    assert!(can_be_choseong('\u{A960}'));
+    assert!(!can_be_choseong('ㄳ'));
+    assert!(!can_be_choseong('ᆨ'));
+    assert!(!can_be_choseong('ㅏ'));
+
+    assert!(can_be_jongseong('ㄺ'));
+    assert!(can_be_jongseong('ᆨ'));
+    assert!(can_be_jongseong('\u{D7FB}'));
+    for ch in ['ㄸ', 'ㅃ', 'ㅉ', '\u{FFB9}', 'ᄀ'] {
+      assert!(!can_be_jongseong(ch), "{}", ch);
+    }
+  }
+
+  #[test]
+  fn test_compound_jamo() {
+    for ch in ['ㄲ', 'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ', 'ᄁ', 'ᆩ', 'ﾢ'] {
+      assert!(is_double_consonant(ch), "{}", ch);
+    }
+    assert!(!is_double_consonant('ㄱ'));
+    assert!(!is_double_consonant('ㄳ'));
+
+    for ch in ['ㄳ', 'ㄺ', 'ㅄ', 'ᆪ', 'ﾣ'] {
+      assert!(is_complex_jongseong(ch), "{}", ch);
+    }
+    assert!(!is_complex_jongseong('ㄲ'));
+    assert!(!is_complex_jongseong('ㄸ'));
+
+    for ch in ['ㅘ', 'ㅢ', 'ᅪ', '\u{FFCD}'] {
+      assert!(is_complex_vowel(ch), "{}", ch);
+    }
+    assert!(!is_complex_vowel('ㅐ'));
+    assert!(!is_complex_vowel('ㄱ'));
+  }
+}
@@ -0,0 +1,81 @@
+use alloc::string::String;
+
+use crate::choseong::Choseong;
+use crate::hangul::Hangul;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+use crate::old_hangul::conjoining_to_compatibility;
+
+// 혼동 문자 골격, 같은 골격이면 눈으로 구별하기 어려운 문자열
+// 조합형 음절은 완성형으로 합치고, 대소문자를 접은 뒤 낱자(조합형, 반각, ㅡ와 비슷한 一·ー, ㅇ과 비슷한 o)는 호환형 자모로 바꿈
+// 완성형 음절과 낱자를 나란히 쓴 것(한, ㅎㅏㄴ)은 모양이 다르므로 구별함
+pub fn confusable_skeleton(text: &str) -> String {
+  let hangul = Hangul::new(text);
+  let mut result = String::with_capacity(text.len());
+
+  for unit in hangul.iter() {
+    if let Some(letter) = unit.hangul() {
+      result.push(letter.value());
+      continue;
+    }
+
+    let mut chars = unit.as_str().chars();
+    match (chars.next(), chars.next()) {
+      (Some(ch), None) => result.extend(ch.to_lowercase().map(prototype)),
+      // 완성형이 없는 옛한글 음절은 그대로 비교
+      _ => result.push_str(unit.as_str()),
+    }
+  }
+
+  result
+}
+
+// 두 문자열의 혼동 문자 골격이 같은지 확인 ("ㅇㅡㅇ"과 "o一o", "HELLO"와 "hello")
+pub fn is_confusable(a: &str, b: &str) -> bool {
+  confusable_skeleton(a) == confusable_skeleton(b)
+}
+
+fn prototype(ch: char) -> char {
+  if let Some(choseong) = Choseong::from_confusable(ch) {
+    choseong.compatibility_value()
+  } else if let Some(jungseong) = Jungseong::from_confusable(ch) {
+    jungseong.compatibility_value()
+  } else if let Some(jongseong) = Jongseong::from_confusable(ch) {
+    jongseong.compatibility_value()
+  } else {
+    conjoining_to_compatibility(ch).unwrap_or(ch)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_confusable_skeleton() {
+    assert_eq!(confusable_skeleton("\u{1112}\u{1161}\u{11AB}글"), "한글");
+    assert_eq!(confusable_skeleton("\u{1112}"), "ㅎ");
+    assert_eq!(confusable_skeleton("\u{11AA}"), "ㄳ");
+    assert_eq!(confusable_skeleton("\u{FFBE}"), "ㅎ");
+    assert_eq!(confusable_skeleton("ㅡ一ー―"), "ㅡㅡㅡㅡ");
+    assert_eq!(confusable_skeleton("o0○"), "ㅇㅇㅇ");
+    assert_eq!(confusable_skeleton("\u{119E}"), "ㆍ");
+    assert_eq!(confusable_skeleton("ab"), "ab");
+    assert_eq!(confusable_skeleton("OIL"), "ㅇiㅣ");
+  }
+
+  #[test]
+  fn test_is_confusable() {
+    assert!(is_confusable("ㅇㅡㅇ", "o一o"));
+    assert!(is_confusable("ㅋㅋㅋ", "\u{110F}\u{110F}\u{110F}"));
+    assert!(is_confusable(
+      "한글",
+      "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+    ));
+    assert!(is_confusable("ㅣㅣ", "l|"));
+    assert!(is_confusable("HELLO", "hello"));
+    assert!(is_confusable("Hello", "hELLO"));
+    assert!(!is_confusable("한", "ㅎㅏㄴ"));
+    assert!(!is_confusable("ㅇ", "ㅁ"));
+  }
+}
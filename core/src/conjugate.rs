@@ -0,0 +1,528 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::choseong::Choseong;
+use crate::hangul_letter::HangulLetter;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+
+// ㄷ 불규칙 어간 (닫다, 받다 등 나머지 ㄷ 받침은 규칙)
+pub(crate) const DIGEUT_IRREGULAR: [&str; 9] =
+  ["듣", "걷", "묻", "싣", "깨닫", "긷", "일컫", "붇", "눋"];
+// ㅂ 받침 중 규칙 활용하는 어간 (나머지 ㅂ 받침은 불규칙)
+pub(crate) const BIEUP_REGULAR: [&str; 10] =
+  ["잡", "입", "씹", "업", "뽑", "좁", "접", "집", "꼽", "수줍"];
+// ㅅ 불규칙 어간 (씻다, 벗다 등 나머지 ㅅ 받침은 규칙)
+pub(crate) const SIOT_IRREGULAR: [&str; 7] = ["낫", "붓", "잇", "짓", "젓", "긋", "잣"];
+// ㅎ 받침 중 규칙 활용하는 어간 (나머지 ㅎ 받침은 불규칙)
+pub(crate) const HIEUT_REGULAR: [&str; 9] = ["좋", "놓", "넣", "낳", "닿", "쌓", "찧", "땋", "빻"];
+// 르로 끝나지만 ㅡ 탈락만 하는 어간 (따라, 치러)
+pub(crate) const REU_REGULAR: [&str; 5] = ["따르", "치르", "들르", "우러르", "다다르"];
+// 러 불규칙 어간
+pub(crate) const REO_IRREGULAR: [&str; 2] = ["푸르", "노르"];
+
+// 용언 활용 유형, ㄹ 탈락과 ㅡ 탈락은 규칙 활용에 포함
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConjugationClass {
+  // 규칙 활용 (먹다 → 먹어, 살다 → 사는, 쓰다 → 써)
+  Regular,
+  // ㅂ 불규칙 (돕다 → 도와, 춥다 → 추워)
+  BieupIrregular,
+  // ㄷ 불규칙 (듣다 → 들어)
+  DigeutIrregular,
+  // ㅅ 불규칙 (낫다 → 나아)
+  SiotIrregular,
+  // ㅎ 불규칙 (그렇다 → 그래, 파랗다 → 파란)
+  HieutIrregular,
+  // 르 불규칙 (부르다 → 불러)
+  ReuIrregular,
+  // 러 불규칙 (푸르다 → 푸르러)
+  ReoIrregular,
+  // 우 불규칙 (푸다 → 퍼)
+  UIrregular,
+  // 여 불규칙 (하다 → 해)
+  YeoIrregular,
+}
+
+// 활용 오류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConjugateError {
+  // '다'로 끝나는 한글 기본형이 아님
+  InvalidPredicate,
+  // 지원하지 않는 어미 형식
+  InvalidEnding,
+}
+
+impl fmt::Display for ConjugateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConjugateError::InvalidPredicate => write!(f, "Not a dictionary-form predicate"),
+      ConjugateError::InvalidEnding => write!(f, "Unsupported ending"),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConjugateError {}
+
+// 어미 형식
+enum Ending<'a> {
+  // -아/어, -았/었으로 시작하는 어미, 나머지 부분
+  Harmony { past: bool, rest: &'a str },
+  // -(으), -(스)로 시작하는 어미, 자음 뒤에서만 넣는 음절과 나머지 부분
+  Optional { vowel: char, rest: &'a str },
+  // 어간에 바로 붙는 어미 (-고, -는, -ㅂ니다)
+  Plain(&'a str),
+}
+
+// 기본형의 활용 유형, 내장 어간 목록과 받침으로 판단
+// 같은 기본형이 두 유형인 경우(묻다: 묻어/물어)는 불규칙 쪽을 반환
+pub fn conjugation_class(predicate: &str) -> Result<ConjugationClass, ConjugateError> {
+  let stem = stem_str(predicate)?;
+  Ok(class_of(stem))
+}
+
+// 기본형에 어미를 붙인 활용형 ("먹다", "-아/어요" → "먹어요", "돕다", "-(으)면" → "도우면")
+// 어미는 "-아/어요", "-았/었다", "-(으)면", "-(스)ㅂ니다", "-고"처럼 쓰며 앞의 '-'는 생략 가능
+pub fn conjugate(predicate: &str, ending: &str) -> Result<String, ConjugateError> {
+  let class = conjugation_class(predicate)?;
+  conjugate_as(predicate, ending, class)
+}
+
+// 활용 유형을 지정해 활용 (묻다를 규칙으로: 묻어요)
+pub fn conjugate_as(
+  predicate: &str,
+  ending: &str,
+  class: ConjugationClass,
+) -> Result<String, ConjugateError> {
+  let mut stem = stem_letters(predicate)?;
+
+  match parse_ending(ending)? {
+    Ending::Harmony { past, rest } => {
+      push_harmony(&mut stem, class, past.then(|| jongseong('ㅆ')));
+      finish(stem, rest)
+    }
+    Ending::Optional { vowel, rest } => {
+      let force_vowel = vowel == '으' && apply_before_vowel(&mut stem, class);
+      let last = stem[stem.len() - 1];
+
+      match last
+        .jongseong
+        .map(|jongseong| jongseong.compatibility_value())
+      {
+        Some('ㄹ') if !force_vowel => drop_rieul(&mut stem, rest),
+        Some(_) => stem.push(optional_syllable(vowel)),
+        None if force_vowel => stem.push(optional_syllable(vowel)),
+        None => {}
+      }
+      finish(stem, rest)
+    }
+    Ending::Plain(rest) => {
+      drop_rieul(&mut stem, rest);
+      finish(stem, rest)
+    }
+  }
+}
+
+fn stem_str(predicate: &str) -> Result<&str, ConjugateError> {
+  let stem = predicate
+    .strip_suffix('다')
+    .ok_or(ConjugateError::InvalidPredicate)?;
+
+  if stem.is_empty()
+    || stem
+      .chars()
+      .any(|ch| HangulLetter::parse_from_char(ch).is_none())
+  {
+    return Err(ConjugateError::InvalidPredicate);
+  }
+  Ok(stem)
+}
+
+fn stem_letters(predicate: &str) -> Result<Vec<HangulLetter>, ConjugateError> {
+  Ok(
+    stem_str(predicate)?
+      .chars()
+      .filter_map(HangulLetter::parse_from_char)
+      .collect(),
+  )
+}
+
+pub(crate) fn class_of(stem: &str) -> ConjugationClass {
+  let ends_with_any = |list: &[&str]| list.iter().any(|entry| stem.ends_with(entry));
+  let Some(last) = stem.chars().last().and_then(HangulLetter::parse_from_char) else {
+    return ConjugationClass::Regular;
+  };
+
+  if stem.ends_with('하') {
+    return ConjugationClass::YeoIrregular;
+  }
+  if stem == "푸" {
+    return ConjugationClass::UIrregular;
+  }
+  if stem.ends_with('르') {
+    return match () {
+      _ if ends_with_any(&REO_IRREGULAR) => ConjugationClass::ReoIrregular,
+      _ if ends_with_any(&REU_REGULAR) || stem.chars().count() < 2 => ConjugationClass::Regular,
+      _ => ConjugationClass::ReuIrregular,
+    };
+  }
+
+  match last
+    .jongseong
+    .map(|jongseong| jongseong.compatibility_value())
+  {
+    Some('ㅂ') if !ends_with_any(&BIEUP_REGULAR) => ConjugationClass::BieupIrregular,
+    Some('ㄷ') if ends_with_any(&DIGEUT_IRREGULAR) => ConjugationClass::DigeutIrregular,
+    Some('ㅅ') if ends_with_any(&SIOT_IRREGULAR) => ConjugationClass::SiotIrregular,
+    Some('ㅎ') if !ends_with_any(&HIEUT_REGULAR) => ConjugationClass::HieutIrregular,
+    _ => ConjugationClass::Regular,
+  }
+}
+
+fn parse_ending(ending: &str) -> Result<Ending<'_>, ConjugateError> {
+  let ending = ending.strip_prefix('-').unwrap_or(ending);
+
+  if let Some(rest) = ending.strip_prefix("(으)") {
+    return Ok(Ending::Optional { vowel: '으', rest });
+  }
+  if let Some(rest) = ending.strip_prefix("(스)") {
+    return Ok(Ending::Optional { vowel: '스', rest });
+  }
+
+  // "아/어요", "았/었다"는 뒤쪽 형태만 사용하고 모음 조화로 다시 고름
+  let ending = match ending.split_once('/') {
+    Some(("아" | "았", dark)) => dark,
+    Some(_) => return Err(ConjugateError::InvalidEnding),
+    None => ending,
+  };
+
+  let mut chars = ending.chars();
+  match chars.next() {
+    Some('아' | '어') => Ok(Ending::Harmony {
+      past: false,
+      rest: chars.as_str(),
+    }),
+    Some('았' | '었') => Ok(Ending::Harmony {
+      past: true,
+      rest: chars.as_str(),
+    }),
+    Some(_) => Ok(Ending::Plain(ending)),
+    None => Err(ConjugateError::InvalidEnding),
+  }
+}
+
+// 아/어로 시작하는 어미 앞의 어간 변화, 어미의 첫 음절(아/어, 았/었)까지 붙임
+fn push_harmony(stem: &mut Vec<HangulLetter>, class: ConjugationClass, jong: Option<Jongseong>) {
+  let last_index = stem.len() - 1;
+  let last = stem[last_index];
+
+  match class {
+    ConjugationClass::YeoIrregular => {
+      stem[last_index] = letter(last.choseong, 'ㅐ', jong);
+    }
+    ConjugationClass::UIrregular => {
+      stem[last_index] = letter(last.choseong, 'ㅓ', jong);
+    }
+    ConjugationClass::ReoIrregular => stem.push(letter(Choseong::RIEUL, 'ㅓ', jong)),
+    ConjugationClass::ReuIrregular if last_index > 0 => {
+      let previous = stem[last_index - 1];
+      stem[last_index - 1] = previous.with_jongseong(jongseong('ㄹ'));
+      stem[last_index] = letter(Choseong::RIEUL, harmony_vowel(previous), jong);
+    }
+    ConjugationClass::HieutIrregular => {
+      let vowel = match last.jungseong.compatibility_value() {
+        'ㅑ' | 'ㅕ' => 'ㅒ',
+        _ => 'ㅐ',
+      };
+      stem[last_index] = letter(last.choseong, vowel, jong);
+    }
+    ConjugationClass::BieupIrregular => {
+      // 돕다, 곱다만 도와, 고와
+      let vowel = match stem.as_slice() {
+        [only] if matches!(only.value(), '돕' | '곱') => 'ㅗ',
+        _ => 'ㅜ',
+      };
+      stem[last_index] = last.remove_jongseong();
+      stem.push(letter(Choseong::IEUNG, vowel, None));
+      push_regular_harmony(stem, jong);
+    }
+    ConjugationClass::DigeutIrregular => {
+      stem[last_index] = last.with_jongseong(jongseong('ㄹ'));
+      push_regular_harmony(stem, jong);
+    }
+    ConjugationClass::SiotIrregular => {
+      // ㅅ이 탈락해도 모음끼리 줄지 않음 (나아, 지어)
+      stem[last_index] = last.remove_jongseong();
+      stem.push(letter(Choseong::IEUNG, harmony_vowel(last), jong));
+    }
+    _ => push_regular_harmony(stem, jong),
+  }
+}
+
+// 규칙 활용의 모음 조화와 모음 축약 (가+아 → 가, 보+아 → 봐, 마시+어 → 마셔, 쓰+어 → 써)
+fn push_regular_harmony(stem: &mut Vec<HangulLetter>, jong: Option<Jongseong>) {
+  let last_index = stem.len() - 1;
+  let last = stem[last_index];
+
+  if last.has_batchim() {
+    stem.push(letter(Choseong::IEUNG, harmony_vowel(last), jong));
+    return;
+  }
+
+  let contracted = match last.jungseong.compatibility_value() {
+    vowel @ ('ㅏ' | 'ㅓ' | 'ㅕ' | 'ㅐ' | 'ㅔ') => Some(vowel),
+    'ㅗ' => Some('ㅘ'),
+    'ㅜ' => Some('ㅝ'),
+    'ㅚ' => Some('ㅙ'),
+    'ㅣ' => Some('ㅕ'),
+    // ㅡ 탈락, 모음 조화는 앞 음절을 따름 (바쁘+아 → 바빠)
+    'ㅡ' => Some(match last_index {
+      0 => 'ㅓ',
+      _ => harmony_vowel(stem[last_index - 1]),
+    }),
+    _ => None,
+  };
+
+  match contracted {
+    Some(vowel) => stem[last_index] = letter(last.choseong, vowel, jong),
+    None => stem.push(letter(Choseong::IEUNG, harmony_vowel(last), jong)),
+  }
+}
+
+// 모음으로 시작하는 어미 앞의 불규칙 어간 변화, 으를 반드시 넣어야 하면 true
+fn apply_before_vowel(stem: &mut Vec<HangulLetter>, class: ConjugationClass) -> bool {
+  let last_index = stem.len() - 1;
+  let last = stem[last_index];
+
+  match class {
+    ConjugationClass::BieupIrregular => {
+      stem[last_index] = last.remove_jongseong();
+      stem.push(letter(Choseong::IEUNG, 'ㅜ', None));
+      false
+    }
+    ConjugationClass::DigeutIrregular => {
+      stem[last_index] = last.with_jongseong(jongseong('ㄹ'));
+      true
+    }
+    ConjugationClass::SiotIrregular => {
+      stem[last_index] = last.remove_jongseong();
+      true
+    }
+    ConjugationClass::HieutIrregular => {
+      stem[last_index] = last.remove_jongseong();
+      false
+    }
+    _ => false,
+  }
+}
+
+// ㄹ 받침은 ㄴ, ㅂ, ㅅ, 오와 받침 ㄴ, ㄹ, ㅂ 앞에서 탈락 (살+는 → 사는, 살+ㄴ → 산)
+fn drop_rieul(stem: &mut [HangulLetter], rest: &str) {
+  let last_index = stem.len() - 1;
+  let last = stem[last_index];
+
+  if last
+    .jongseong
+    .map(|jongseong| jongseong.compatibility_value())
+    != Some('ㄹ')
+  {
+    return;
+  }
+
+  let drops = match rest.chars().next() {
+    Some('ㄴ' | 'ㄹ' | 'ㅂ') => true,
+    Some(ch) => HangulLetter::parse_from_char(ch).is_some_and(|next| {
+      matches!(next.choseong.compatibility_value(), 'ㄴ' | 'ㅂ' | 'ㅅ') || next.value() == '오'
+    }),
+    None => false,
+  };
+
+  if drops {
+    stem[last_index] = last.remove_jongseong();
+  }
+}
+
+// 어미 앞의 자음(ㄴ, ㄹ, ㅂ니다)은 마지막 음절의 받침으로 붙임
+fn finish(mut stem: Vec<HangulLetter>, rest: &str) -> Result<String, ConjugateError> {
+  let mut chars = rest.chars();
+  let mut rest = rest;
+
+  if let Some(jamo) = chars.next().and_then(|ch| Jongseong::try_from(ch).ok()) {
+    let last_index = stem.len() - 1;
+    if stem[last_index].has_batchim() {
+      return Err(ConjugateError::InvalidEnding);
+    }
+    stem[last_index] = stem[last_index].with_jongseong(jamo);
+    rest = chars.as_str();
+  }
+
+  let mut result: String = stem.into_iter().map(char::from).collect();
+  result.push_str(rest);
+  Ok(result)
+}
+
+// 양성 모음(ㅏ, ㅑ, ㅗ) 뒤에는 ㅏ, 그 밖에는 ㅓ
+fn harmony_vowel(letter: HangulLetter) -> char {
+  match letter.jungseong.compatibility_value() {
+    'ㅏ' | 'ㅑ' | 'ㅗ' => 'ㅏ',
+    _ => 'ㅓ',
+  }
+}
+
+fn optional_syllable(vowel: char) -> HangulLetter {
+  let choseong = match vowel {
+    '스' => Choseong::new('ㅅ' as u32),
+    _ => Choseong::IEUNG,
+  };
+  letter(choseong, 'ㅡ', None)
+}
+
+fn letter(choseong: Choseong, jungseong: char, jongseong: Option<Jongseong>) -> HangulLetter {
+  HangulLetter::from_parts(choseong, Jungseong::new(jungseong as u32), jongseong)
+}
+
+pub(crate) fn jongseong(ch: char) -> Jongseong {
+  Jongseong::new(ch as u32)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // 해요체, 과거, 조건, 나열, 관형사형 어미
+  const ENDINGS: [&str; 5] = ["-아/어요", "-았/었다", "-(으)면", "-고", "-(으)ㄴ"];
+
+  fn forms(predicate: &str) -> [String; 5] {
+    ENDINGS.map(|ending| conjugate(predicate, ending).unwrap())
+  }
+
+  #[test]
+  fn test_conjugate_regular() {
+    assert_eq!(
+      forms("먹다"),
+      ["먹어요", "먹었다", "먹으면", "먹고", "먹은"]
+    );
+    assert_eq!(
+      forms("좋다"),
+      ["좋아요", "좋았다", "좋으면", "좋고", "좋은"]
+    );
+    assert_eq!(forms("살다"), ["살아요", "살았다", "살면", "살고", "산"]);
+    assert_eq!(forms("가다"), ["가요", "갔다", "가면", "가고", "간"]);
+  }
+
+  #[test]
+  fn test_conjugate_contraction() {
+    let polite = |predicate| conjugate(predicate, "-아/어요").unwrap();
+
+    assert_eq!(polite("보다"), "봐요");
+    assert_eq!(polite("오다"), "와요");
+    assert_eq!(polite("주다"), "줘요");
+    assert_eq!(polite("마시다"), "마셔요");
+    assert_eq!(polite("되다"), "돼요");
+    assert_eq!(polite("서다"), "서요");
+    assert_eq!(polite("켜다"), "켜요");
+    assert_eq!(polite("보내다"), "보내요");
+    assert_eq!(polite("쉬다"), "쉬어요");
+    assert_eq!(polite("쓰다"), "써요");
+    assert_eq!(polite("바쁘다"), "바빠요");
+    assert_eq!(polite("따르다"), "따라요");
+    assert_eq!(conjugate("보다", "았/었어요").unwrap(), "봤어요");
+  }
+
+  #[test]
+  fn test_conjugate_irregular() {
+    assert_eq!(forms("하다"), ["해요", "했다", "하면", "하고", "한"]);
+    assert_eq!(
+      forms("돕다"),
+      ["도와요", "도왔다", "도우면", "돕고", "도운"]
+    );
+    assert_eq!(
+      forms("춥다"),
+      ["추워요", "추웠다", "추우면", "춥고", "추운"]
+    );
+    assert_eq!(
+      forms("듣다"),
+      ["들어요", "들었다", "들으면", "듣고", "들은"]
+    );
+    assert_eq!(
+      forms("낫다"),
+      ["나아요", "나았다", "나으면", "낫고", "나은"]
+    );
+    assert_eq!(
+      forms("그렇다"),
+      ["그래요", "그랬다", "그러면", "그렇고", "그런"]
+    );
+    assert_eq!(
+      forms("하얗다"),
+      ["하얘요", "하얬다", "하야면", "하얗고", "하얀"]
+    );
+    assert_eq!(
+      forms("부르다"),
+      ["불러요", "불렀다", "부르면", "부르고", "부른"]
+    );
+    assert_eq!(
+      forms("푸르다"),
+      ["푸르러요", "푸르렀다", "푸르면", "푸르고", "푸른"]
+    );
+    assert_eq!(forms("푸다"), ["퍼요", "펐다", "푸면", "푸고", "푼"]);
+    assert_eq!(
+      forms("공부하다"),
+      ["공부해요", "공부했다", "공부하면", "공부하고", "공부한"]
+    );
+    assert_eq!(conjugate("모르다", "아/어요").unwrap(), "몰라요");
+    assert_eq!(conjugate("깨닫다", "아/어요").unwrap(), "깨달아요");
+  }
+
+  #[test]
+  fn test_conjugate_rieul_deletion() {
+    assert_eq!(conjugate("살다", "-는").unwrap(), "사는");
+    assert_eq!(conjugate("살다", "-(으)세요").unwrap(), "사세요");
+    assert_eq!(conjugate("살다", "-(스)ㅂ니다").unwrap(), "삽니다");
+    assert_eq!(conjugate("먹다", "-(스)ㅂ니다").unwrap(), "먹습니다");
+    assert_eq!(conjugate("가다", "-(스)ㅂ니다").unwrap(), "갑니다");
+    assert_eq!(conjugate("돕다", "-(스)ㅂ니다").unwrap(), "돕습니다");
+  }
+
+  #[test]
+  fn test_conjugation_class() {
+    assert_eq!(conjugation_class("잡다"), Ok(ConjugationClass::Regular));
+    assert_eq!(
+      conjugation_class("아름답다"),
+      Ok(ConjugationClass::BieupIrregular)
+    );
+    assert_eq!(conjugation_class("닫다"), Ok(ConjugationClass::Regular));
+    assert_eq!(conjugation_class("씻다"), Ok(ConjugationClass::Regular));
+    assert_eq!(conjugation_class("놓다"), Ok(ConjugationClass::Regular));
+    assert_eq!(
+      conjugation_class("흐르다"),
+      Ok(ConjugationClass::ReuIrregular)
+    );
+    assert_eq!(conjugation_class("치르다"), Ok(ConjugationClass::Regular));
+    assert_eq!(
+      conjugation_class("먹"),
+      Err(ConjugateError::InvalidPredicate)
+    );
+    assert_eq!(
+      conjugation_class("다"),
+      Err(ConjugateError::InvalidPredicate)
+    );
+
+    assert_eq!(
+      conjugate_as("묻다", "-아/어요", ConjugationClass::Regular).unwrap(),
+      "묻어요"
+    );
+    assert_eq!(conjugate("묻다", "-아/어요").unwrap(), "물어요");
+  }
+
+  #[test]
+  fn test_invalid_ending() {
+    assert_eq!(conjugate("먹다", ""), Err(ConjugateError::InvalidEnding));
+    assert_eq!(conjugate("먹다", "-ㄴ"), Err(ConjugateError::InvalidEnding));
+    assert_eq!(
+      conjugate("먹다", "-가/나"),
+      Err(ConjugateError::InvalidEnding)
+    );
+  }
+}
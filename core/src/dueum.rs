@@ -0,0 +1,70 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hangul_letter::HangulLetter;
+
+// 단어 첫 음절에 두음법칙을 적용 (녀자 → 여자, 래일 → 내일, 리설주 → 이설주)
+// 첫 글자가 한글 음절이 아니면 그대로 반환
+pub fn apply_dueum(word: &str) -> String {
+  match split_first_letter(word) {
+    Some((letter, rest)) => join(letter.apply_dueum(), rest),
+    None => String::from(word),
+  }
+}
+
+// 두음법칙을 적용하면 `word`가 되는 본래 단어 목록 (이설주 → 리설주, 니설주 / 노인 → 로인)
+// ㄹ로 시작하는 단어를 먼저 반환하며, 두음법칙의 결과일 수 없는 단어면 빈 목록
+pub fn dueum_originals(word: &str) -> Vec<String> {
+  let Some((letter, rest)) = split_first_letter(word) else {
+    return Vec::new();
+  };
+
+  letter
+    .dueum_originals()
+    .map(|original| join(original, rest))
+    .collect()
+}
+
+fn split_first_letter(word: &str) -> Option<(HangulLetter, &str)> {
+  let first = word.chars().next()?;
+  let letter = HangulLetter::parse_from_char(first)?;
+
+  Some((letter, &word[first.len_utf8()..]))
+}
+
+fn join(letter: HangulLetter, rest: &str) -> String {
+  let mut result = String::with_capacity(rest.len() + 3);
+  result.push(letter.value());
+  result.push_str(rest);
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_apply_dueum() {
+    assert_eq!(apply_dueum("녀자"), "여자");
+    assert_eq!(apply_dueum("래일"), "내일");
+    assert_eq!(apply_dueum("로인"), "노인");
+    assert_eq!(apply_dueum("리설주"), "이설주");
+    assert_eq!(apply_dueum("량심"), "양심");
+    assert_eq!(apply_dueum("남녀"), "남녀");
+    assert_eq!(apply_dueum("Lee"), "Lee");
+    assert_eq!(apply_dueum(""), "");
+  }
+
+  #[test]
+  fn test_dueum_originals() {
+    assert_eq!(dueum_originals("이설주"), ["리설주", "니설주"]);
+    assert_eq!(dueum_originals("노인"), ["로인"]);
+    assert_eq!(dueum_originals("양심"), ["량심"]);
+    assert!(dueum_originals("김정은").is_empty());
+    assert!(dueum_originals("").is_empty());
+
+    for original in dueum_originals("여자") {
+      assert_eq!(apply_dueum(&original), "여자");
+    }
+  }
+}
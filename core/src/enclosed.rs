@@ -0,0 +1,155 @@
+// 괄호·원문자 한글 (U+3200–321E, U+3260–327E, Enclosed CJK Letters and Months)
+// 자음 14자와 가–하 14자는 같은 순서로 배열되고, 뒤에 약어 문자가 붙음
+
+// 목록 번호에 쓰는 자음과 음절 순서
+const CONSONANTS: [&str; 14] = [
+  "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ",
+];
+const SYLLABLES: [&str; 14] = [
+  "가", "나", "다", "라", "마", "바", "사", "아", "자", "차", "카", "타", "파", "하",
+];
+
+const PARENTHESIZED_START: u32 = 0x3200;
+const CIRCLED_START: u32 = 0x3260;
+
+// 음절 뒤에 오는 약어 문자 (㈜ ㈝ ㈞, ㉼ ㉽ ㉾)
+const PARENTHESIZED_WORDS: [&str; 3] = ["주", "오전", "오후"];
+const CIRCLED_WORDS: [&str; 3] = ["참고", "주의", "우"];
+
+// 둘러싼 모양
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnclosedStyle {
+  // 원문자 (㉠, ㉮)
+  Circled,
+  // 괄호 문자 (㈀, ㈎)
+  Parenthesized,
+}
+
+impl EnclosedStyle {
+  const fn start(self) -> u32 {
+    match self {
+      EnclosedStyle::Circled => CIRCLED_START,
+      EnclosedStyle::Parenthesized => PARENTHESIZED_START,
+    }
+  }
+
+  const fn words(self) -> &'static [&'static str; 3] {
+    match self {
+      EnclosedStyle::Circled => &CIRCLED_WORDS,
+      EnclosedStyle::Parenthesized => &PARENTHESIZED_WORDS,
+    }
+  }
+}
+
+// 괄호·원문자 한글의 모양과 블록 안의 순서 (자음 0–13, 음절 14–27, 약어 28–30)
+fn enclosed_offset(ch: char) -> Option<(EnclosedStyle, usize)> {
+  let unicode = ch as u32;
+
+  [EnclosedStyle::Parenthesized, EnclosedStyle::Circled]
+    .into_iter()
+    .find(|style| (style.start()..style.start() + 31).contains(&unicode))
+    .map(|style| (style, (unicode - style.start()) as usize))
+}
+
+// 괄호·원문자 한글 확인 (㉠, ㈎, ㈜, ㉾)
+pub fn is_enclosed_hangul(ch: char) -> bool {
+  enclosed_offset(ch).is_some()
+}
+
+pub fn enclosed_style(ch: char) -> Option<EnclosedStyle> {
+  enclosed_offset(ch).map(|(style, _)| style)
+}
+
+// 괄호·원문자 한글이 나타내는 호환형 자모나 음절 (㉠ → "ㄱ", ㈎ → "가", ㉼ → "참고")
+// 괄호는 포함하지 않으며, 괄호·원문자 한글이 아니면 `None`
+pub fn expand_enclosed(ch: char) -> Option<&'static str> {
+  let (style, offset) = enclosed_offset(ch)?;
+
+  match offset {
+    0..=13 => Some(CONSONANTS[offset]),
+    14..=27 => Some(SYLLABLES[offset - 14]),
+    _ => Some(style.words()[offset - 28]),
+  }
+}
+
+// 자모나 음절, 약어를 괄호·원문자로 바꿈 ("ㄱ" → ㉠, "가" → ㈎, "주" → ㈜), 없으면 `None`
+pub fn to_enclosed(text: &str, style: EnclosedStyle) -> Option<char> {
+  let offset = CONSONANTS
+    .iter()
+    .chain(&SYLLABLES)
+    .chain(style.words())
+    .position(|&value| value == text)?;
+
+  char::from_u32(style.start() + offset as u32)
+}
+
+// 목록 번호용 자음 문자, `index`는 0부터 (0 → ㉠, 13 → ㉭), 14 이상이면 `None`
+pub fn enclosed_jamo(index: usize, style: EnclosedStyle) -> Option<char> {
+  if index >= CONSONANTS.len() {
+    return None;
+  }
+  char::from_u32(style.start() + index as u32)
+}
+
+// 목록 번호용 음절 문자, `index`는 0부터 (0 → ㉮, 13 → ㉻), 14 이상이면 `None`
+pub fn enclosed_syllable(index: usize, style: EnclosedStyle) -> Option<char> {
+  if index >= SYLLABLES.len() {
+    return None;
+  }
+  char::from_u32(style.start() + (CONSONANTS.len() + index) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_expand_enclosed() {
+    assert_eq!(expand_enclosed('㉠'), Some("ㄱ"));
+    assert_eq!(expand_enclosed('㉭'), Some("ㅎ"));
+    assert_eq!(expand_enclosed('㈀'), Some("ㄱ"));
+    assert_eq!(expand_enclosed('㉮'), Some("가"));
+    assert_eq!(expand_enclosed('㈎'), Some("가"));
+    assert_eq!(expand_enclosed('㈛'), Some("하"));
+    assert_eq!(expand_enclosed('㈜'), Some("주"));
+    assert_eq!(expand_enclosed('㈞'), Some("오후"));
+    assert_eq!(expand_enclosed('㉼'), Some("참고"));
+    assert_eq!(expand_enclosed('㉾'), Some("우"));
+    assert_eq!(expand_enclosed('㉿'), None);
+    assert_eq!(expand_enclosed('㈟'), None);
+    assert_eq!(expand_enclosed('가'), None);
+
+    assert_eq!(enclosed_style('㉠'), Some(EnclosedStyle::Circled));
+    assert_eq!(enclosed_style('㈎'), Some(EnclosedStyle::Parenthesized));
+    assert!(is_enclosed_hangul('㉾'));
+    assert!(!is_enclosed_hangul('①'));
+  }
+
+  #[test]
+  fn test_to_enclosed() {
+    assert_eq!(to_enclosed("ㄱ", EnclosedStyle::Circled), Some('㉠'));
+    assert_eq!(to_enclosed("가", EnclosedStyle::Parenthesized), Some('㈎'));
+    assert_eq!(to_enclosed("주", EnclosedStyle::Parenthesized), Some('㈜'));
+    assert_eq!(to_enclosed("주의", EnclosedStyle::Circled), Some('㉽'));
+    assert_eq!(to_enclosed("주", EnclosedStyle::Circled), None);
+    assert_eq!(to_enclosed("ㄲ", EnclosedStyle::Circled), None);
+
+    for ch in ('\u{3200}'..='\u{321E}').chain('\u{3260}'..='\u{327E}') {
+      let style = enclosed_style(ch).unwrap();
+      assert_eq!(to_enclosed(expand_enclosed(ch).unwrap(), style), Some(ch));
+    }
+  }
+
+  #[test]
+  fn test_enclosed_numbering() {
+    assert_eq!(enclosed_jamo(0, EnclosedStyle::Circled), Some('㉠'));
+    assert_eq!(enclosed_jamo(1, EnclosedStyle::Parenthesized), Some('㈁'));
+    assert_eq!(enclosed_jamo(14, EnclosedStyle::Circled), None);
+    assert_eq!(
+      enclosed_syllable(0, EnclosedStyle::Parenthesized),
+      Some('㈎')
+    );
+    assert_eq!(enclosed_syllable(13, EnclosedStyle::Circled), Some('㉻'));
+    assert_eq!(enclosed_syllable(14, EnclosedStyle::Circled), None);
+  }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+// 반각 채움 문자와 호환형 채움 문자
+const HALFWIDTH_FILLER: u32 = 0xFFA0;
+const COMPAT_FILLER: u32 = 0x3164;
+
+// 반각 자모 구간 (반각 시작, 반각 끝, 호환형 시작), 반각 자모는 호환형과 같은 순서로 배열됨
+const HALFWIDTH_RANGES: [(u32, u32, u32); 5] = [
+  (0xFFA1, 0xFFBE, 0x3131),
+  (0xFFC2, 0xFFC7, 0x314F),
+  (0xFFCA, 0xFFCF, 0x3155),
+  (0xFFD2, 0xFFD7, 0x315B),
+  (0xFFDA, 0xFFDC, 0x3161),
+];
+
+// 반각 한글 자모 확인 (U+FFA0–FFDC, 채움 문자 포함)
+pub fn is_halfwidth_jamo(ch: char) -> bool {
+  halfwidth_to_compatibility(ch).is_some()
+}
+
+// 반각 자모를 호환형 자모로 변환 (ﾡ → ㄱ), 반각 자모가 아니면 `None`
+pub fn halfwidth_to_compatibility(ch: char) -> Option<char> {
+  halfwidth_to_compatibility_unicode(ch as u32).and_then(char::from_u32)
+}
+
+// 호환형 자모를 반각 자모로 변환 (ㄱ → ﾡ), 반각 자모가 없으면 (옛한글 자모) `None`
+pub fn compatibility_to_halfwidth(ch: char) -> Option<char> {
+  let unicode = ch as u32;
+
+  if unicode == COMPAT_FILLER {
+    return char::from_u32(HALFWIDTH_FILLER);
+  }
+
+  HALFWIDTH_RANGES
+    .iter()
+    .find(|&&(start, end, compat)| (compat..=compat + (end - start)).contains(&unicode))
+    .and_then(|&(start, _, compat)| char::from_u32(unicode - compat + start))
+}
+
+pub(crate) fn halfwidth_to_compatibility_unicode(unicode: u32) -> Option<u32> {
+  if unicode == HALFWIDTH_FILLER {
+    return Some(COMPAT_FILLER);
+  }
+
+  HALFWIDTH_RANGES
+    .iter()
+    .find(|&&(start, end, _)| (start..=end).contains(&unicode))
+    .map(|&(start, _, compat)| unicode - start + compat)
+}
+
+// 문자열의 반각 자모를 호환형 자모로 바꿈 (NFKC와 같은 결과), 나머지 문자는 그대로
+#[cfg(feature = "alloc")]
+pub fn fold_halfwidth(text: &str) -> String {
+  text
+    .chars()
+    .map(|ch| halfwidth_to_compatibility(ch).unwrap_or(ch))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_halfwidth_to_compatibility() {
+    assert_eq!(halfwidth_to_compatibility('ﾡ'), Some('ㄱ'));
+    assert_eq!(halfwidth_to_compatibility('ﾢ'), Some('ㄲ'));
+    assert_eq!(halfwidth_to_compatibility('ﾾ'), Some('ㅎ'));
+    assert_eq!(halfwidth_to_compatibility('ￂ'), Some('ㅏ'));
+    assert_eq!(halfwidth_to_compatibility('ￜ'), Some('ㅣ'));
+    assert_eq!(halfwidth_to_compatibility('\u{FFA0}'), Some('\u{3164}'));
+    assert_eq!(halfwidth_to_compatibility('\u{FFC0}'), None);
+    assert_eq!(halfwidth_to_compatibility('ㄱ'), None);
+
+    assert!(is_halfwidth_jamo('ﾡ'));
+    assert!(!is_halfwidth_jamo('ｱ'));
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for unicode in 0x3131..=0x3164 {
+      let compat = char::from_u32(unicode).unwrap();
+      let halfwidth = compatibility_to_halfwidth(compat).unwrap();
+      assert_eq!(halfwidth_to_compatibility(halfwidth), Some(compat));
+    }
+    assert_eq!(compatibility_to_halfwidth('ㅿ'), None);
+    assert_eq!(compatibility_to_halfwidth('가'), None);
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_fold_halfwidth() {
+    assert_eq!(fold_halfwidth("ﾡￂﾤ ok"), "ㄱㅏㄴ ok");
+    assert_eq!(fold_halfwidth("한글"), "한글");
+  }
+}
@@ -1,31 +1,81 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::convert::Infallible;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::{
+  Bound, Index, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use core::slice;
+use core::str::FromStr;
+
+use crate::choseong::Choseong;
+use crate::halfwidth::{fold_halfwidth, is_halfwidth_jamo};
 use crate::hangul_letter::HangulLetter;
+use crate::hanja::{hanja_reading, hanja_to_hangul};
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+use crate::nfc::NFC;
+use crate::old_hangul::{is_leading_jamo, is_trailing_jamo, is_vowel_jamo, OldHangul};
+use crate::options::{DisassembleOptions, ExtractOptions, NonHangul};
+use crate::utils::is_compatibility_jamo;
 
-struct CharUnit {
-  original: char,
+#[derive(Clone)]
+struct UnitSpan {
+  start: usize,
+  len: u32,
   hangul: Option<HangulLetter>,
 }
 
+// 한 글자 단위, 조합형(NFD) 음절은 여러 문자가 한 단위가 됨
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharUnit<'a> {
+  text: &'a str,
+  hangul: Option<HangulLetter>,
+}
+
+impl<'a> CharUnit<'a> {
+  // 원본 문자열에서 이 글자가 차지하는 부분
+  pub fn as_str(&self) -> &'a str {
+    self.text
+  }
+
+  // 한글 음절이면 분석 결과, 아니면 `None`
+  pub fn hangul(&self) -> Option<HangulLetter> {
+    self.hangul
+  }
+
+  pub fn is_hangul(&self) -> bool {
+    self.hangul.is_some()
+  }
+
+  // 완성형이 없는 옛한글 음절이면 분석 결과, 아니면 `None`
+  pub fn old_hangul(&self) -> Option<OldHangul<'a>> {
+    match self.hangul {
+      Some(_) => None,
+      None => OldHangul::parse(self.text),
+    }
+  }
+}
+
+impl fmt::Display for CharUnit<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.text)
+  }
+}
+
+#[derive(Clone)]
 pub struct Hangul {
-  char_units: Vec<CharUnit>,
+  char_units: Vec<UnitSpan>,
   original: String,
 }
 
-// TODO: NFD 지원
 impl Hangul {
   pub fn new(string: &str) -> Self {
-    let mut char_units = Vec::with_capacity(string.chars().count());
-
-    for ch in string.chars() {
-      char_units.push(CharUnit {
-        original: ch,
-        hangul: HangulLetter::parse_from_char(ch),
-      });
-    }
-
-    Self {
-      char_units,
-      original: string.to_string(),
-    }
+    Self::from(String::from(string))
   }
 
   pub fn original(&self) -> &str {
@@ -40,17 +90,90 @@ impl Hangul {
     self.char_units.is_empty()
   }
 
+  pub fn iter(&self) -> CharUnits<'_> {
+    CharUnits {
+      original: &self.original,
+      spans: self.char_units.iter(),
+    }
+  }
+
+  // `index`번째 글자, 범위를 벗어나면 `None`
+  pub fn get(&self, index: usize) -> Option<CharUnit<'_>> {
+    self
+      .char_units
+      .get(index)
+      .map(|span| unit(&self.original, span))
+  }
+
+  // 글자 단위 범위에 해당하는 원본 문자열, 범위를 벗어나면 `None`
+  pub fn get_str(&self, range: impl RangeBounds<usize>) -> Option<&str> {
+    let start = match range.start_bound() {
+      Bound::Included(&start) => start,
+      Bound::Excluded(&start) => start.checked_add(1)?,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(&end) => end.checked_add(1)?,
+      Bound::Excluded(&end) => end,
+      Bound::Unbounded => self.len(),
+    };
+
+    if start > end || end > self.len() {
+      return None;
+    }
+
+    Some(&self.original[self.byte_offset(start)..self.byte_offset(end)])
+  }
+
+  // 글자 인덱스를 원본 문자열의 바이트 위치로 변환 (`len()`이면 문자열 끝)
+  pub(crate) fn byte_offset(&self, index: usize) -> usize {
+    match self.char_units.get(index) {
+      Some(span) => span.start,
+      None => self.original.len(),
+    }
+  }
+
   pub fn disassemble(&self) -> String {
-    if self.is_empty() {
-      return String::new();
+    self.disassemble_with(&DisassembleOptions::default())
+  }
+
+  // 옵션에 따라 자모로 분해한 문자열
+  pub fn disassemble_with(&self, options: &DisassembleOptions) -> String {
+    let mut result = String::with_capacity(self.original.len() * 2);
+    let mut after_hangul = false;
+
+    for unit in self.iter() {
+      let start = result.len();
+
+      if options.push_unit_jamo(&unit, |ch| result.push(ch)) {
+        if let (true, Some(separator)) = (after_hangul, &options.separator) {
+          result.insert_str(start, separator);
+        }
+        after_hangul = true;
+      } else if options.non_hangul.push(unit.as_str(), false, &mut result) {
+        after_hangul = false;
+      }
     }
 
-    let mut result = String::with_capacity(self.char_units.len() * 3);
+    result
+  }
+
+  // 글자마다 분해한 자모 목록, 구분자 옵션은 사용하지 않음
+  pub fn disassemble_grouped(&self, options: &DisassembleOptions) -> Vec<Vec<char>> {
+    let mut result = Vec::with_capacity(self.len());
 
-    for unit in &self.char_units {
-      match &unit.hangul {
-        Some(hangul) => result.push_str(&hangul.disassemble()),
-        None => result.push(unit.original),
+    for unit in self.iter() {
+      let mut group = Vec::with_capacity(4);
+
+      if options.push_unit_jamo(&unit, |ch| group.push(ch)) {
+        result.push(group);
+        continue;
+      }
+
+      match options.non_hangul {
+        NonHangul::Keep => result.push(unit.as_str().chars().collect()),
+        NonHangul::Remove => {}
+        NonHangul::Replace(replacement) => result.push(alloc::vec![replacement]),
       }
     }
 
@@ -58,21 +181,473 @@ impl Hangul {
   }
 
   pub fn get_choseong(&self) -> String {
-    if self.is_empty() {
-      return String::new();
+    self.get_choseong_with(&ExtractOptions::default())
+  }
+
+  // 옵션에 따라 초성을 추출
+  pub fn get_choseong_with(&self, options: &ExtractOptions) -> String {
+    self.extract(
+      options,
+      ExtractOptions::push_choseong,
+      ExtractOptions::push_old_choseong,
+    )
+  }
+
+  pub fn get_jungseong(&self) -> String {
+    self.get_jungseong_with(&ExtractOptions::default())
+  }
+
+  // 옵션에 따라 중성을 추출
+  pub fn get_jungseong_with(&self, options: &ExtractOptions) -> String {
+    self.extract(
+      options,
+      ExtractOptions::push_jungseong,
+      ExtractOptions::push_old_jungseong,
+    )
+  }
+
+  pub fn get_jongseong(&self) -> String {
+    self.get_jongseong_with(&ExtractOptions::default())
+  }
+
+  // 옵션에 따라 종성을 추출, 종성이 없는 음절은 `missing_jongseong` 문자로 표시
+  pub fn get_jongseong_with(&self, options: &ExtractOptions) -> String {
+    self.extract(
+      options,
+      ExtractOptions::push_jongseong,
+      ExtractOptions::push_old_jongseong,
+    )
+  }
+
+  fn extract(
+    &self,
+    options: &ExtractOptions,
+    push: fn(&ExtractOptions, &HangulLetter, &mut String),
+    push_old: fn(&ExtractOptions, &OldHangul, &mut String),
+  ) -> String {
+    let mut result = String::with_capacity(self.original.len());
+
+    for unit in self.iter() {
+      match (unit.hangul(), unit.old_hangul()) {
+        (Some(letter), _) => push(options, &letter, &mut result),
+        (None, Some(cluster)) => push_old(options, &cluster, &mut result),
+        (None, None) => {
+          options
+            .non_hangul
+            .push(unit.as_str(), options.keep_whitespace, &mut result);
+        }
+      }
+    }
+
+    result
+  }
+
+  // 한글 음절을 완성형(NFC)으로 바꾼 문자열, 한글이 아닌 문자는 그대로 유지
+  pub fn to_nfc(&self) -> String {
+    let mut result = String::with_capacity(self.original.len());
+
+    for unit in &self.char_units {
+      match &unit.hangul {
+        Some(hangul) => result.push(hangul.value()),
+        None => result.push_str(self.span_str(unit)),
+      }
     }
 
-    let mut result = String::with_capacity(self.char_units.len());
+    result
+  }
+
+  // 한글 음절을 조합형 자모(NFD)로 바꾼 문자열, 한글이 아닌 문자는 그대로 유지
+  pub fn to_nfd(&self) -> String {
+    let mut result = String::with_capacity(self.original.len() * 3);
 
     for unit in &self.char_units {
       match &unit.hangul {
-        Some(hangul) => result.push(hangul.choseong.compatibility_value),
-        None => result.push(unit.original),
+        Some(hangul) => {
+          result.push(hangul.choseong.conjoining_value());
+          result.push(hangul.jungseong.conjoining_value());
+          if let Some(jongseong) = hangul.jongseong {
+            result.push(jongseong.conjoining_value());
+          }
+        }
+        None => result.push_str(self.span_str(unit)),
       }
     }
 
     result
   }
+
+  // 반각 자모(ﾡ)를 호환형 자모(ㄱ)로 바꾼 문자열, 분해·검색·조립에서 일반 자모로 다룸
+  pub fn fold_halfwidth(&self) -> Hangul {
+    if !self.original.chars().any(is_halfwidth_jamo) {
+      return self.clone();
+    }
+    Hangul::new(&fold_halfwidth(&self.original))
+  }
+
+  // 한자를 한글 독음으로 바꾼 문자열, 초성 검색 등에서 한자를 한글로 다룸 (大韓 → 대한)
+  pub fn hanja_to_hangul(&self) -> Hangul {
+    if !self.original.chars().any(|ch| hanja_reading(ch).is_some()) {
+      return self.clone();
+    }
+    Hangul::new(&hanja_to_hangul(&self.original))
+  }
+
+  // `index`번째 음절의 초성을 바꾼 문자열, 한글 음절이 아니면 `None`
+  pub fn with_choseong(&self, index: usize, choseong: Choseong) -> Option<Hangul> {
+    self.map_letter(index, |letter| letter.with_choseong(choseong))
+  }
+
+  pub fn with_jungseong(&self, index: usize, jungseong: Jungseong) -> Option<Hangul> {
+    self.map_letter(index, |letter| letter.with_jungseong(jungseong))
+  }
+
+  pub fn with_jongseong(&self, index: usize, jongseong: Jongseong) -> Option<Hangul> {
+    self.map_letter(index, |letter| letter.with_jongseong(jongseong))
+  }
+
+  pub fn remove_jongseong(&self, index: usize) -> Option<Hangul> {
+    self.map_letter(index, |letter| letter.remove_jongseong())
+  }
+
+  // 끝에서 자모 하나를 지운 문자열, 입력기의 백스페이스와 같음 (닭 → 달 → 다 → ㄷ → "")
+  // 한글 음절이 아닌 마지막 글자는 통째로 지우고, 호환형 겹자모는 앞 자모만 남김 (ㄺ → ㄹ)
+  pub fn remove_last_jamo(&self) -> Hangul {
+    let Some(last) = self.iter().next_back() else {
+      return self.clone();
+    };
+    let mut buffer = [0; 4];
+
+    let replacement = match last.hangul() {
+      Some(letter) => match letter.remove_last_jamo() {
+        Some(letter) => letter.value(),
+        None => letter.choseong.compatibility_value(),
+      }
+      .encode_utf8(&mut buffer),
+      None => {
+        let text = last.as_str();
+        let ch = text.chars().next_back().unwrap();
+        let kept = &text[..text.len() - ch.len_utf8()];
+
+        match split_compatibility_jamo(ch) {
+          Some(first) if kept.is_empty() => first.encode_utf8(&mut buffer),
+          _ => kept,
+        }
+      }
+    };
+
+    self.replace_unit(self.len() - 1, replacement)
+  }
+
+  fn map_letter(
+    &self,
+    index: usize,
+    map: impl FnOnce(HangulLetter) -> HangulLetter,
+  ) -> Option<Hangul> {
+    let letter = map(self.get(index)?.hangul()?);
+    let mut buffer = [0; 4];
+
+    Some(self.replace_unit(index, letter.value().encode_utf8(&mut buffer)))
+  }
+
+  // `index`번째 글자를 `replacement`로 바꾼 문자열
+  fn replace_unit(&self, index: usize, replacement: &str) -> Hangul {
+    let mut result = String::with_capacity(self.original.len() + replacement.len());
+    result.push_str(&self.original[..self.byte_offset(index)]);
+    result.push_str(replacement);
+    result.push_str(&self.original[self.byte_offset(index + 1)..]);
+
+    Self::from(result)
+  }
+
+  fn span_str(&self, span: &UnitSpan) -> &str {
+    unit(&self.original, span).text
+  }
+}
+
+fn unit<'a>(original: &'a str, span: &UnitSpan) -> CharUnit<'a> {
+  CharUnit {
+    text: &original[span.start..span.start + span.len as usize],
+    hangul: span.hangul,
+  }
+}
+
+// 글자 단위 반복자
+#[derive(Clone)]
+pub struct CharUnits<'a> {
+  original: &'a str,
+  spans: slice::Iter<'a, UnitSpan>,
+}
+
+impl<'a> Iterator for CharUnits<'a> {
+  type Item = CharUnit<'a>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.spans.next().map(|span| unit(self.original, span))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.spans.size_hint()
+  }
+}
+
+impl DoubleEndedIterator for CharUnits<'_> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.spans.next_back().map(|span| unit(self.original, span))
+  }
+}
+
+impl ExactSizeIterator for CharUnits<'_> {}
+
+impl FusedIterator for CharUnits<'_> {}
+
+impl<'a> IntoIterator for &'a Hangul {
+  type Item = CharUnit<'a>;
+  type IntoIter = CharUnits<'a>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl From<String> for Hangul {
+  fn from(original: String) -> Self {
+    let mut char_units = Vec::with_capacity(original.chars().count());
+    let mut start = 0;
+
+    while let Some((len, hangul)) = next_unit(&original[start..]) {
+      char_units.push(UnitSpan {
+        start,
+        len: len as u32,
+        hangul,
+      });
+      start += len;
+    }
+
+    Self {
+      char_units,
+      original,
+    }
+  }
+}
+
+impl From<&str> for Hangul {
+  fn from(string: &str) -> Self {
+    Self::new(string)
+  }
+}
+
+impl From<Hangul> for String {
+  fn from(hangul: Hangul) -> Self {
+    hangul.original
+  }
+}
+
+impl FromStr for Hangul {
+  type Err = Infallible;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Ok(Self::new(string))
+  }
+}
+
+impl fmt::Display for Hangul {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.original)
+  }
+}
+
+impl fmt::Debug for Hangul {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("Hangul").field(&self.original).finish()
+  }
+}
+
+impl AsRef<str> for Hangul {
+  fn as_ref(&self) -> &str {
+    &self.original
+  }
+}
+
+// 글자 단위는 원본 문자열에서 결정되므로 비교와 해시는 원본 문자열 기준
+impl Borrow<str> for Hangul {
+  fn borrow(&self) -> &str {
+    &self.original
+  }
+}
+
+impl PartialEq for Hangul {
+  fn eq(&self, other: &Self) -> bool {
+    self.original == other.original
+  }
+}
+
+impl Eq for Hangul {}
+
+impl PartialEq<str> for Hangul {
+  fn eq(&self, other: &str) -> bool {
+    self.original == other
+  }
+}
+
+impl PartialEq<&str> for Hangul {
+  fn eq(&self, other: &&str) -> bool {
+    self.original == *other
+  }
+}
+
+impl PartialEq<Hangul> for str {
+  fn eq(&self, other: &Hangul) -> bool {
+    self == other.original
+  }
+}
+
+impl PartialEq<Hangul> for &str {
+  fn eq(&self, other: &Hangul) -> bool {
+    *self == other.original
+  }
+}
+
+impl PartialOrd for Hangul {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Hangul {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.original.cmp(&other.original)
+  }
+}
+
+impl Hash for Hangul {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.original.hash(state)
+  }
+}
+
+// `index`번째 글자의 원본 문자열
+impl Index<usize> for Hangul {
+  type Output = str;
+
+  fn index(&self, index: usize) -> &str {
+    let span = &self.char_units[index];
+    &self.original[span.start..span.start + span.len as usize]
+  }
+}
+
+// 글자 단위 범위로 원본 문자열을 자름 (`hangul[1..3]`)
+macro_rules! impl_index_range {
+  ($($range:ty),*) => {
+    $(
+      impl Index<$range> for Hangul {
+        type Output = str;
+
+        fn index(&self, range: $range) -> &str {
+          match self.get_str(range.clone()) {
+            Some(string) => string,
+            None => panic!(
+              "character range {:?} out of bounds for Hangul of length {}",
+              range,
+              self.len()
+            ),
+          }
+        }
+      }
+    )*
+  };
+}
+
+impl_index_range!(
+  Range<usize>,
+  RangeFrom<usize>,
+  RangeTo<usize>,
+  RangeInclusive<usize>,
+  RangeToInclusive<usize>,
+  RangeFull
+);
+
+// 문자열 앞에서 한 글자 단위를 읽어 (바이트 길이, 한글 음절)을 반환
+// 완성형 음절, 조합형(NFD) 초성+중성(+종성), 완성형 초성+중성 음절 뒤의 조합형 종성을 한 글자로 묶음
+// 완성형 초성+중성 음절 뒤에 현대 종성 하나가 아닌 종성(ᇰ, ᆨᆨ)이 이어지면 옛한글 음절
+pub(crate) fn next_unit(string: &str) -> Option<(usize, Option<HangulLetter>)> {
+  let first = string.chars().next()?;
+
+  if let Some(letter) = HangulLetter::parse_from_char(first) {
+    if let Some(cluster) = OldHangul::parse_prefix(string) {
+      let mut trailing = cluster.jongseong().chars();
+      let letter = match (trailing.next(), trailing.next()) {
+        (Some(next), None) => Jongseong::try_new(next as u32).map(|jongseong| HangulLetter {
+          jongseong: Some(jongseong),
+          ..letter
+        }),
+        _ => None,
+      };
+      return Some((cluster.as_str().len(), letter));
+    }
+
+    return Some((first.len_utf8(), Some(letter)));
+  }
+
+  // 조합형 초성+ 중성+ 종성*, 현대 한글 초성·중성·종성 하나씩이면 현대 음절이고 아니면 옛한글 음절
+  if let Some(cluster) = OldHangul::parse_prefix(string) {
+    let text = cluster.as_str();
+    return Some((text.len(), HangulLetter::parse(text)));
+  }
+
+  Some((first.len_utf8(), None))
+}
+
+// 다음 입력과 이어질 수 있는 마지막 글자 단위의 시작 위치, 없으면 문자열 길이
+// 마지막 문자가 완성형 음절이거나 조합형 자모이면 다음 청크에서 종성·중성이 붙을 수 있음
+// 끝에 초성만 이어지면(ᄀᄀ) 다음 청크의 중성과 한 음절이 되므로 그 시작부터 보관
+pub(crate) fn incomplete_tail_start(string: &str) -> usize {
+  let mut start = 0;
+  let mut last_start = 0;
+  let mut leading_start = None;
+
+  while let Some((len, _)) = next_unit(&string[start..]) {
+    let is_leading = string[start..start + len].chars().all(is_leading_jamo);
+    leading_start = match (is_leading, leading_start) {
+      (true, None) => Some(start),
+      (true, run_start) => run_start,
+      (false, _) => None,
+    };
+    last_start = start;
+    start += len;
+  }
+
+  match string.chars().next_back() {
+    Some(last) if can_continue(last) => leading_start.unwrap_or(last_start),
+    _ => string.len(),
+  }
+}
+
+// 호환형 겹자모의 앞 자모 (ㄺ → ㄹ, ㅘ → ㅗ), 겹자모가 아니면 `None`
+fn split_compatibility_jamo(ch: char) -> Option<char> {
+  let unicode = ch as u32;
+
+  if !is_compatibility_jamo(unicode) {
+    return None;
+  }
+
+  match (Jongseong::try_new(unicode), Jungseong::try_new(unicode)) {
+    (Some(jongseong), _) => jongseong
+      .split()
+      .1
+      .map(|_| jongseong.split().0.compatibility_value()),
+    (_, Some(jungseong)) => jungseong
+      .split()
+      .1
+      .map(|_| jungseong.split().0.compatibility_value()),
+    _ => None,
+  }
+}
+
+fn can_continue(ch: char) -> bool {
+  NFC::is_complete_hangul(ch as u32)
+    || is_leading_jamo(ch)
+    || is_vowel_jamo(ch)
+    || is_trailing_jamo(ch)
 }
 
 #[cfg(test)]
@@ -119,6 +694,89 @@ mod tests {
     assert!(!mixed.is_empty());
   }
 
+  #[test]
+  fn test_iter() {
+    let mixed = Hangul::new("닭 \u{1112}\u{1161}\u{11AB}");
+    let units: Vec<CharUnit> = mixed.iter().collect();
+
+    assert_eq!(units.len(), 3);
+    assert_eq!(units[0].as_str(), "닭");
+    assert_eq!(units[0].hangul(), HangulLetter::parse("닭"));
+    assert_eq!(units[1].as_str(), " ");
+    assert!(!units[1].is_hangul());
+    assert_eq!(units[2].as_str(), "\u{1112}\u{1161}\u{11AB}");
+    assert_eq!(units[2].hangul(), HangulLetter::parse("한"));
+
+    let reversed: String = mixed.iter().rev().map(|unit| unit.as_str()).collect();
+    assert_eq!(reversed, "\u{1112}\u{1161}\u{11AB} 닭");
+
+    let mut count = 0;
+    for unit in &mixed {
+      assert_eq!(Some(unit), mixed.get(count));
+      count += 1;
+    }
+    assert_eq!(count, mixed.len());
+    assert_eq!(mixed.get(3), None);
+  }
+
+  #[test]
+  fn test_index() {
+    let text = Hangul::new("안녕 \u{1112}\u{1161}\u{11AB}글!");
+
+    assert_eq!(&text[0], "안");
+    assert_eq!(&text[3], "\u{1112}\u{1161}\u{11AB}");
+    assert_eq!(&text[1..4], "녕 \u{1112}\u{1161}\u{11AB}");
+    assert_eq!(&text[..2], "안녕");
+    assert_eq!(&text[4..], "글!");
+    assert_eq!(&text[..=1], "안녕");
+    assert_eq!(&text[2..=2], " ");
+    assert_eq!(&text[..], text.original());
+    assert_eq!(&text[6..], "");
+
+    assert_eq!(text.get_str(0..2), Some("안녕"));
+    assert_eq!(text.get_str(4..), Some("글!"));
+    assert_eq!(text.get_str(5..7), None);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_index_out_of_bounds() {
+    let text = Hangul::new("안녕");
+    let _ = &text[1..3];
+  }
+
+  #[test]
+  fn test_traits() {
+    let text: Hangul = "안녕".parse().unwrap();
+    assert_eq!(text, Hangul::from("안녕"));
+    assert_eq!(text, Hangul::from(String::from("안녕")));
+    assert_eq!(text, "안녕");
+    assert_eq!(*"안녕", text);
+    assert_ne!(text, "안녕!");
+
+    assert_eq!(alloc::format!("{}", text), "안녕");
+    assert_eq!(alloc::format!("{:?}", text), "Hangul(\"안녕\")");
+    assert_eq!(String::from(text.clone()), "안녕");
+
+    let mut sorted = alloc::vec![Hangul::new("다"), Hangul::new("가"), Hangul::new("나")];
+    sorted.sort();
+    assert_eq!(sorted, ["가", "나", "다"]);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_map_key() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let mut counts = HashMap::new();
+    *counts.entry(Hangul::new("한글")).or_insert(0) += 1;
+    *counts.entry(Hangul::new("한글")).or_insert(0) += 1;
+    assert_eq!(counts.get("한글"), Some(&2));
+
+    let set: BTreeSet<Hangul> = ["나", "가"].into_iter().map(Hangul::from).collect();
+    assert!(set.contains("가"));
+  }
+
   #[test]
   fn test_disassemble() {
     let sentence = Hangul::new("안녕");
@@ -131,6 +789,312 @@ mod tests {
     assert_eq!(special.disassemble(), "ㅇㅏㄴㄴㅕㅇ!");
   }
 
+  #[test]
+  fn test_disassemble_with_options() {
+    use crate::options::JamoForm;
+
+    let text = Hangul::new("닭과 Rust!");
+    assert_eq!(
+      text.disassemble_with(&DisassembleOptions::new()),
+      text.disassemble()
+    );
+    assert_eq!(
+      text.disassemble_with(&DisassembleOptions::new().split_complex_jongseong(false)),
+      "ㄷㅏㄺㄱㅘ Rust!"
+    );
+    assert_eq!(
+      text.disassemble_with(&DisassembleOptions::new().split_complex_jungseong(true)),
+      "ㄷㅏㄹㄱㄱㅗㅏ Rust!"
+    );
+    assert_eq!(
+      text.disassemble_with(&DisassembleOptions::new().separator("/")),
+      "ㄷㅏㄹㄱ/ㄱㅘ Rust!"
+    );
+    assert_eq!(
+      text.disassemble_with(
+        &DisassembleOptions::new()
+          .separator("/")
+          .non_hangul(NonHangul::Remove)
+      ),
+      "ㄷㅏㄹㄱ/ㄱㅘ"
+    );
+    assert_eq!(
+      Hangul::new("한 글").disassemble_with(
+        &DisassembleOptions::new()
+          .separator("|")
+          .non_hangul(NonHangul::Remove)
+      ),
+      "ㅎㅏㄴ|ㄱㅡㄹ"
+    );
+    assert_eq!(
+      Hangul::new("한")
+        .disassemble_with(&DisassembleOptions::new().jamo_form(JamoForm::Conjoining)),
+      "\u{1112}\u{1161}\u{11AB}"
+    );
+  }
+
+  #[test]
+  fn test_disassemble_grouped() {
+    let text = Hangul::new("값 ok");
+    let grouped = text.disassemble_grouped(&DisassembleOptions::new());
+    assert_eq!(
+      grouped,
+      [
+        alloc::vec!['ㄱ', 'ㅏ', 'ㅂ', 'ㅅ'],
+        alloc::vec![' '],
+        alloc::vec!['o'],
+        alloc::vec!['k'],
+      ]
+    );
+
+    let grouped =
+      text.disassemble_grouped(&DisassembleOptions::new().non_hangul(NonHangul::Remove));
+    assert_eq!(grouped, [['ㄱ', 'ㅏ', 'ㅂ', 'ㅅ']]);
+  }
+
+  #[test]
+  fn test_disassemble_enclosed() {
+    use crate::options::JamoForm;
+
+    let text = Hangul::new("㉠ 항목 ㈎호 ㉼");
+    assert_eq!(text.disassemble(), "㉠ ㅎㅏㅇㅁㅗㄱ ㈎ㅎㅗ ㉼");
+
+    let options = DisassembleOptions::new().expand_enclosed(true);
+    assert_eq!(
+      text.disassemble_with(&options),
+      "ㄱ ㅎㅏㅇㅁㅗㄱ ㄱㅏㅎㅗ ㅊㅏㅁㄱㅗ"
+    );
+    assert_eq!(
+      Hangul::new("㈎호").disassemble_with(&options.clone().separator("/")),
+      "ㄱㅏ/ㅎㅗ"
+    );
+    assert_eq!(
+      Hangul::new("㉠").disassemble_with(&options.clone().jamo_form(JamoForm::Conjoining)),
+      "\u{1100}"
+    );
+    assert_eq!(
+      Hangul::new("㉮").disassemble_grouped(&options),
+      [['ㄱ', 'ㅏ']]
+    );
+  }
+
+  #[test]
+  fn test_extract_with_options() {
+    use crate::options::JamoForm;
+
+    let text = Hangul::new("까치, 뛰다! ok");
+    assert_eq!(text.get_choseong(), "ㄲㅊ, ㄸㄷ! ok");
+    assert_eq!(
+      text.get_choseong_with(&ExtractOptions::new().non_hangul(NonHangul::Remove)),
+      "ㄲㅊㄸㄷ"
+    );
+    assert_eq!(
+      text.get_choseong_with(
+        &ExtractOptions::new()
+          .non_hangul(NonHangul::Remove)
+          .keep_whitespace(true)
+          .fold_double_consonants(true)
+      ),
+      "ㄱㅊ ㄷㄷ "
+    );
+    assert_eq!(
+      text.get_choseong_with(&ExtractOptions::new().non_hangul(NonHangul::Replace('?'))),
+      "ㄲㅊ??ㄸㄷ????"
+    );
+    assert_eq!(
+      Hangul::new("한").get_choseong_with(&ExtractOptions::new().jamo_form(JamoForm::Conjoining)),
+      "\u{1112}"
+    );
+  }
+
+  #[test]
+  fn test_get_jungseong_and_jongseong() {
+    let text = Hangul::new("닭과 밖에");
+    assert_eq!(text.get_jungseong(), "ㅏㅘ ㅏㅔ");
+    assert_eq!(text.get_jongseong(), "ㄺ ㄲ");
+    assert_eq!(
+      text.get_jongseong_with(
+        &ExtractOptions::new()
+          .missing_jongseong('_')
+          .fold_double_consonants(true)
+      ),
+      "ㄺ_ ㄱ_"
+    );
+    assert_eq!(Hangul::new("").get_jongseong(), "");
+  }
+
+  #[test]
+  fn test_disassemble_replace_non_hangul() {
+    let text = Hangul::new("한 a");
+    let options = DisassembleOptions::new().non_hangul(NonHangul::Replace('_'));
+    assert_eq!(text.disassemble_with(&options), "ㅎㅏㄴ__");
+    assert_eq!(
+      text.disassemble_grouped(&options),
+      [
+        alloc::vec!['ㅎ', 'ㅏ', 'ㄴ'],
+        alloc::vec!['_'],
+        alloc::vec!['_']
+      ]
+    );
+  }
+
+  #[test]
+  fn test_with_components() {
+    let text = Hangul::new("한글 a");
+    assert_eq!(
+      text.with_choseong(0, Choseong::new('ㄱ' as u32)).unwrap(),
+      "간글 a"
+    );
+    assert_eq!(
+      text.with_jungseong(1, Jungseong::new(0x1175)).unwrap(),
+      "한길 a"
+    );
+    assert_eq!(
+      text.with_jongseong(1, Jongseong::new('ㄺ' as u32)).unwrap(),
+      "한긁 a"
+    );
+    assert_eq!(text.remove_jongseong(0).unwrap(), "하글 a");
+    assert!(text.remove_jongseong(3).is_none());
+    assert!(text.remove_jongseong(4).is_none());
+
+    let nfd = Hangul::new("\u{1112}\u{1161}\u{11AB}다");
+    assert_eq!(nfd.remove_jongseong(0).unwrap(), "하다");
+  }
+
+  #[test]
+  fn test_remove_last_jamo() {
+    let mut text = Hangul::new("닭");
+    let mut steps = Vec::new();
+    while !text.is_empty() {
+      text = text.remove_last_jamo();
+      steps.push(String::from(text.original()));
+    }
+    assert_eq!(steps, ["달", "다", "ㄷ", ""]);
+
+    assert_eq!(Hangul::new("사과").remove_last_jamo(), "사고");
+    assert_eq!(Hangul::new("값").remove_last_jamo(), "갑");
+    assert_eq!(Hangul::new("가ㄺ").remove_last_jamo(), "가ㄹ");
+    assert_eq!(Hangul::new("가ㅘ").remove_last_jamo(), "가ㅗ");
+    assert_eq!(Hangul::new("가!").remove_last_jamo(), "가");
+    assert_eq!(Hangul::new("").remove_last_jamo(), "");
+  }
+
+  #[test]
+  fn test_nfd_input() {
+    let nfd = Hangul::new("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}");
+    assert_eq!(nfd.len(), 2);
+    assert_eq!(nfd.disassemble(), "ㅎㅏㄴㄱㅡㄹ");
+    assert_eq!(nfd.get_choseong(), "ㅎㄱ");
+
+    // 완성형 초성+중성 음절 뒤의 조합형 종성
+    let mixed = Hangul::new("\u{AC00}\u{11A8}!");
+    assert_eq!(mixed.len(), 2);
+    assert_eq!(mixed.disassemble(), "ㄱㅏㄱ!");
+
+    // 홀로 쓰인 조합형 자모는 그대로 유지
+    let lone = Hangul::new("\u{1100}a\u{1161}");
+    assert_eq!(lone.len(), 3);
+    assert_eq!(lone.disassemble(), "\u{1100}a\u{1161}");
+  }
+
+  #[test]
+  fn test_old_hangul() {
+    // 나랏말ᄊᆞ미 (훈민정음 언해)
+    let text = Hangul::new("나랏말\u{110A}\u{119E}미");
+    assert_eq!(text.len(), 5);
+    assert!(text.get(3).unwrap().old_hangul().is_some());
+    assert!(text.get(0).unwrap().old_hangul().is_none());
+    assert_eq!(text.disassemble(), "ㄴㅏㄹㅏㅅㅁㅏㄹㅆㆍㅁㅣ");
+    assert_eq!(text.get_choseong(), "ㄴㄹㅁㅆㅁ");
+    assert_eq!(text.to_nfc(), text.original());
+
+    // 어두 자음군과 옛한글 종성: ᄢᅳᆷ, ᄒᆞᇰ
+    let text = Hangul::new("\u{1122}\u{1173}\u{11B7} \u{1112}\u{119E}\u{11F0}");
+    assert_eq!(text.len(), 3);
+    assert_eq!(text.get_choseong(), "ㅴ ㅎ");
+    assert_eq!(text.get_jungseong(), "ㅡ ㆍ");
+    assert_eq!(text.get_jongseong(), "ㅁ ㆁ");
+    assert_eq!(
+      text.disassemble_with(&DisassembleOptions::new().separator("/")),
+      "ㅴㅡㅁ ㅎㆍㆁ"
+    );
+    assert_eq!(
+      text.disassemble_grouped(&DisassembleOptions::new())[2],
+      ['ㅎ', 'ㆍ', 'ㆁ']
+    );
+
+    // 현대 자모 여러 개로 이루어진 음절과 채움 문자
+    let text = Hangul::new("\u{1100}\u{1161}\u{11AF}\u{11A8}\u{115F}\u{1161}");
+    assert_eq!(text.len(), 2);
+    assert_eq!(text.disassemble(), "ㄱㅏㄹㄱㅏ");
+    assert_eq!(
+      text.get_jongseong_with(&ExtractOptions::new().missing_jongseong('_')),
+      "ㄹㄱ_"
+    );
+
+    // 완성형 음절 뒤의 옛한글 종성: 가+ᇰ
+    let text = Hangul::new("가\u{11F0}나");
+    assert_eq!(text.len(), 2);
+    assert!(text.get(0).unwrap().old_hangul().is_some());
+    assert_eq!(text.get_choseong(), "ㄱㄴ");
+    assert_eq!(text.get_jongseong(), "ㆁ");
+    assert_eq!(text.disassemble(), "ㄱㅏㆁㄴㅏ");
+
+    // 종성이 있는 완성형 음절 뒤의 종성은 따로 한 단위: 각+ᆺ
+    let text = Hangul::new("각\u{11BA}");
+    assert_eq!(text.len(), 2);
+    assert!(text.get(0).unwrap().old_hangul().is_none());
+    assert_eq!(text.get(1).unwrap().as_str(), "\u{11BA}");
+
+    // 255바이트보다 긴 자모 연속도 한 음절
+    let long: String = "\u{1100}".repeat(100) + "\u{1161}";
+    let text = Hangul::new(&long);
+    assert_eq!(text.len(), 1);
+    assert_eq!(text.get(0).unwrap().as_str(), long);
+  }
+
+  #[test]
+  fn test_fold_halfwidth() {
+    let hangul = Hangul::new("ﾡￂﾤ\u{FFB5}ￃ");
+    assert_eq!(hangul.disassemble(), "ﾡￂﾤ\u{FFB5}ￃ");
+
+    let folded = hangul.fold_halfwidth();
+    assert_eq!(folded.original(), "ㄱㅏㄴㅅㅐ");
+    assert_eq!(folded.disassemble(), "ㄱㅏㄴㅅㅐ");
+    assert_eq!(crate::assemble(hangul.original()), "간새");
+  }
+
+  #[test]
+  fn test_hanja_to_hangul() {
+    let hangul = Hangul::new("李舜臣 將軍");
+    assert_eq!(hangul.get_choseong(), "李舜臣 將軍");
+
+    let converted = hangul.hanja_to_hangul();
+    assert_eq!(converted.original(), "이순신 장군");
+    assert_eq!(converted.get_choseong(), "ㅇㅅㅅ ㅈㄱ");
+  }
+
+  #[test]
+  fn test_to_nfc_and_to_nfd() {
+    let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ok";
+    assert_eq!(Hangul::new(nfd).to_nfc(), "한글 ok");
+    assert_eq!(Hangul::new("한글 ok").to_nfd(), nfd);
+    assert_eq!(Hangul::new("가\u{11A8}").to_nfc(), "각");
+    assert_eq!(Hangul::new("").to_nfd(), "");
+  }
+
+  #[test]
+  fn test_incomplete_tail_start() {
+    assert_eq!(incomplete_tail_start("abc"), 3);
+    assert_eq!(incomplete_tail_start("안녕"), 3);
+    assert_eq!(incomplete_tail_start("a\u{1100}"), 1);
+    assert_eq!(incomplete_tail_start("a\u{1100}\u{1161}"), 1);
+    assert_eq!(incomplete_tail_start("a\u{1100}\u{1161}\u{11A8}"), 1);
+    assert_eq!(incomplete_tail_start("a\u{1100}\u{1100}"), 1);
+    assert_eq!(incomplete_tail_start("가\u{11F0}"), 0);
+    assert_eq!(incomplete_tail_start(""), 0);
+  }
+
   #[test]
   fn test_get_choseong() {
     let sentence = Hangul::new("안녕하세요");
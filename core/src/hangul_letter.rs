@@ -1,63 +1,134 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
 use crate::choseong::Choseong;
 use crate::jongseong::Jongseong;
 use crate::jungseong::Jungseong;
 use crate::nfc::NFC;
-use crate::nfd::NFD;
+use crate::nfd::{NormalizeError, NFD};
+#[cfg(feature = "alloc")]
+use crate::options::DisassembleOptions;
+
+const HANGUL_BASE: u32 = 0xAC00;
+const JUNGSEONG_COUNT: u32 = 0x15;
+const JONGSEONG_COUNT: u32 = 0x1C;
 
+// 두음법칙에서 ㄹ이 ㅇ이 되는 모음 (랴 려 례 료 류 리)
+const RIEUL_TO_IEUNG_VOWELS: [char; 6] = ['ㅑ', 'ㅕ', 'ㅖ', 'ㅛ', 'ㅠ', 'ㅣ'];
+// 두음법칙에서 ㄴ이 ㅇ이 되는 모음 (녀 뇨 뉴 니)
+const NIEUN_TO_IEUNG_VOWELS: [char; 4] = ['ㅕ', 'ㅛ', 'ㅠ', 'ㅣ'];
+
+// 자모로 음절을 만들 때의 오류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JamoError {
+  // 초성이 될 수 없는 문자 (ㄳ, ㅏ)
+  InvalidChoseong(char),
+  // 중성이 될 수 없는 문자 (ㄱ)
+  InvalidJungseong(char),
+  // 종성이 될 수 없는 문자 (ㄸ, ㅃ, ㅉ)
+  InvalidJongseong(char),
+  // (초성, 중성, 종성) 인덱스가 범위를 벗어남
+  IndexOutOfRange(u8, u8, u8),
+}
+
+impl fmt::Display for JamoError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      JamoError::InvalidChoseong(ch) => write!(f, "Not a valid choseong: {:?}", ch),
+      JamoError::InvalidJungseong(ch) => write!(f, "Not a valid jungseong: {:?}", ch),
+      JamoError::InvalidJongseong(ch) => write!(f, "Not a valid jongseong: {:?}", ch),
+      JamoError::IndexOutOfRange(cho, jung, jong) => write!(
+        f,
+        "Jamo indices out of range: ({}, {}, {})",
+        cho, jung, jong
+      ),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JamoError {}
+
+// 초성/중성/종성 인덱스만 보관하는 3바이트 `Copy` 타입, 문자 값은 필요할 때 계산
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HangulLetter {
-  pub value: String,
-  pub unicode: Vec<u32>,
+  #[cfg_attr(feature = "serde", serde(rename = "cho"))]
   pub choseong: Choseong,
+  #[cfg_attr(feature = "serde", serde(rename = "jung"))]
   pub jungseong: Jungseong,
+  #[cfg_attr(feature = "serde", serde(rename = "jong", default))]
   pub jongseong: Option<Jongseong>,
 }
 
 impl HangulLetter {
+  pub const fn from_parts(
+    choseong: Choseong,
+    jungseong: Jungseong,
+    jongseong: Option<Jongseong>,
+  ) -> Self {
+    Self {
+      choseong,
+      jungseong,
+      jongseong,
+    }
+  }
+
+  // 조합형 또는 호환형 자모로 생성 (ㅎ, ㅏ, ㄴ → 한)
+  pub fn from_jamo(
+    choseong: char,
+    jungseong: char,
+    jongseong: Option<char>,
+  ) -> Result<Self, JamoError> {
+    Ok(Self {
+      choseong: Choseong::try_from(choseong)?,
+      jungseong: Jungseong::try_from(jungseong)?,
+      jongseong: jongseong.map(Jongseong::try_from).transpose()?,
+    })
+  }
+
+  // (초성 0–18, 중성 0–20, 종성 0–27) 인덱스로 생성, 종성 0은 종성 없음
+  pub fn from_indices(choseong: u8, jungseong: u8, jongseong: u8) -> Result<Self, JamoError> {
+    let out_of_range = || JamoError::IndexOutOfRange(choseong, jungseong, jongseong);
+
+    Ok(Self {
+      choseong: Choseong::from_index(choseong).ok_or_else(out_of_range)?,
+      jungseong: Jungseong::from_index(jungseong).ok_or_else(out_of_range)?,
+      jongseong: match jongseong {
+        0 => None,
+        index => Some(Jongseong::from_index(index).ok_or_else(out_of_range)?),
+      },
+    })
+  }
+
   pub fn parse(string: &str) -> Option<Self> {
     if NFC::is_nfc_hangul(string) {
       let ch = string.chars().next().unwrap();
-      let unicode = vec![ch as u32];
-      let NFD(cho, jung, jong) = NFD::normalize(unicode[0]).unwrap();
-
-      return Some(Self {
-        value: ch.to_string(),
-        unicode,
-        choseong: Choseong::new(cho),
-        jungseong: Jungseong::new(jung),
-        jongseong: jong.map(Jongseong::new),
-      });
+      return Self::parse_from_char(ch);
     }
 
     if NFD::is_nfd_hangul(string) {
-      let chars: Vec<char> = string.chars().collect();
-      let choseong = Choseong::new(chars[0] as u32);
-      let jungseong = Jungseong::new(chars[1] as u32);
-      let jongseong = if chars.len() == 3 {
-        Some(Jongseong::new(chars[2] as u32))
-      } else {
-        None
-      };
+      let mut chars = string.chars();
+      let choseong = Choseong::new(chars.next().unwrap() as u32);
+      let jungseong = Jungseong::new(chars.next().unwrap() as u32);
+      let jongseong = chars.next().map(|ch| Jongseong::new(ch as u32));
 
       return Some(Self {
-        value: string.to_string(),
-        unicode: chars.iter().map(|c| *c as u32).collect(),
         choseong,
         jungseong,
         jongseong,
       });
     }
 
-    return None;
+    None
   }
 
   pub fn parse_from_char(nfc_char: char) -> Option<Self> {
     if NFC::is_nfc_hangul_char(nfc_char) {
-      let unicode = nfc_char as u32;
-      let NFD(cho, jung, jong) = NFD::normalize(unicode).unwrap();
+      let NFD(cho, jung, jong) = NFD::normalize(nfc_char as u32).unwrap();
 
       return Some(Self {
-        value: nfc_char.to_string(),
-        unicode: vec![unicode],
         choseong: Choseong::new(cho),
         jungseong: Jungseong::new(jung),
         jongseong: jong.map(Jongseong::new),
@@ -66,28 +137,129 @@ impl HangulLetter {
     None
   }
 
+  // 완성형(NFC) 음절 문자
+  pub fn value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.unicode()) }
+  }
+
+  // 완성형(NFC) 음절 유니코드
+  pub fn unicode(&self) -> u32 {
+    let (choseong_index, jungseong_index, jongseong_index) = self.indices();
+
+    HANGUL_BASE
+      + (choseong_index as u32 * JUNGSEONG_COUNT + jungseong_index as u32) * JONGSEONG_COUNT
+      + jongseong_index as u32
+  }
+
+  // (초성 0–18, 중성 0–20, 종성 0–27) 인덱스, 종성이 없으면 0
+  pub fn indices(&self) -> (u8, u8, u8) {
+    (
+      self.choseong.index(),
+      self.jungseong.index(),
+      self.jongseong.map_or(0, |jong| jong.index()),
+    )
+  }
+
+  #[cfg(feature = "alloc")]
   pub fn disassemble(&self) -> String {
-    let mut result = String::with_capacity(4);
-
-    result.push(self.choseong.compatibility_value);
-    result.push(self.jungseong.compatibility_value);
-
-    if let Some(ref jong) = self.jongseong {
-      if jong.is_complex_jongseong() {
-        for c in jong.decompose_complex_jongseong() {
-          result.push(c);
-        }
-      } else {
-        result.push(jong.compatibility_value);
-      }
-    }
+    self.disassemble_with(&DisassembleOptions::default())
+  }
 
+  // 옵션에 따라 자모로 분해, 구분자와 한글이 아닌 문자 옵션은 사용하지 않음
+  #[cfg(feature = "alloc")]
+  pub fn disassemble_with(&self, options: &DisassembleOptions) -> String {
+    let mut result = String::with_capacity(8);
+    options.push_jamo(self, |ch| result.push(ch));
     result
   }
 
   pub fn has_batchim(&self) -> bool {
     self.jongseong.is_some()
   }
+
+  pub fn with_choseong(&self, choseong: Choseong) -> Self {
+    Self { choseong, ..*self }
+  }
+
+  pub fn with_jungseong(&self, jungseong: Jungseong) -> Self {
+    Self { jungseong, ..*self }
+  }
+
+  // 받침을 추가하거나 바꿈
+  pub fn with_jongseong(&self, jongseong: Jongseong) -> Self {
+    Self {
+      jongseong: Some(jongseong),
+      ..*self
+    }
+  }
+
+  pub fn remove_jongseong(&self) -> Self {
+    Self {
+      jongseong: None,
+      ..*self
+    }
+  }
+
+  // 마지막 자모 하나를 뺀 음절 (닭 → 달 → 다, 과 → 고)
+  // 초성과 단모음만 남아 음절이 될 수 없으면 `None`
+  pub fn remove_last_jamo(&self) -> Option<Self> {
+    if let Some(jongseong) = self.jongseong {
+      return Some(match jongseong.split() {
+        (first, Some(_)) => self.with_jongseong(first),
+        _ => self.remove_jongseong(),
+      });
+    }
+
+    match self.jungseong.split() {
+      (first, Some(_)) => Some(self.with_jungseong(first)),
+      _ => None,
+    }
+  }
+
+  // 단어 첫머리 음절에 두음법칙을 적용 (녀 → 여, 리 → 이, 래 → 내, 로 → 노)
+  // 중성에 따라 ㄹ은 ㅇ이나 ㄴ으로, ㄴ은 ㅇ으로 바뀌며, 해당하지 않으면 그대로
+  pub fn apply_dueum(&self) -> Self {
+    let jungseong = self.jungseong.compatibility_value();
+
+    match self.choseong.compatibility_value() {
+      'ㄹ' if RIEUL_TO_IEUNG_VOWELS.contains(&jungseong) => self.with_choseong(Choseong::IEUNG),
+      'ㄹ' => self.with_choseong(Choseong::NIEUN),
+      'ㄴ' if NIEUN_TO_IEUNG_VOWELS.contains(&jungseong) => self.with_choseong(Choseong::IEUNG),
+      _ => *self,
+    }
+  }
+
+  // 두음법칙을 적용하면 이 음절이 되는 본래 음절 (이 → 리, 니 / 노 → 로)
+  // ㄹ로 시작하는 음절을 먼저 반환하며, 두음법칙의 결과가 아니면 비어 있음
+  pub fn dueum_originals(&self) -> impl Iterator<Item = Self> {
+    let jungseong = self.jungseong.compatibility_value();
+    let rieul = self.with_choseong(Choseong::RIEUL);
+    let nieun = self.with_choseong(Choseong::NIEUN);
+
+    let originals = match self.choseong.compatibility_value() {
+      'ㅇ' if NIEUN_TO_IEUNG_VOWELS.contains(&jungseong) => [Some(rieul), Some(nieun)],
+      'ㅇ' if RIEUL_TO_IEUNG_VOWELS.contains(&jungseong) => [Some(rieul), None],
+      'ㄴ' if !RIEUL_TO_IEUNG_VOWELS.contains(&jungseong) => [Some(rieul), None],
+      _ => [None, None],
+    };
+
+    originals.into_iter().flatten()
+  }
+}
+
+impl From<HangulLetter> for char {
+  fn from(letter: HangulLetter) -> Self {
+    letter.value()
+  }
+}
+
+// 완성형(NFC) 음절 문자만 허용
+impl TryFrom<char> for HangulLetter {
+  type Error = NormalizeError;
+
+  fn try_from(ch: char) -> Result<Self, Self::Error> {
+    Self::parse_from_char(ch).ok_or(NormalizeError::InvalidHangul)
+  }
 }
 
 #[cfg(test)]
@@ -97,62 +269,62 @@ mod tests {
   #[test]
   fn test_parse_nfc_hangul() {
     let hangul = HangulLetter::parse("가").unwrap();
-    assert_eq!(hangul.value, "가");
-    assert_eq!(hangul.unicode, vec![0xAC00]);
-    assert_eq!(hangul.choseong.compatibility_value, 'ㄱ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(hangul.value(), '가');
+    assert_eq!(hangul.unicode(), 0xAC00);
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㄱ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
     assert!(hangul.jongseong.is_none());
 
     let hangul = HangulLetter::parse("한").unwrap();
-    assert_eq!(hangul.value, "한");
-    assert_eq!(hangul.unicode, vec![0xD55C]);
-    assert_eq!(hangul.choseong.compatibility_value, 'ㅎ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
-    assert_eq!(hangul.jongseong.unwrap().compatibility_value, 'ㄴ');
+    assert_eq!(hangul.value(), '한');
+    assert_eq!(hangul.unicode(), 0xD55C);
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㅎ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
+    assert_eq!(hangul.jongseong.unwrap().compatibility_value(), 'ㄴ');
 
     let hangul = HangulLetter::parse("쌍").unwrap();
-    assert_eq!(hangul.value, "쌍");
-    assert_eq!(hangul.choseong.compatibility_value, 'ㅆ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
-    assert_eq!(hangul.jongseong.unwrap().compatibility_value, 'ㅇ');
+    assert_eq!(hangul.value(), '쌍');
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㅆ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
+    assert_eq!(hangul.jongseong.unwrap().compatibility_value(), 'ㅇ');
 
     let hangul = HangulLetter::parse("귀").unwrap();
-    assert_eq!(hangul.value, "귀");
-    assert_eq!(hangul.choseong.compatibility_value, 'ㄱ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅟ');
+    assert_eq!(hangul.value(), '귀');
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㄱ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅟ');
     assert!(hangul.jongseong.is_none());
 
     let hangul = HangulLetter::parse("값").unwrap();
-    assert_eq!(hangul.value, "값");
-    assert_eq!(hangul.choseong.compatibility_value, 'ㄱ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
-    assert_eq!(hangul.jongseong.unwrap().compatibility_value, 'ㅄ');
+    assert_eq!(hangul.value(), '값');
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㄱ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
+    assert_eq!(hangul.jongseong.unwrap().compatibility_value(), 'ㅄ');
   }
 
   #[test]
   fn test_parse_nfd_hangul() {
     let nfd_ga = "\u{1100}\u{1161}";
     let hangul = HangulLetter::parse(nfd_ga).unwrap();
-    assert_eq!(hangul.value, nfd_ga);
-    assert_eq!(hangul.unicode, vec![0x1100, 0x1161]);
-    assert_eq!(hangul.choseong.compatibility_value, 'ㄱ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(hangul.value(), '가');
+    assert_eq!(hangul.unicode(), 0xAC00);
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㄱ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
     assert!(hangul.jongseong.is_none());
 
     let nfd_han = "\u{1112}\u{1161}\u{11AB}";
     let hangul = HangulLetter::parse(nfd_han).unwrap();
-    assert_eq!(hangul.value, nfd_han);
-    assert_eq!(hangul.unicode, vec![0x1112, 0x1161, 0x11AB]);
-    assert_eq!(hangul.choseong.compatibility_value, 'ㅎ');
-    assert_eq!(hangul.jungseong.compatibility_value, 'ㅏ');
-    assert_eq!(hangul.jongseong.unwrap().compatibility_value, 'ㄴ');
+    assert_eq!(hangul.value(), '한');
+    assert_eq!(hangul.unicode(), 0xD55C);
+    assert_eq!(hangul.choseong.compatibility_value(), 'ㅎ');
+    assert_eq!(hangul.jungseong.compatibility_value(), 'ㅏ');
+    assert_eq!(hangul.jongseong.unwrap().compatibility_value(), 'ㄴ');
   }
 
   #[test]
   fn test_parse_from_char() {
     let hangul = HangulLetter::parse_from_char('한').unwrap();
-    assert_eq!(hangul.value, "한");
-    assert_eq!(hangul.unicode, vec![0xD55C]);
+    assert_eq!(hangul.value(), '한');
+    assert_eq!(hangul.unicode(), 0xD55C);
 
     assert!(HangulLetter::parse_from_char('a').is_none());
     assert!(HangulLetter::parse_from_char('ㄱ').is_none());
@@ -160,6 +332,7 @@ mod tests {
   }
 
   #[test]
+  #[cfg(feature = "alloc")]
   fn test_disassemble() {
     let hangul = HangulLetter::parse("가").unwrap();
     assert_eq!(hangul.disassemble(), "ㄱㅏ");
@@ -174,6 +347,14 @@ mod tests {
     assert_eq!(hangul.disassemble(), "ㄱㅏㅂㅅ");
   }
 
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_disassemble_with() {
+    let hangul = HangulLetter::parse("괜").unwrap();
+    let options = DisassembleOptions::new().split_complex_jungseong(true);
+    assert_eq!(hangul.disassemble_with(&options), "ㄱㅗㅐㄴ");
+  }
+
   #[test]
   fn test_has_batchim() {
     assert!(HangulLetter::parse("한").unwrap().has_batchim());
@@ -183,6 +364,167 @@ mod tests {
     assert!(!HangulLetter::parse("뉘").unwrap().has_batchim());
   }
 
+  #[test]
+  fn test_indices() {
+    assert_eq!(HangulLetter::parse("가").unwrap().indices(), (0, 0, 0));
+    assert_eq!(HangulLetter::parse("한").unwrap().indices(), (18, 0, 4));
+    assert_eq!(HangulLetter::parse("힣").unwrap().indices(), (18, 20, 27));
+  }
+
+  #[test]
+  fn test_compact_representation() {
+    assert_eq!(core::mem::size_of::<HangulLetter>(), 3);
+
+    let hangul = HangulLetter::parse("닭").unwrap();
+    let copied = hangul;
+    assert_eq!(hangul, copied);
+    assert_eq!(copied.value(), '닭');
+  }
+
+  #[test]
+  fn test_from_parts() {
+    let letter = HangulLetter::from_parts(
+      Choseong::new('ㅎ' as u32),
+      Jungseong::new(0x1161),
+      Some(Jongseong::new('ㄴ' as u32)),
+    );
+    assert_eq!(char::from(letter), '한');
+    assert_eq!(HangulLetter::try_from('한'), Ok(letter));
+    assert_eq!(
+      HangulLetter::try_from('ㅎ'),
+      Err(NormalizeError::InvalidHangul)
+    );
+  }
+
+  #[test]
+  fn test_from_jamo() {
+    assert_eq!(
+      HangulLetter::from_jamo('ㄷ', 'ㅏ', Some('ㄺ')).map(|letter| letter.value()),
+      Ok('닭')
+    );
+    assert_eq!(
+      HangulLetter::from_jamo('\u{1104}', '\u{1161}', None).map(|letter| letter.value()),
+      Ok('따')
+    );
+    assert_eq!(
+      HangulLetter::from_jamo('ㄱ', 'ㅏ', Some('ㄸ')),
+      Err(JamoError::InvalidJongseong('ㄸ'))
+    );
+    assert_eq!(
+      HangulLetter::from_jamo('ㄳ', 'ㅏ', None),
+      Err(JamoError::InvalidChoseong('ㄳ'))
+    );
+    assert_eq!(
+      HangulLetter::from_jamo('ㄱ', 'ㄱ', None),
+      Err(JamoError::InvalidJungseong('ㄱ'))
+    );
+  }
+
+  #[test]
+  fn test_from_indices() {
+    assert_eq!(
+      HangulLetter::from_indices(18, 20, 27).map(|letter| letter.value()),
+      Ok('힣')
+    );
+    assert_eq!(
+      HangulLetter::from_indices(0, 0, 0).map(|letter| letter.value()),
+      Ok('가')
+    );
+    assert_eq!(
+      HangulLetter::from_indices(19, 0, 0),
+      Err(JamoError::IndexOutOfRange(19, 0, 0))
+    );
+    assert_eq!(
+      HangulLetter::from_indices(0, 0, 28),
+      Err(JamoError::IndexOutOfRange(0, 0, 28))
+    );
+  }
+
+  #[test]
+  fn test_with_components() {
+    let letter = HangulLetter::parse("한").unwrap();
+    assert_eq!(
+      letter.with_choseong(Choseong::new('ㄱ' as u32)).value(),
+      '간'
+    );
+    assert_eq!(
+      letter.with_jungseong(Jungseong::new('ㅘ' as u32)).value(),
+      '환'
+    );
+    assert_eq!(
+      letter.with_jongseong(Jongseong::new('ㄺ' as u32)).value(),
+      '핡'
+    );
+    assert_eq!(letter.remove_jongseong().value(), '하');
+    assert_eq!(letter.remove_jongseong().remove_jongseong().value(), '하');
+  }
+
+  #[test]
+  fn test_remove_last_jamo() {
+    let remove = |text: &str| {
+      HangulLetter::parse(text)
+        .unwrap()
+        .remove_last_jamo()
+        .map(|letter| letter.value())
+    };
+
+    assert_eq!(remove("닭"), Some('달'));
+    assert_eq!(remove("달"), Some('다'));
+    assert_eq!(remove("다"), None);
+    assert_eq!(remove("괎"), Some('괍'));
+    assert_eq!(remove("과"), Some('고'));
+    assert_eq!(remove("의"), Some('으'));
+  }
+
+  #[test]
+  fn test_apply_dueum() {
+    let apply = |text: &str| HangulLetter::parse(text).unwrap().apply_dueum().value();
+
+    assert_eq!(apply("녀"), '여');
+    assert_eq!(apply("뇨"), '요');
+    assert_eq!(apply("니"), '이');
+    assert_eq!(apply("리"), '이');
+    assert_eq!(apply("량"), '양');
+    assert_eq!(apply("례"), '예');
+    assert_eq!(apply("래"), '내');
+    assert_eq!(apply("로"), '노');
+    assert_eq!(apply("뢰"), '뇌');
+    assert_eq!(apply("르"), '느');
+    assert_eq!(apply("나"), '나');
+    assert_eq!(apply("냐"), '냐');
+    assert_eq!(apply("가"), '가');
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_dueum_originals() {
+    let originals = |text: &str| {
+      HangulLetter::parse(text)
+        .unwrap()
+        .dueum_originals()
+        .map(|letter| letter.value())
+        .collect::<alloc::vec::Vec<_>>()
+    };
+
+    assert_eq!(originals("이"), ['리', '니']);
+    assert_eq!(originals("여"), ['려', '녀']);
+    assert_eq!(originals("양"), ['량']);
+    assert_eq!(originals("예"), ['례']);
+    assert_eq!(originals("노"), ['로']);
+    assert_eq!(originals("내"), ['래']);
+    assert!(originals("녀").is_empty());
+    assert!(originals("아").is_empty());
+    assert!(originals("가").is_empty());
+
+    // 모든 음절에서 본래 음절에 두음법칙을 적용하면 원래 음절이 됨
+    for unicode in 0xAC00..=0xD7A3 {
+      let letter = HangulLetter::parse_from_char(char::from_u32(unicode).unwrap()).unwrap();
+      for original in letter.dueum_originals() {
+        assert_eq!(original.apply_dueum(), letter);
+      }
+    }
+  }
+
   #[test]
   fn test_invalid_input() {
     assert!(HangulLetter::parse("a").is_none());
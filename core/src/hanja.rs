@@ -0,0 +1,130 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::choseong::Choseong;
+#[cfg(feature = "alloc")]
+use crate::hangul_letter::HangulLetter;
+use crate::hanja_table::HANJA_READINGS;
+
+// 不 (통합 한자, 호환 한자)
+#[cfg(feature = "alloc")]
+const BUL: [char; 2] = ['不', '\u{F967}'];
+
+// 한자의 본음 (李 → 리, 女 → 녀), 독음표에 없는 한자이면 `None`
+// 두음법칙은 적용하지 않으며, 호환 한자(U+F900–FA0B)도 같은 본음을 반환
+// 독음이 여럿인 한자는 대표 독음 (金 → 금, 宅 → 택)
+pub fn hanja_reading(ch: char) -> Option<char> {
+  HANJA_READINGS
+    .binary_search_by_key(&ch, |&(hanja, _)| hanja)
+    .ok()
+    .map(|index| HANJA_READINGS[index].1)
+}
+
+// 한자를 한글 독음으로 바꾼 문자열, 한자가 아닌 문자는 그대로 유지
+// 한글 음절 뒤가 아닌 단어 첫머리 한자에는 두음법칙을 적용 (李舜臣 → 이순신, 男女 → 남녀)
+// 모음이나 ㄴ 받침 뒤의 렬, 률은 열, 율로 읽음 (羅列 → 나열, 法律 → 법률)
+// 不은 ㄷ, ㅈ으로 시작하는 한자 앞에서 부로 읽음 (不安 → 불안, 不足 → 부족)
+#[cfg(feature = "alloc")]
+pub fn hanja_to_hangul(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut previous: Option<HangulLetter> = None;
+  let mut chars = text.chars().peekable();
+
+  while let Some(ch) = chars.next() {
+    let Some(letter) = hanja_reading(ch).and_then(HangulLetter::parse_from_char) else {
+      result.push(ch);
+      previous = HangulLetter::parse_from_char(ch);
+      continue;
+    };
+
+    let letter = match chars.peek() {
+      Some(&next) if BUL.contains(&ch) => apply_bu_rule(letter, next),
+      _ => letter,
+    };
+    let letter = match previous {
+      None => letter.apply_dueum(),
+      Some(previous) => apply_yeol_yul_rule(previous, letter),
+    };
+    result.push(letter.value());
+    previous = Some(letter);
+  }
+
+  result
+}
+
+// 不 다음 한자의 독음이 ㄷ, ㅈ으로 시작하면 불 → 부
+#[cfg(feature = "alloc")]
+fn apply_bu_rule(letter: HangulLetter, next: char) -> HangulLetter {
+  let before_digeut_jieut = hanja_reading(next)
+    .and_then(HangulLetter::parse_from_char)
+    .is_some_and(|next| matches!(next.choseong.compatibility_value(), 'ㄷ' | 'ㅈ'));
+
+  match before_digeut_jieut {
+    true => letter.remove_jongseong(),
+    false => letter,
+  }
+}
+
+// 모음이나 ㄴ 받침 뒤의 렬, 률 → 열, 율
+#[cfg(feature = "alloc")]
+fn apply_yeol_yul_rule(previous: HangulLetter, letter: HangulLetter) -> HangulLetter {
+  let after_vowel_or_nieun = previous
+    .jongseong
+    .is_none_or(|jongseong| jongseong.compatibility_value() == 'ㄴ');
+
+  match letter.value() {
+    '렬' | '률' if after_vowel_or_nieun => letter.with_choseong(Choseong::IEUNG),
+    _ => letter,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_hanja_reading() {
+    assert_eq!(hanja_reading('大'), Some('대'));
+    assert_eq!(hanja_reading('韓'), Some('한'));
+    assert_eq!(hanja_reading('李'), Some('리'));
+    assert_eq!(hanja_reading('女'), Some('녀'));
+    assert_eq!(hanja_reading('樂'), Some('악'));
+    // 호환 한자 樂(낙), 羅(나)는 본음으로
+    assert_eq!(hanja_reading('\u{F914}'), Some('락'));
+    assert_eq!(hanja_reading('\u{F90F}'), Some('라'));
+    // 독음이 여럿인 통합 한자는 대표 독음
+    assert_eq!(hanja_reading('金'), Some('금'));
+    assert_eq!(hanja_reading('宅'), Some('택'));
+    assert_eq!(hanja_reading('不'), Some('불'));
+    // KS X 1001 밖의 통합 한자 (일본 신자체)
+    assert_eq!(hanja_reading('国'), Some('국'));
+    assert_eq!(hanja_reading('駅'), Some('역'));
+    assert_eq!(hanja_reading('가'), None);
+    assert_eq!(hanja_reading('a'), None);
+
+    assert!(HANJA_READINGS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_hanja_to_hangul() {
+    assert_eq!(hanja_to_hangul("大韓民國"), "대한민국");
+    assert_eq!(hanja_to_hangul("李舜臣"), "이순신");
+    assert_eq!(hanja_to_hangul("女子"), "여자");
+    assert_eq!(hanja_to_hangul("男女"), "남녀");
+    assert_eq!(hanja_to_hangul("老人 來日"), "노인 내일");
+    assert_eq!(hanja_to_hangul("勞動"), "노동");
+    assert_eq!(hanja_to_hangul("新羅"), "신라");
+    assert_eq!(
+      hanja_to_hangul("羅列 分裂 旋律 法律"),
+      "나열 분열 선율 법률"
+    );
+    assert_eq!(hanja_to_hangul("韓國語 사전"), "한국어 사전");
+    assert_eq!(hanja_to_hangul("(株)"), "(주)");
+    assert_eq!(hanja_to_hangul("金曜日"), "금요일");
+    assert_eq!(hanja_to_hangul("宅配"), "택배");
+    assert_eq!(hanja_to_hangul("不安 不足 不動産"), "불안 부족 부동산");
+    assert_eq!(hanja_to_hangul("学校 来年"), "학교 내년");
+  }
+}
@@ -0,0 +1,5157 @@
+// KS X 1001 한자 4,888자(호환 한자 268자 포함)와 KS X 1001 밖의 자주 쓰는 통합 한자(일본 신자체 등)
+// 263자의 독음, 한자 코드 순으로 정렬
+// 독음이 여럿인 통합 한자는 대표 독음(金 금, 宅 택, 不 불)을 쓰고, 두음법칙이 적용된 자리의
+// 호환 한자(樂 U+F914 낙)는 본음(락)으로 바꿔 저장
+pub(crate) static HANJA_READINGS: [(char, char); 5151] = [
+  ('一', '일'),
+  ('丁', '정'),
+  ('七', '칠'),
+  ('万', '만'),
+  ('丈', '장'),
+  ('三', '삼'),
+  ('上', '상'),
+  ('下', '하'),
+  ('不', '불'),
+  ('与', '여'),
+  ('丑', '축'),
+  ('且', '차'),
+  ('丕', '비'),
+  ('世', '세'),
+  ('丘', '구'),
+  ('丙', '병'),
+  ('丞', '승'),
+  ('両', '량'),
+  ('並', '병'),
+  ('中', '중'),
+  ('串', '관'),
+  ('丸', '환'),
+  ('丹', '단'),
+  ('主', '주'),
+  ('乂', '예'),
+  ('乃', '내'),
+  ('久', '구'),
+  ('之', '지'),
+  ('乍', '사'),
+  ('乎', '호'),
+  ('乏', '핍'),
+  ('乖', '괴'),
+  ('乗', '승'),
+  ('乘', '승'),
+  ('乙', '을'),
+  ('九', '구'),
+  ('乞', '걸'),
+  ('也', '야'),
+  ('乫', '갈'),
+  ('乭', '돌'),
+  ('乱', '란'),
+  ('乳', '유'),
+  ('乶', '볼'),
+  ('乷', '살'),
+  ('乾', '건'),
+  ('亀', '구'),
+  ('亂', '란'),
+  ('了', '료'),
+  ('予', '여'),
+  ('争', '쟁'),
+  ('事', '사'),
+  ('二', '이'),
+  ('于', '우'),
+  ('亐', '울'),
+  ('云', '운'),
+  ('互', '호'),
+  ('五', '오'),
+  ('井', '정'),
+  ('亘', '긍'),
+  ('些', '사'),
+  ('亜', '아'),
+  ('亞', '아'),
+  ('亡', '망'),
+  ('亢', '항'),
+  ('交', '교'),
+  ('亥', '해'),
+  ('亦', '역'),
+  ('亨', '형'),
+  ('享', '향'),
+  ('京', '경'),
+  ('亭', '정'),
+  ('亮', '량'),
+  ('亶', '단'),
+  ('人', '인'),
+  ('什', '십'),
+  ('仁', '인'),
+  ('仄', '측'),
+  ('仇', '구'),
+  ('今', '금'),
+  ('介', '개'),
+  ('仍', '잉'),
+  ('仏', '불'),
+  ('仔', '자'),
+  ('仕', '사'),
+  ('他', '타'),
+  ('仗', '장'),
+  ('付', '부'),
+  ('仙', '선'),
+  ('仝', '동'),
+  ('仟', '천'),
+  ('代', '대'),
+  ('令', '령'),
+  ('以', '이'),
+  ('仮', '가'),
+  ('仰', '앙'),
+  ('仲', '중'),
+  ('件', '건'),
+  ('价', '개'),
+  ('任', '임'),
+  ('企', '기'),
+  ('伉', '항'),
+  ('伊', '이'),
+  ('伋', '급'),
+  ('伍', '오'),
+  ('伎', '기'),
+  ('伏', '복'),
+  ('伐', '벌'),
+  ('休', '휴'),
+  ('会', '회'),
+  ('伝', '전'),
+  ('伯', '백'),
+  ('伴', '반'),
+  ('伶', '령'),
+  ('伸', '신'),
+  ('伺', '사'),
+  ('似', '사'),
+  ('伽', '가'),
+  ('佃', '전'),
+  ('但', '단'),
+  ('佇', '저'),
+  ('佈', '포'),
+  ('位', '위'),
+  ('低', '저'),
+  ('住', '주'),
+  ('佐', '좌'),
+  ('佑', '우'),
+  ('体', '체'),
+  ('何', '하'),
+  ('余', '여'),
+  ('佚', '일'),
+  ('佛', '불'),
+  ('作', '작'),
+  ('佩', '패'),
+  ('佯', '양'),
+  ('佰', '백'),
+  ('佳', '가'),
+  ('併', '병'),
+  ('佶', '길'),
+  ('佺', '전'),
+  ('佾', '일'),
+  ('使', '사'),
+  ('侁', '신'),
+  ('侃', '간'),
+  ('侄', '질'),
+  ('來', '래'),
+  ('侈', '치'),
+  ('侊', '광'),
+  ('例', '례'),
+  ('侍', '시'),
+  ('侏', '주'),
+  ('侑', '유'),
+  ('侖', '륜'),
+  ('侘', '차'),
+  ('供', '공'),
+  ('依', '의'),
+  ('価', '가'),
+  ('侮', '모'),
+  ('侯', '후'),
+  ('侵', '침'),
+  ('侶', '려'),
+  ('便', '편'),
+  ('係', '계'),
+  ('促', '촉'),
+  ('俄', '아'),
+  ('俉', '오'),
+  ('俊', '준'),
+  ('俎', '조'),
+  ('俑', '용'),
+  ('俓', '경'),
+  ('俔', '현'),
+  ('俗', '속'),
+  ('俚', '리'),
+  ('保', '보'),
+  ('俟', '사'),
+  ('俠', '협'),
+  ('信', '신'),
+  ('修', '수'),
+  ('俯', '부'),
+  ('俱', '구'),
+  ('俳', '배'),
+  ('俵', '표'),
+  ('俸', '봉'),
+  ('俺', '엄'),
+  ('倂', '병'),
+  ('倆', '량'),
+  ('倉', '창'),
+  ('個', '개'),
+  ('倍', '배'),
+  ('們', '문'),
+  ('倒', '도'),
+  ('倖', '행'),
+  ('候', '후'),
+  ('倚', '의'),
+  ('倜', '척'),
+  ('倞', '경'),
+  ('借', '차'),
+  ('倡', '창'),
+  ('倣', '방'),
+  ('値', '치'),
+  ('倦', '권'),
+  ('倧', '종'),
+  ('倨', '거'),
+  ('倪', '예'),
+  ('倫', '륜'),
+  ('倬', '탁'),
+  ('倭', '왜'),
+  ('倹', '검'),
+  ('倻', '야'),
+  ('偃', '언'),
+  ('假', '가'),
+  ('偈', '게'),
+  ('偉', '위'),
+  ('偏', '편'),
+  ('偕', '해'),
+  ('做', '주'),
+  ('停', '정'),
+  ('健', '건'),
+  ('側', '측'),
+  ('偵', '정'),
+  ('偶', '우'),
+  ('偸', '투'),
+  ('偽', '위'),
+  ('傀', '괴'),
+  ('傅', '부'),
+  ('傍', '방'),
+  ('傑', '걸'),
+  ('傘', '산'),
+  ('備', '비'),
+  ('催', '최'),
+  ('傭', '용'),
+  ('傲', '오'),
+  ('傳', '전'),
+  ('債', '채'),
+  ('傷', '상'),
+  ('傾', '경'),
+  ('僅', '근'),
+  ('僉', '첨'),
+  ('僊', '선'),
+  ('像', '상'),
+  ('僑', '교'),
+  ('僕', '복'),
+  ('僖', '희'),
+  ('僚', '료'),
+  ('僞', '위'),
+  ('僥', '요'),
+  ('僧', '승'),
+  ('僭', '참'),
+  ('價', '가'),
+  ('僻', '벽'),
+  ('僿', '사'),
+  ('儀', '의'),
+  ('儁', '준'),
+  ('億', '억'),
+  ('儆', '경'),
+  ('儉', '검'),
+  ('儒', '유'),
+  ('償', '상'),
+  ('儡', '뢰'),
+  ('優', '우'),
+  ('儲', '저'),
+  ('儷', '려'),
+  ('儺', '나'),
+  ('儼', '엄'),
+  ('兀', '올'),
+  ('允', '윤'),
+  ('元', '원'),
+  ('兄', '형'),
+  ('充', '충'),
+  ('兆', '조'),
+  ('兇', '흉'),
+  ('先', '선'),
+  ('光', '광'),
+  ('克', '극'),
+  ('兌', '태'),
+  ('免', '면'),
+  ('兎', '토'),
+  ('児', '아'),
+  ('兒', '아'),
+  ('党', '당'),
+  ('兜', '두'),
+  ('兢', '긍'),
+  ('入', '입'),
+  ('內', '내'),
+  ('全', '전'),
+  ('兩', '량'),
+  ('兪', '유'),
+  ('八', '팔'),
+  ('公', '공'),
+  ('六', '륙'),
+  ('兮', '혜'),
+  ('共', '공'),
+  ('兵', '병'),
+  ('其', '기'),
+  ('具', '구'),
+  ('典', '전'),
+  ('兼', '겸'),
+  ('冀', '기'),
+  ('円', '엔'),
+  ('冊', '책'),
+  ('再', '재'),
+  ('冒', '모'),
+  ('冕', '면'),
+  ('冗', '용'),
+  ('写', '사'),
+  ('冠', '관'),
+  ('冥', '명'),
+  ('冪', '멱'),
+  ('冬', '동'),
+  ('冶', '야'),
+  ('冷', '랭'),
+  ('冽', '렬'),
+  ('凄', '처'),
+  ('准', '준'),
+  ('凉', '량'),
+  ('凋', '조'),
+  ('凌', '릉'),
+  ('凍', '동'),
+  ('凜', '름'),
+  ('凝', '응'),
+  ('凞', '희'),
+  ('凡', '범'),
+  ('処', '처'),
+  ('凰', '황'),
+  ('凱', '개'),
+  ('凶', '흉'),
+  ('凸', '철'),
+  ('凹', '요'),
+  ('出', '출'),
+  ('函', '함'),
+  ('刀', '도'),
+  ('刃', '인'),
+  ('分', '분'),
+  ('切', '절'),
+  ('刈', '예'),
+  ('刊', '간'),
+  ('刎', '문'),
+  ('刑', '형'),
+  ('列', '렬'),
+  ('初', '초'),
+  ('判', '판'),
+  ('別', '별'),
+  ('利', '리'),
+  ('刪', '산'),
+  ('刮', '괄'),
+  ('到', '도'),
+  ('制', '제'),
+  ('刷', '쇄'),
+  ('券', '권'),
+  ('刹', '찰'),
+  ('刺', '자'),
+  ('刻', '각'),
+  ('剃', '체'),
+  ('則', '칙'),
+  ('削', '삭'),
+  ('剋', '극'),
+  ('剌', '랄'),
+  ('前', '전'),
+  ('剔', '척'),
+  ('剖', '부'),
+  ('剛', '강'),
+  ('剝', '박'),
+  ('剡', '섬'),
+  ('剣', '검'),
+  ('剤', '제'),
+  ('剩', '잉'),
+  ('剪', '전'),
+  ('副', '부'),
+  ('剰', '잉'),
+  ('割', '할'),
+  ('創', '창'),
+  ('剽', '표'),
+  ('剿', '초'),
+  ('劃', '획'),
+  ('劇', '극'),
+  ('劈', '벽'),
+  ('劉', '류'),
+  ('劍', '검'),
+  ('劑', '제'),
+  ('劒', '검'),
+  ('力', '력'),
+  ('功', '공'),
+  ('加', '가'),
+  ('劣', '렬'),
+  ('劤', '근'),
+  ('助', '조'),
+  ('努', '노'),
+  ('劫', '겁'),
+  ('励', '려'),
+  ('労', '로'),
+  ('効', '효'),
+  ('劾', '핵'),
+  ('勁', '경'),
+  ('勃', '발'),
+  ('勅', '칙'),
+  ('勇', '용'),
+  ('勉', '면'),
+  ('勍', '경'),
+  ('勒', '륵'),
+  ('動', '동'),
+  ('勖', '욱'),
+  ('勘', '감'),
+  ('務', '무'),
+  ('勛', '훈'),
+  ('勝', '승'),
+  ('勞', '로'),
+  ('募', '모'),
+  ('勢', '세'),
+  ('勣', '적'),
+  ('勤', '근'),
+  ('勧', '권'),
+  ('勲', '훈'),
+  ('勳', '훈'),
+  ('勵', '려'),
+  ('勸', '권'),
+  ('勺', '작'),
+  ('勻', '균'),
+  ('勾', '구'),
+  ('勿', '물'),
+  ('包', '포'),
+  ('匈', '흉'),
+  ('匍', '포'),
+  ('匏', '포'),
+  ('匐', '복'),
+  ('匕', '비'),
+  ('化', '화'),
+  ('北', '북'),
+  ('匙', '시'),
+  ('匠', '장'),
+  ('匡', '광'),
+  ('匣', '갑'),
+  ('匪', '비'),
+  ('匯', '회'),
+  ('匹', '필'),
+  ('区', '구'),
+  ('医', '의'),
+  ('匿', '닉'),
+  ('區', '구'),
+  ('十', '십'),
+  ('千', '천'),
+  ('卄', '입'),
+  ('升', '승'),
+  ('午', '오'),
+  ('卉', '훼'),
+  ('半', '반'),
+  ('卍', '만'),
+  ('卑', '비'),
+  ('卒', '졸'),
+  ('卓', '탁'),
+  ('協', '협'),
+  ('南', '남'),
+  ('単', '단'),
+  ('博', '박'),
+  ('卜', '복'),
+  ('卞', '변'),
+  ('占', '점'),
+  ('卦', '괘'),
+  ('卨', '설'),
+  ('卯', '묘'),
+  ('印', '인'),
+  ('危', '위'),
+  ('即', '즉'),
+  ('却', '각'),
+  ('卵', '란'),
+  ('卷', '권'),
+  ('卽', '즉'),
+  ('卿', '경'),
+  ('厄', '액'),
+  ('厓', '애'),
+  ('厘', '리'),
+  ('厚', '후'),
+  ('原', '원'),
+  ('厠', '측'),
+  ('厥', '궐'),
+  ('厦', '하'),
+  ('厭', '염'),
+  ('厳', '엄'),
+  ('去', '거'),
+  ('参', '참'),
+  ('參', '참'),
+  ('又', '우'),
+  ('叉', '차'),
+  ('及', '급'),
+  ('友', '우'),
+  ('双', '쌍'),
+  ('反', '반'),
+  ('収', '수'),
+  ('叔', '숙'),
+  ('取', '취'),
+  ('受', '수'),
+  ('叙', '서'),
+  ('叛', '반'),
+  ('叡', '예'),
+  ('叢', '총'),
+  ('口', '구'),
+  ('古', '고'),
+  ('句', '구'),
+  ('叩', '고'),
+  ('只', '지'),
+  ('叫', '규'),
+  ('召', '소'),
+  ('叭', '팔'),
+  ('可', '가'),
+  ('台', '태'),
+  ('叱', '질'),
+  ('史', '사'),
+  ('右', '우'),
+  ('号', '호'),
+  ('司', '사'),
+  ('吃', '흘'),
+  ('各', '각'),
+  ('合', '합'),
+  ('吉', '길'),
+  ('吊', '적'),
+  ('同', '동'),
+  ('名', '명'),
+  ('后', '후'),
+  ('吏', '리'),
+  ('吐', '토'),
+  ('向', '향'),
+  ('君', '군'),
+  ('吝', '린'),
+  ('吟', '음'),
+  ('吠', '폐'),
+  ('否', '부'),
+  ('吩', '분'),
+  ('含', '함'),
+  ('吳', '오'),
+  ('吸', '흡'),
+  ('吹', '취'),
+  ('吻', '문'),
+  ('吼', '후'),
+  ('吾', '오'),
+  ('呂', '려'),
+  ('呈', '정'),
+  ('告', '고'),
+  ('呑', '탄'),
+  ('周', '주'),
+  ('呪', '주'),
+  ('呱', '고'),
+  ('味', '미'),
+  ('呵', '가'),
+  ('呻', '신'),
+  ('呼', '호'),
+  ('命', '명'),
+  ('咀', '저'),
+  ('咆', '포'),
+  ('和', '화'),
+  ('咎', '구'),
+  ('咐', '부'),
+  ('咤', '타'),
+  ('咨', '자'),
+  ('咫', '지'),
+  ('咬', '교'),
+  ('咳', '해'),
+  ('咸', '함'),
+  ('咽', '인'),
+  ('哀', '애'),
+  ('品', '품'),
+  ('哄', '홍'),
+  ('哈', '합'),
+  ('哉', '재'),
+  ('員', '원'),
+  ('哥', '가'),
+  ('哨', '초'),
+  ('哭', '곡'),
+  ('哮', '효'),
+  ('哲', '철'),
+  ('哺', '포'),
+  ('唄', '패'),
+  ('唆', '사'),
+  ('唇', '진'),
+  ('唎', '리'),
+  ('唐', '당'),
+  ('唜', '말'),
+  ('唯', '유'),
+  ('唱', '창'),
+  ('唵', '암'),
+  ('唾', '타'),
+  ('啄', '탁'),
+  ('商', '상'),
+  ('問', '문'),
+  ('啓', '계'),
+  ('啖', '담'),
+  ('啞', '아'),
+  ('啣', '함'),
+  ('啼', '제'),
+  ('喀', '객'),
+  ('善', '선'),
+  ('喆', '철'),
+  ('喇', '라'),
+  ('喉', '후'),
+  ('喊', '함'),
+  ('喘', '천'),
+  ('喙', '훼'),
+  ('喚', '환'),
+  ('喜', '희'),
+  ('喝', '갈'),
+  ('喧', '훤'),
+  ('喩', '유'),
+  ('喪', '상'),
+  ('喫', '끽'),
+  ('喬', '교'),
+  ('單', '단'),
+  ('営', '영'),
+  ('嗅', '후'),
+  ('嗇', '색'),
+  ('嗔', '진'),
+  ('嗚', '오'),
+  ('嗜', '기'),
+  ('嗟', '차'),
+  ('嗣', '사'),
+  ('嗤', '치'),
+  ('嗽', '수'),
+  ('嗾', '주'),
+  ('嘆', '탄'),
+  ('嘉', '가'),
+  ('嘔', '구'),
+  ('嘗', '상'),
+  ('嘯', '소'),
+  ('嘲', '조'),
+  ('嘴', '취'),
+  ('嘶', '시'),
+  ('噓', '허'),
+  ('器', '기'),
+  ('噫', '희'),
+  ('噴', '분'),
+  ('嚆', '효'),
+  ('嚥', '연'),
+  ('嚬', '빈'),
+  ('嚮', '향'),
+  ('嚴', '엄'),
+  ('嚼', '작'),
+  ('囊', '낭'),
+  ('囍', '희'),
+  ('囑', '촉'),
+  ('囚', '수'),
+  ('四', '사'),
+  ('回', '회'),
+  ('因', '인'),
+  ('団', '단'),
+  ('困', '곤'),
+  ('囲', '위'),
+  ('図', '도'),
+  ('囹', '령'),
+  ('固', '고'),
+  ('国', '국'),
+  ('圃', '포'),
+  ('圄', '어'),
+  ('圈', '권'),
+  ('國', '국'),
+  ('圍', '위'),
+  ('圏', '권'),
+  ('園', '원'),
+  ('圓', '원'),
+  ('圖', '도'),
+  ('團', '단'),
+  ('土', '토'),
+  ('圧', '압'),
+  ('在', '재'),
+  ('圭', '규'),
+  ('地', '지'),
+  ('圻', '기'),
+  ('址', '지'),
+  ('坂', '판'),
+  ('均', '균'),
+  ('坊', '방'),
+  ('坍', '담'),
+  ('坎', '감'),
+  ('坐', '좌'),
+  ('坑', '갱'),
+  ('坡', '파'),
+  ('坤', '곤'),
+  ('坦', '탄'),
+  ('坪', '평'),
+  ('坮', '대'),
+  ('坰', '경'),
+  ('坵', '구'),
+  ('坼', '탁'),
+  ('垂', '수'),
+  ('垈', '대'),
+  ('型', '형'),
+  ('垓', '해'),
+  ('垠', '은'),
+  ('垢', '구'),
+  ('垣', '원'),
+  ('埃', '애'),
+  ('埇', '용'),
+  ('埈', '준'),
+  ('埋', '매'),
+  ('城', '성'),
+  ('域', '역'),
+  ('埠', '부'),
+  ('埰', '채'),
+  ('埴', '식'),
+  ('執', '집'),
+  ('培', '배'),
+  ('基', '기'),
+  ('埼', '기'),
+  ('堀', '굴'),
+  ('堂', '당'),
+  ('堅', '견'),
+  ('堆', '퇴'),
+  ('堈', '강'),
+  ('堉', '육'),
+  ('堊', '악'),
+  ('堕', '타'),
+  ('堞', '첩'),
+  ('堡', '보'),
+  ('堤', '제'),
+  ('堧', '연'),
+  ('堪', '감'),
+  ('堯', '요'),
+  ('堰', '언'),
+  ('報', '보'),
+  ('場', '장'),
+  ('堵', '도'),
+  ('堺', '계'),
+  ('塀', '병'),
+  ('塁', '루'),
+  ('塊', '괴'),
+  ('塋', '영'),
+  ('塏', '개'),
+  ('塑', '소'),
+  ('塔', '탑'),
+  ('塗', '도'),
+  ('塘', '당'),
+  ('塚', '총'),
+  ('塞', '새'),
+  ('塡', '전'),
+  ('塢', '오'),
+  ('塤', '훈'),
+  ('塵', '진'),
+  ('塹', '참'),
+  ('塼', '전'),
+  ('塾', '숙'),
+  ('境', '경'),
+  ('墅', '서'),
+  ('墉', '용'),
+  ('墓', '묘'),
+  ('増', '증'),
+  ('墜', '추'),
+  ('增', '증'),
+  ('墟', '허'),
+  ('墨', '묵'),
+  ('墩', '돈'),
+  ('墮', '타'),
+  ('墳', '분'),
+  ('墺', '오'),
+  ('墻', '장'),
+  ('墾', '간'),
+  ('壁', '벽'),
+  ('壅', '옹'),
+  ('壇', '단'),
+  ('壊', '괴'),
+  ('壌', '양'),
+  ('壎', '훈'),
+  ('壑', '학'),
+  ('壓', '압'),
+  ('壕', '호'),
+  ('壘', '루'),
+  ('壙', '광'),
+  ('壞', '괴'),
+  ('壟', '롱'),
+  ('壤', '양'),
+  ('士', '사'),
+  ('壬', '임'),
+  ('壮', '장'),
+  ('壯', '장'),
+  ('声', '성'),
+  ('壱', '일'),
+  ('売', '매'),
+  ('壹', '일'),
+  ('壺', '호'),
+  ('壻', '서'),
+  ('壽', '수'),
+  ('変', '변'),
+  ('夏', '하'),
+  ('夔', '기'),
+  ('夕', '석'),
+  ('外', '외'),
+  ('夙', '숙'),
+  ('多', '다'),
+  ('夜', '야'),
+  ('夢', '몽'),
+  ('大', '대'),
+  ('天', '천'),
+  ('太', '태'),
+  ('夫', '부'),
+  ('夭', '요'),
+  ('央', '앙'),
+  ('失', '실'),
+  ('夷', '이'),
+  ('夾', '협'),
+  ('奄', '엄'),
+  ('奇', '기'),
+  ('奈', '내'),
+  ('奉', '봉'),
+  ('奎', '규'),
+  ('奏', '주'),
+  ('奐', '환'),
+  ('契', '계'),
+  ('奔', '분'),
+  ('奕', '혁'),
+  ('套', '투'),
+  ('奚', '해'),
+  ('奠', '전'),
+  ('奢', '사'),
+  ('奥', '오'),
+  ('奧', '오'),
+  ('奨', '장'),
+  ('奪', '탈'),
+  ('奫', '윤'),
+  ('奬', '장'),
+  ('奭', '석'),
+  ('奮', '분'),
+  ('女', '녀'),
+  ('奴', '노'),
+  ('奸', '간'),
+  ('好', '호'),
+  ('如', '여'),
+  ('妃', '비'),
+  ('妄', '망'),
+  ('妊', '임'),
+  ('妓', '기'),
+  ('妖', '요'),
+  ('妗', '금'),
+  ('妙', '묘'),
+  ('妥', '타'),
+  ('妨', '방'),
+  ('妬', '투'),
+  ('妹', '매'),
+  ('妻', '처'),
+  ('妾', '첩'),
+  ('姃', '정'),
+  ('姆', '모'),
+  ('姉', '자'),
+  ('始', '시'),
+  ('姐', '저'),
+  ('姑', '고'),
+  ('姓', '성'),
+  ('委', '위'),
+  ('姙', '임'),
+  ('姚', '요'),
+  ('姜', '강'),
+  ('姝', '주'),
+  ('姦', '간'),
+  ('姨', '이'),
+  ('姪', '질'),
+  ('姬', '희'),
+  ('姮', '항'),
+  ('姸', '연'),
+  ('姻', '인'),
+  ('姿', '자'),
+  ('威', '위'),
+  ('娃', '왜'),
+  ('娑', '사'),
+  ('娘', '낭'),
+  ('娛', '오'),
+  ('娜', '나'),
+  ('娟', '연'),
+  ('娠', '신'),
+  ('娥', '아'),
+  ('娩', '만'),
+  ('娶', '취'),
+  ('娼', '창'),
+  ('婁', '루'),
+  ('婆', '파'),
+  ('婉', '완'),
+  ('婚', '혼'),
+  ('婢', '비'),
+  ('婦', '부'),
+  ('媒', '매'),
+  ('媚', '미'),
+  ('媛', '원'),
+  ('媤', '시'),
+  ('嫁', '가'),
+  ('嫂', '수'),
+  ('嫄', '원'),
+  ('嫉', '질'),
+  ('嫌', '혐'),
+  ('嫡', '적'),
+  ('嫦', '항'),
+  ('嫩', '눈'),
+  ('嬅', '화'),
+  ('嬉', '희'),
+  ('嬋', '선'),
+  ('嬌', '교'),
+  ('嬖', '폐'),
+  ('嬢', '양'),
+  ('嬪', '빈'),
+  ('孀', '상'),
+  ('孃', '양'),
+  ('子', '자'),
+  ('孑', '혈'),
+  ('孔', '공'),
+  ('孕', '잉'),
+  ('字', '자'),
+  ('存', '존'),
+  ('孚', '부'),
+  ('孜', '자'),
+  ('孝', '효'),
+  ('孟', '맹'),
+  ('季', '계'),
+  ('孤', '고'),
+  ('学', '학'),
+  ('孩', '해'),
+  ('孫', '손'),
+  ('孰', '숙'),
+  ('孱', '잔'),
+  ('孵', '부'),
+  ('學', '학'),
+  ('孺', '유'),
+  ('孼', '얼'),
+  ('宅', '택'),
+  ('宇', '우'),
+  ('守', '수'),
+  ('安', '안'),
+  ('宋', '송'),
+  ('完', '완'),
+  ('宏', '굉'),
+  ('宓', '복'),
+  ('宕', '탕'),
+  ('宖', '횡'),
+  ('宗', '종'),
+  ('官', '관'),
+  ('宙', '주'),
+  ('定', '정'),
+  ('宛', '완'),
+  ('宜', '의'),
+  ('宝', '보'),
+  ('実', '실'),
+  ('客', '객'),
+  ('宣', '선'),
+  ('室', '실'),
+  ('宥', '유'),
+  ('宦', '환'),
+  ('宬', '성'),
+  ('宮', '궁'),
+  ('宰', '재'),
+  ('害', '해'),
+  ('宴', '연'),
+  ('宵', '소'),
+  ('家', '가'),
+  ('宸', '신'),
+  ('容', '용'),
+  ('宿', '숙'),
+  ('寀', '채'),
+  ('寂', '적'),
+  ('寃', '원'),
+  ('寄', '기'),
+  ('寅', '인'),
+  ('密', '밀'),
+  ('寇', '구'),
+  ('富', '부'),
+  ('寐', '매'),
+  ('寒', '한'),
+  ('寓', '우'),
+  ('寔', '식'),
+  ('寗', '녕'),
+  ('寛', '관'),
+  ('寝', '침'),
+  ('寞', '막'),
+  ('察', '찰'),
+  ('寡', '과'),
+  ('寢', '침'),
+  ('寤', '오'),
+  ('寥', '요'),
+  ('實', '실'),
+  ('寧', '녕'),
+  ('寨', '채'),
+  ('審', '심'),
+  ('寫', '사'),
+  ('寬', '관'),
+  ('寮', '료'),
+  ('寯', '준'),
+  ('寵', '총'),
+  ('寶', '보'),
+  ('寸', '촌'),
+  ('寺', '사'),
+  ('対', '대'),
+  ('寿', '수'),
+  ('封', '봉'),
+  ('専', '전'),
+  ('射', '사'),
+  ('将', '장'),
+  ('將', '장'),
+  ('專', '전'),
+  ('尉', '위'),
+  ('尊', '존'),
+  ('尋', '심'),
+  ('對', '대'),
+  ('導', '도'),
+  ('小', '소'),
+  ('少', '소'),
+  ('尖', '첨'),
+  ('尙', '상'),
+  ('尤', '우'),
+  ('尨', '방'),
+  ('就', '취'),
+  ('尸', '시'),
+  ('尹', '윤'),
+  ('尺', '척'),
+  ('尻', '고'),
+  ('尼', '니'),
+  ('尽', '진'),
+  ('尾', '미'),
+  ('尿', '뇨'),
+  ('局', '국'),
+  ('居', '거'),
+  ('屆', '계'),
+  ('屈', '굴'),
+  ('届', '계'),
+  ('屋', '옥'),
+  ('屍', '시'),
+  ('屎', '시'),
+  ('屑', '설'),
+  ('展', '전'),
+  ('屛', '병'),
+  ('属', '속'),
+  ('屠', '도'),
+  ('屢', '루'),
+  ('層', '층'),
+  ('履', '리'),
+  ('屬', '속'),
+  ('屯', '둔'),
+  ('山', '산'),
+  ('屹', '흘'),
+  ('岐', '기'),
+  ('岑', '잠'),
+  ('岡', '강'),
+  ('岩', '암'),
+  ('岫', '수'),
+  ('岬', '갑'),
+  ('岱', '대'),
+  ('岳', '악'),
+  ('岵', '호'),
+  ('岷', '민'),
+  ('岸', '안'),
+  ('岺', '령'),
+  ('岾', '점'),
+  ('峀', '수'),
+  ('峙', '치'),
+  ('峠', '상'),
+  ('峡', '협'),
+  ('峨', '아'),
+  ('峯', '봉'),
+  ('峰', '봉'),
+  ('峴', '현'),
+  ('島', '도'),
+  ('峻', '준'),
+  ('峽', '협'),
+  ('崇', '숭'),
+  ('崍', '래'),
+  ('崎', '기'),
+  ('崑', '곤'),
+  ('崔', '최'),
+  ('崖', '애'),
+  ('崗', '강'),
+  ('崙', '륜'),
+  ('崧', '숭'),
+  ('崩', '붕'),
+  ('嵋', '미'),
+  ('嵌', '감'),
+  ('嵐', '람'),
+  ('嵩', '숭'),
+  ('嵬', '외'),
+  ('嵯', '차'),
+  ('嶇', '구'),
+  ('嶋', '도'),
+  ('嶝', '등'),
+  ('嶠', '교'),
+  ('嶢', '요'),
+  ('嶪', '업'),
+  ('嶸', '영'),
+  ('嶺', '령'),
+  ('嶼', '서'),
+  ('嶽', '악'),
+  ('巍', '외'),
+  ('巒', '만'),
+  ('巖', '암'),
+  ('川', '천'),
+  ('州', '주'),
+  ('巡', '순'),
+  ('巢', '소'),
+  ('巣', '소'),
+  ('工', '공'),
+  ('左', '좌'),
+  ('巧', '교'),
+  ('巨', '거'),
+  ('巫', '무'),
+  ('差', '차'),
+  ('己', '기'),
+  ('已', '이'),
+  ('巳', '사'),
+  ('巴', '파'),
+  ('巷', '항'),
+  ('巻', '권'),
+  ('巽', '손'),
+  ('巾', '건'),
+  ('市', '시'),
+  ('布', '포'),
+  ('帆', '범'),
+  ('希', '희'),
+  ('帑', '탕'),
+  ('帖', '첩'),
+  ('帙', '질'),
+  ('帛', '백'),
+  ('帝', '제'),
+  ('帥', '수'),
+  ('師', '사'),
+  ('席', '석'),
+  ('帯', '대'),
+  ('帰', '귀'),
+  ('帳', '장'),
+  ('帶', '대'),
+  ('常', '상'),
+  ('帽', '모'),
+  ('帿', '후'),
+  ('幀', '정'),
+  ('幄', '악'),
+  ('幅', '폭'),
+  ('幇', '방'),
+  ('幌', '황'),
+  ('幕', '막'),
+  ('幟', '치'),
+  ('幡', '번'),
+  ('幢', '당'),
+  ('幣', '폐'),
+  ('干', '간'),
+  ('平', '평'),
+  ('年', '년'),
+  ('幷', '병'),
+  ('幸', '행'),
+  ('幹', '간'),
+  ('幻', '환'),
+  ('幼', '유'),
+  ('幽', '유'),
+  ('幾', '기'),
+  ('庁', '청'),
+  ('広', '광'),
+  ('庄', '장'),
+  ('庇', '비'),
+  ('床', '상'),
+  ('序', '서'),
+  ('底', '저'),
+  ('店', '점'),
+  ('庚', '경'),
+  ('府', '부'),
+  ('庠', '상'),
+  ('度', '도'),
+  ('座', '좌'),
+  ('庫', '고'),
+  ('庭', '정'),
+  ('庵', '암'),
+  ('庶', '서'),
+  ('康', '강'),
+  ('庸', '용'),
+  ('庾', '유'),
+  ('廂', '상'),
+  ('廃', '폐'),
+  ('廈', '하'),
+  ('廉', '렴'),
+  ('廊', '랑'),
+  ('廐', '구'),
+  ('廓', '곽'),
+  ('廖', '료'),
+  ('廚', '주'),
+  ('廛', '전'),
+  ('廟', '묘'),
+  ('廠', '창'),
+  ('廢', '폐'),
+  ('廣', '광'),
+  ('廬', '려'),
+  ('廳', '청'),
+  ('延', '연'),
+  ('廷', '정'),
+  ('建', '건'),
+  ('廻', '회'),
+  ('弁', '변'),
+  ('弄', '롱'),
+  ('弊', '폐'),
+  ('式', '식'),
+  ('弐', '이'),
+  ('弑', '시'),
+  ('弓', '궁'),
+  ('弔', '조'),
+  ('引', '인'),
+  ('弗', '불'),
+  ('弘', '홍'),
+  ('弛', '이'),
+  ('弟', '제'),
+  ('弦', '현'),
+  ('弧', '호'),
+  ('弩', '노'),
+  ('弱', '약'),
+  ('張', '장'),
+  ('强', '강'),
+  ('弼', '필'),
+  ('弾', '탄'),
+  ('彈', '탄'),
+  ('彊', '강'),
+  ('彌', '미'),
+  ('彎', '만'),
+  ('当', '당'),
+  ('彖', '단'),
+  ('彗', '혜'),
+  ('彙', '휘'),
+  ('彛', '이'),
+  ('形', '형'),
+  ('彦', '언'),
+  ('彧', '욱'),
+  ('彩', '채'),
+  ('彪', '표'),
+  ('彫', '조'),
+  ('彬', '빈'),
+  ('彭', '팽'),
+  ('彰', '창'),
+  ('影', '영'),
+  ('彷', '방'),
+  ('役', '역'),
+  ('彼', '피'),
+  ('彿', '불'),
+  ('往', '왕'),
+  ('征', '정'),
+  ('径', '경'),
+  ('待', '대'),
+  ('徇', '순'),
+  ('徊', '회'),
+  ('律', '률'),
+  ('後', '후'),
+  ('徐', '서'),
+  ('徑', '경'),
+  ('徒', '도'),
+  ('従', '종'),
+  ('得', '득'),
+  ('徘', '배'),
+  ('徙', '사'),
+  ('從', '종'),
+  ('徠', '래'),
+  ('御', '어'),
+  ('徨', '황'),
+  ('復', '복'),
+  ('循', '순'),
+  ('微', '미'),
+  ('徳', '덕'),
+  ('徵', '징'),
+  ('德', '덕'),
+  ('徹', '철'),
+  ('徽', '휘'),
+  ('心', '심'),
+  ('必', '필'),
+  ('忌', '기'),
+  ('忍', '인'),
+  ('忖', '촌'),
+  ('志', '지'),
+  ('忘', '망'),
+  ('忙', '망'),
+  ('応', '응'),
+  ('忠', '충'),
+  ('快', '쾌'),
+  ('念', '념'),
+  ('忽', '홀'),
+  ('忿', '분'),
+  ('怏', '앙'),
+  ('怒', '노'),
+  ('怖', '포'),
+  ('怜', '령'),
+  ('思', '사'),
+  ('怠', '태'),
+  ('怡', '이'),
+  ('急', '급'),
+  ('性', '성'),
+  ('怨', '원'),
+  ('怪', '괴'),
+  ('怯', '겁'),
+  ('恁', '임'),
+  ('恂', '순'),
+  ('恃', '시'),
+  ('恋', '련'),
+  ('恍', '황'),
+  ('恐', '공'),
+  ('恒', '항'),
+  ('恕', '서'),
+  ('恙', '양'),
+  ('恝', '괄'),
+  ('恢', '회'),
+  ('恣', '자'),
+  ('恤', '휼'),
+  ('恥', '치'),
+  ('恨', '한'),
+  ('恩', '은'),
+  ('恪', '각'),
+  ('恬', '념'),
+  ('恭', '공'),
+  ('息', '식'),
+  ('恰', '흡'),
+  ('恵', '혜'),
+  ('悅', '열'),
+  ('悉', '실'),
+  ('悌', '제'),
+  ('悍', '한'),
+  ('悔', '회'),
+  ('悖', '패'),
+  ('悚', '송'),
+  ('悛', '전'),
+  ('悟', '오'),
+  ('悠', '유'),
+  ('患', '환'),
+  ('悤', '총'),
+  ('悧', '리'),
+  ('悩', '뇌'),
+  ('悪', '악'),
+  ('悰', '종'),
+  ('悲', '비'),
+  ('悳', '덕'),
+  ('悴', '췌'),
+  ('悶', '민'),
+  ('悸', '계'),
+  ('悼', '도'),
+  ('悽', '처'),
+  ('情', '정'),
+  ('惇', '돈'),
+  ('惑', '혹'),
+  ('惚', '홀'),
+  ('惜', '석'),
+  ('惟', '유'),
+  ('惠', '혜'),
+  ('惡', '악'),
+  ('惨', '참'),
+  ('惰', '타'),
+  ('惱', '뇌'),
+  ('想', '상'),
+  ('惶', '황'),
+  ('惹', '야'),
+  ('惺', '성'),
+  ('惻', '측'),
+  ('愁', '수'),
+  ('愆', '건'),
+  ('愈', '유'),
+  ('愉', '유'),
+  ('愍', '민'),
+  ('愎', '퍅'),
+  ('意', '의'),
+  ('愕', '악'),
+  ('愚', '우'),
+  ('愛', '애'),
+  ('感', '감'),
+  ('愧', '괴'),
+  ('愰', '황'),
+  ('愴', '창'),
+  ('愷', '개'),
+  ('愼', '신'),
+  ('愾', '개'),
+  ('愿', '원'),
+  ('慂', '용'),
+  ('慄', '률'),
+  ('慇', '은'),
+  ('慈', '자'),
+  ('慊', '겸'),
+  ('態', '태'),
+  ('慌', '황'),
+  ('慎', '신'),
+  ('慓', '표'),
+  ('慕', '모'),
+  ('慘', '참'),
+  ('慙', '참'),
+  ('慝', '특'),
+  ('慟', '통'),
+  ('慢', '만'),
+  ('慣', '관'),
+  ('慤', '각'),
+  ('慧', '혜'),
+  ('慨', '개'),
+  ('慫', '종'),
+  ('慮', '려'),
+  ('慰', '위'),
+  ('慶', '경'),
+  ('慷', '강'),
+  ('慽', '척'),
+  ('慾', '욕'),
+  ('憁', '총'),
+  ('憂', '우'),
+  ('憊', '비'),
+  ('憎', '증'),
+  ('憐', '련'),
+  ('憑', '빙'),
+  ('憔', '초'),
+  ('憘', '희'),
+  ('憙', '희'),
+  ('憚', '탄'),
+  ('憤', '분'),
+  ('憧', '동'),
+  ('憩', '게'),
+  ('憫', '민'),
+  ('憬', '경'),
+  ('憮', '무'),
+  ('憲', '헌'),
+  ('憶', '억'),
+  ('憺', '담'),
+  ('憾', '감'),
+  ('懃', '근'),
+  ('懇', '간'),
+  ('懈', '해'),
+  ('應', '응'),
+  ('懊', '오'),
+  ('懋', '무'),
+  ('懐', '회'),
+  ('懦', '나'),
+  ('懲', '징'),
+  ('懶', '라'),
+  ('懷', '회'),
+  ('懸', '현'),
+  ('懺', '참'),
+  ('懼', '구'),
+  ('懿', '의'),
+  ('戀', '련'),
+  ('戇', '당'),
+  ('戈', '과'),
+  ('戊', '무'),
+  ('戌', '술'),
+  ('戍', '수'),
+  ('戎', '융'),
+  ('成', '성'),
+  ('我', '아'),
+  ('戒', '계'),
+  ('或', '혹'),
+  ('戚', '척'),
+  ('戟', '극'),
+  ('戡', '감'),
+  ('戦', '전'),
+  ('截', '절'),
+  ('戮', '륙'),
+  ('戯', '희'),
+  ('戰', '전'),
+  ('戱', '희'),
+  ('戴', '대'),
+  ('戶', '호'),
+  ('戾', '려'),
+  ('房', '방'),
+  ('所', '소'),
+  ('扁', '편'),
+  ('扇', '선'),
+  ('扈', '호'),
+  ('扉', '비'),
+  ('手', '수'),
+  ('才', '재'),
+  ('打', '타'),
+  ('払', '불'),
+  ('托', '탁'),
+  ('扮', '분'),
+  ('扱', '급'),
+  ('扶', '부'),
+  ('批', '비'),
+  ('扼', '액'),
+  ('承', '승'),
+  ('技', '기'),
+  ('抄', '초'),
+  ('抉', '결'),
+  ('把', '파'),
+  ('抑', '억'),
+  ('抒', '서'),
+  ('投', '투'),
+  ('抗', '항'),
+  ('折', '절'),
+  ('抛', '포'),
+  ('抜', '발'),
+  ('択', '택'),
+  ('披', '피'),
+  ('抱', '포'),
+  ('抵', '저'),
+  ('抹', '말'),
+  ('押', '압'),
+  ('抽', '추'),
+  ('拂', '불'),
+  ('担', '담'),
+  ('拇', '무'),
+  ('拈', '념'),
+  ('拉', '랍'),
+  ('拌', '반'),
+  ('拍', '박'),
+  ('拏', '나'),
+  ('拐', '괴'),
+  ('拒', '거'),
+  ('拓', '척'),
+  ('拔', '발'),
+  ('拖', '타'),
+  ('拗', '요'),
+  ('拘', '구'),
+  ('拙', '졸'),
+  ('招', '초'),
+  ('拜', '배'),
+  ('拠', '거'),
+  ('拡', '확'),
+  ('括', '괄'),
+  ('拭', '식'),
+  ('拮', '길'),
+  ('拯', '증'),
+  ('拱', '공'),
+  ('拳', '권'),
+  ('拷', '고'),
+  ('拾', '습'),
+  ('拿', '나'),
+  ('持', '지'),
+  ('指', '지'),
+  ('按', '안'),
+  ('挑', '도'),
+  ('挙', '거'),
+  ('挟', '협'),
+  ('挫', '좌'),
+  ('振', '진'),
+  ('挺', '정'),
+  ('挻', '연'),
+  ('挽', '만'),
+  ('挾', '협'),
+  ('挿', '삽'),
+  ('捉', '착'),
+  ('捌', '팔'),
+  ('捏', '날'),
+  ('捐', '연'),
+  ('捕', '포'),
+  ('捜', '수'),
+  ('捧', '봉'),
+  ('捨', '사'),
+  ('据', '거'),
+  ('捲', '권'),
+  ('捷', '첩'),
+  ('捺', '날'),
+  ('捻', '념'),
+  ('捿', '서'),
+  ('掃', '소'),
+  ('授', '수'),
+  ('掉', '도'),
+  ('掌', '장'),
+  ('排', '배'),
+  ('掖', '액'),
+  ('掘', '굴'),
+  ('掛', '괘'),
+  ('掠', '략'),
+  ('採', '채'),
+  ('探', '탐'),
+  ('接', '접'),
+  ('控', '공'),
+  ('推', '추'),
+  ('掩', '엄'),
+  ('措', '조'),
+  ('掲', '게'),
+  ('揀', '간'),
+  ('揄', '유'),
+  ('揆', '규'),
+  ('描', '묘'),
+  ('提', '제'),
+  ('揖', '읍'),
+  ('揚', '양'),
+  ('換', '환'),
+  ('握', '악'),
+  ('揭', '게'),
+  ('揮', '휘'),
+  ('援', '원'),
+  ('揶', '야'),
+  ('揷', '삽'),
+  ('揺', '요'),
+  ('損', '손'),
+  ('搏', '박'),
+  ('搔', '소'),
+  ('搖', '요'),
+  ('搗', '도'),
+  ('搜', '수'),
+  ('搢', '진'),
+  ('搬', '반'),
+  ('搭', '탑'),
+  ('携', '휴'),
+  ('搾', '착'),
+  ('摂', '섭'),
+  ('摘', '적'),
+  ('摠', '총'),
+  ('摩', '마'),
+  ('摯', '지'),
+  ('摸', '모'),
+  ('摹', '모'),
+  ('摺', '접'),
+  ('撃', '격'),
+  ('撈', '로'),
+  ('撑', '탱'),
+  ('撒', '살'),
+  ('撓', '요'),
+  ('撚', '년'),
+  ('撞', '당'),
+  ('撤', '철'),
+  ('撥', '발'),
+  ('撫', '무'),
+  ('播', '파'),
+  ('撮', '촬'),
+  ('撰', '찬'),
+  ('撲', '박'),
+  ('撻', '달'),
+  ('擁', '옹'),
+  ('擄', '로'),
+  ('擅', '천'),
+  ('擇', '택'),
+  ('擊', '격'),
+  ('操', '조'),
+  ('擎', '경'),
+  ('擒', '금'),
+  ('擔', '담'),
+  ('擘', '벽'),
+  ('據', '거'),
+  ('擡', '대'),
+  ('擢', '탁'),
+  ('擥', '람'),
+  ('擦', '찰'),
+  ('擧', '거'),
+  ('擬', '의'),
+  ('擲', '척'),
+  ('擴', '확'),
+  ('擺', '파'),
+  ('擾', '요'),
+  ('攀', '반'),
+  ('攄', '터'),
+  ('攘', '양'),
+  ('攝', '섭'),
+  ('攣', '련'),
+  ('攪', '교'),
+  ('攫', '확'),
+  ('攬', '람'),
+  ('支', '지'),
+  ('收', '수'),
+  ('攷', '고'),
+  ('攸', '유'),
+  ('改', '개'),
+  ('攻', '공'),
+  ('放', '방'),
+  ('政', '정'),
+  ('故', '고'),
+  ('效', '효'),
+  ('敍', '서'),
+  ('敎', '교'),
+  ('敏', '민'),
+  ('救', '구'),
+  ('敖', '오'),
+  ('敗', '패'),
+  ('敞', '창'),
+  ('敢', '감'),
+  ('散', '산'),
+  ('敦', '돈'),
+  ('敬', '경'),
+  ('敭', '양'),
+  ('数', '수'),
+  ('敲', '고'),
+  ('整', '정'),
+  ('敵', '적'),
+  ('敷', '부'),
+  ('數', '수'),
+  ('敾', '선'),
+  ('斂', '렴'),
+  ('斃', '폐'),
+  ('斅', '효'),
+  ('文', '문'),
+  ('斌', '빈'),
+  ('斎', '재'),
+  ('斐', '비'),
+  ('斑', '반'),
+  ('斗', '두'),
+  ('料', '료'),
+  ('斛', '곡'),
+  ('斜', '사'),
+  ('斟', '짐'),
+  ('斡', '알'),
+  ('斤', '근'),
+  ('斥', '척'),
+  ('斧', '부'),
+  ('斫', '작'),
+  ('斬', '참'),
+  ('断', '단'),
+  ('斯', '사'),
+  ('新', '신'),
+  ('斷', '단'),
+  ('方', '방'),
+  ('於', '어'),
+  ('施', '시'),
+  ('旁', '방'),
+  ('旅', '려'),
+  ('旋', '선'),
+  ('旌', '정'),
+  ('族', '족'),
+  ('旒', '류'),
+  ('旗', '기'),
+  ('无', '무'),
+  ('既', '기'),
+  ('旣', '기'),
+  ('日', '일'),
+  ('旦', '단'),
+  ('旧', '구'),
+  ('旨', '지'),
+  ('早', '조'),
+  ('旬', '순'),
+  ('旭', '욱'),
+  ('旱', '한'),
+  ('旴', '우'),
+  ('旺', '왕'),
+  ('旻', '민'),
+  ('旼', '민'),
+  ('旽', '돈'),
+  ('旿', '오'),
+  ('昀', '윤'),
+  ('昆', '곤'),
+  ('昇', '승'),
+  ('昉', '방'),
+  ('昊', '호'),
+  ('昌', '창'),
+  ('明', '명'),
+  ('昏', '혼'),
+  ('昐', '분'),
+  ('昑', '금'),
+  ('易', '역'),
+  ('昔', '석'),
+  ('昕', '흔'),
+  ('昞', '병'),
+  ('星', '성'),
+  ('映', '영'),
+  ('春', '춘'),
+  ('昧', '매'),
+  ('昨', '작'),
+  ('昭', '소'),
+  ('是', '시'),
+  ('昰', '하'),
+  ('昱', '욱'),
+  ('昴', '묘'),
+  ('昶', '창'),
+  ('昺', '병'),
+  ('昻', '앙'),
+  ('昼', '주'),
+  ('晁', '조'),
+  ('時', '시'),
+  ('晃', '황'),
+  ('晄', '황'),
+  ('晉', '진'),
+  ('晋', '진'),
+  ('晏', '안'),
+  ('晙', '준'),
+  ('晛', '현'),
+  ('晝', '주'),
+  ('晞', '희'),
+  ('晟', '성'),
+  ('晤', '오'),
+  ('晥', '환'),
+  ('晦', '회'),
+  ('晧', '호'),
+  ('晨', '신'),
+  ('晩', '만'),
+  ('晫', '탁'),
+  ('普', '보'),
+  ('景', '경'),
+  ('晳', '석'),
+  ('晴', '청'),
+  ('晶', '정'),
+  ('晷', '귀'),
+  ('晸', '정'),
+  ('智', '지'),
+  ('暁', '효'),
+  ('暄', '훤'),
+  ('暇', '가'),
+  ('暈', '훈'),
+  ('暉', '휘'),
+  ('暎', '영'),
+  ('暐', '위'),
+  ('暑', '서'),
+  ('暖', '난'),
+  ('暗', '암'),
+  ('暘', '양'),
+  ('暝', '명'),
+  ('暠', '고'),
+  ('暢', '창'),
+  ('暦', '력'),
+  ('暫', '잠'),
+  ('暮', '모'),
+  ('暲', '장'),
+  ('暳', '혜'),
+  ('暴', '폭'),
+  ('暹', '섬'),
+  ('暻', '경'),
+  ('暾', '돈'),
+  ('曄', '엽'),
+  ('曆', '력'),
+  ('曇', '담'),
+  ('曉', '효'),
+  ('曖', '애'),
+  ('曙', '서'),
+  ('曜', '요'),
+  ('曝', '폭'),
+  ('曠', '광'),
+  ('曦', '희'),
+  ('曰', '왈'),
+  ('曲', '곡'),
+  ('曳', '예'),
+  ('更', '경'),
+  ('曷', '갈'),
+  ('書', '서'),
+  ('曹', '조'),
+  ('曺', '조'),
+  ('曼', '만'),
+  ('曾', '증'),
+  ('替', '체'),
+  ('最', '최'),
+  ('會', '회'),
+  ('月', '월'),
+  ('有', '유'),
+  ('朋', '붕'),
+  ('服', '복'),
+  ('朔', '삭'),
+  ('朕', '짐'),
+  ('朗', '랑'),
+  ('望', '망'),
+  ('朝', '조'),
+  ('朞', '기'),
+  ('期', '기'),
+  ('朦', '몽'),
+  ('朧', '롱'),
+  ('木', '목'),
+  ('未', '미'),
+  ('末', '말'),
+  ('本', '본'),
+  ('札', '찰'),
+  ('朮', '출'),
+  ('朱', '주'),
+  ('朴', '박'),
+  ('朶', '타'),
+  ('机', '궤'),
+  ('朽', '후'),
+  ('杆', '간'),
+  ('杉', '삼'),
+  ('李', '리'),
+  ('杏', '행'),
+  ('材', '재'),
+  ('村', '촌'),
+  ('杓', '표'),
+  ('杖', '장'),
+  ('杜', '두'),
+  ('杞', '기'),
+  ('束', '속'),
+  ('条', '조'),
+  ('来', '래'),
+  ('杭', '항'),
+  ('杯', '배'),
+  ('杰', '걸'),
+  ('東', '동'),
+  ('杳', '묘'),
+  ('杵', '저'),
+  ('杷', '파'),
+  ('杻', '뉴'),
+  ('松', '송'),
+  ('板', '판'),
+  ('枇', '비'),
+  ('枉', '왕'),
+  ('枋', '방'),
+  ('枏', '남'),
+  ('析', '석'),
+  ('枓', '두'),
+  ('枕', '침'),
+  ('林', '림'),
+  ('枚', '매'),
+  ('果', '과'),
+  ('枝', '지'),
+  ('枯', '고'),
+  ('枰', '평'),
+  ('枳', '지'),
+  ('架', '가'),
+  ('枷', '가'),
+  ('枸', '구'),
+  ('枾', '시'),
+  ('柄', '병'),
+  ('柏', '백'),
+  ('某', '모'),
+  ('柑', '감'),
+  ('柒', '칠'),
+  ('染', '염'),
+  ('柔', '유'),
+  ('柚', '유'),
+  ('柝', '탁'),
+  ('柩', '구'),
+  ('柬', '간'),
+  ('柯', '가'),
+  ('柰', '내'),
+  ('柱', '주'),
+  ('柳', '류'),
+  ('柴', '시'),
+  ('柵', '책'),
+  ('柶', '사'),
+  ('査', '사'),
+  ('柾', '정'),
+  ('栄', '영'),
+  ('栒', '순'),
+  ('栓', '전'),
+  ('栖', '서'),
+  ('栗', '률'),
+  ('校', '교'),
+  ('栢', '백'),
+  ('株', '주'),
+  ('栯', '욱'),
+  ('核', '핵'),
+  ('根', '근'),
+  ('格', '격'),
+  ('栽', '재'),
+  ('桀', '걸'),
+  ('桁', '항'),
+  ('桂', '계'),
+  ('桃', '도'),
+  ('案', '안'),
+  ('桎', '질'),
+  ('桐', '동'),
+  ('桑', '상'),
+  ('桓', '환'),
+  ('桔', '길'),
+  ('桜', '앵'),
+  ('桟', '잔'),
+  ('桭', '진'),
+  ('桶', '통'),
+  ('桿', '간'),
+  ('梁', '량'),
+  ('梅', '매'),
+  ('梏', '곡'),
+  ('梓', '재'),
+  ('梔', '치'),
+  ('梗', '경'),
+  ('條', '조'),
+  ('梟', '효'),
+  ('梡', '완'),
+  ('梢', '초'),
+  ('梧', '오'),
+  ('梨', '리'),
+  ('梭', '사'),
+  ('梯', '제'),
+  ('械', '계'),
+  ('梱', '곤'),
+  ('梳', '소'),
+  ('梵', '범'),
+  ('梶', '미'),
+  ('棄', '기'),
+  ('棅', '병'),
+  ('棉', '면'),
+  ('棋', '기'),
+  ('棍', '곤'),
+  ('棒', '봉'),
+  ('棕', '종'),
+  ('棗', '조'),
+  ('棘', '극'),
+  ('棚', '붕'),
+  ('棟', '동'),
+  ('棠', '당'),
+  ('棧', '잔'),
+  ('棨', '계'),
+  ('森', '삼'),
+  ('棲', '서'),
+  ('棹', '도'),
+  ('棺', '관'),
+  ('椀', '완'),
+  ('椅', '의'),
+  ('植', '식'),
+  ('椎', '추'),
+  ('椒', '초'),
+  ('検', '검'),
+  ('椧', '명'),
+  ('椰', '야'),
+  ('椽', '연'),
+  ('椿', '춘'),
+  ('楊', '양'),
+  ('楓', '풍'),
+  ('楔', '설'),
+  ('楕', '타'),
+  ('楗', '건'),
+  ('楙', '무'),
+  ('楚', '초'),
+  ('楞', '릉'),
+  ('楠', '남'),
+  ('楡', '유'),
+  ('楢', '유'),
+  ('楣', '미'),
+  ('楨', '정'),
+  ('楫', '즙'),
+  ('業', '업'),
+  ('楮', '저'),
+  ('楯', '순'),
+  ('極', '극'),
+  ('楷', '해'),
+  ('楸', '추'),
+  ('楹', '영'),
+  ('楼', '루'),
+  ('楽', '악'),
+  ('概', '개'),
+  ('榕', '용'),
+  ('榛', '진'),
+  ('榜', '방'),
+  ('榥', '황'),
+  ('榧', '비'),
+  ('榮', '영'),
+  ('榴', '류'),
+  ('榻', '탑'),
+  ('槁', '고'),
+  ('槃', '반'),
+  ('構', '구'),
+  ('槌', '퇴'),
+  ('槍', '창'),
+  ('槐', '괴'),
+  ('様', '양'),
+  ('槨', '곽'),
+  ('槪', '개'),
+  ('槻', '규'),
+  ('槽', '조'),
+  ('槿', '근'),
+  ('樂', '악'),
+  ('樊', '번'),
+  ('樑', '량'),
+  ('樓', '루'),
+  ('樗', '저'),
+  ('標', '표'),
+  ('樞', '추'),
+  ('樟', '장'),
+  ('模', '모'),
+  ('樣', '양'),
+  ('権', '권'),
+  ('横', '횡'),
+  ('樵', '초'),
+  ('樸', '박'),
+  ('樹', '수'),
+  ('樺', '화'),
+  ('樽', '준'),
+  ('橄', '감'),
+  ('橈', '요'),
+  ('橋', '교'),
+  ('橒', '운'),
+  ('橓', '순'),
+  ('橘', '귤'),
+  ('橙', '등'),
+  ('機', '기'),
+  ('橡', '상'),
+  ('橫', '횡'),
+  ('檀', '단'),
+  ('檄', '격'),
+  ('檉', '정'),
+  ('檍', '억'),
+  ('檎', '금'),
+  ('檗', '벽'),
+  ('檜', '회'),
+  ('檢', '검'),
+  ('檣', '장'),
+  ('檳', '빈'),
+  ('檻', '함'),
+  ('櫂', '도'),
+  ('櫃', '궤'),
+  ('櫓', '로'),
+  ('櫚', '려'),
+  ('櫛', '즐'),
+  ('櫶', '헌'),
+  ('櫻', '앵'),
+  ('欄', '란'),
+  ('權', '권'),
+  ('欌', '장'),
+  ('欒', '란'),
+  ('欖', '람'),
+  ('欠', '흠'),
+  ('次', '차'),
+  ('欣', '흔'),
+  ('欧', '구'),
+  ('欲', '욕'),
+  ('欺', '기'),
+  ('欽', '흠'),
+  ('款', '관'),
+  ('歆', '흠'),
+  ('歇', '헐'),
+  ('歌', '가'),
+  ('歎', '탄'),
+  ('歐', '구'),
+  ('歓', '환'),
+  ('歟', '여'),
+  ('歡', '환'),
+  ('止', '지'),
+  ('正', '정'),
+  ('此', '차'),
+  ('步', '보'),
+  ('武', '무'),
+  ('歩', '보'),
+  ('歪', '왜'),
+  ('歯', '치'),
+  ('歲', '세'),
+  ('歳', '세'),
+  ('歴', '력'),
+  ('歷', '력'),
+  ('歸', '귀'),
+  ('死', '사'),
+  ('歿', '몰'),
+  ('殃', '앙'),
+  ('殄', '진'),
+  ('殆', '태'),
+  ('殉', '순'),
+  ('殊', '수'),
+  ('残', '잔'),
+  ('殖', '식'),
+  ('殘', '잔'),
+  ('殞', '운'),
+  ('殮', '렴'),
+  ('殯', '빈'),
+  ('殲', '섬'),
+  ('殴', '구'),
+  ('段', '단'),
+  ('殷', '은'),
+  ('殺', '살'),
+  ('殻', '각'),
+  ('殼', '각'),
+  ('殿', '전'),
+  ('毁', '훼'),
+  ('毅', '의'),
+  ('毆', '구'),
+  ('毋', '무'),
+  ('母', '모'),
+  ('毎', '매'),
+  ('每', '매'),
+  ('毒', '독'),
+  ('毓', '육'),
+  ('比', '비'),
+  ('毖', '비'),
+  ('毗', '비'),
+  ('毘', '비'),
+  ('毛', '모'),
+  ('毫', '호'),
+  ('毬', '구'),
+  ('氈', '전'),
+  ('氏', '씨'),
+  ('民', '민'),
+  ('氓', '맹'),
+  ('気', '기'),
+  ('氣', '기'),
+  ('水', '수'),
+  ('氷', '빙'),
+  ('永', '영'),
+  ('氾', '범'),
+  ('汀', '정'),
+  ('汁', '즙'),
+  ('求', '구'),
+  ('汎', '범'),
+  ('汐', '석'),
+  ('汕', '산'),
+  ('汗', '한'),
+  ('汚', '오'),
+  ('汝', '여'),
+  ('汞', '홍'),
+  ('江', '강'),
+  ('池', '지'),
+  ('汨', '골'),
+  ('汪', '왕'),
+  ('汭', '예'),
+  ('汰', '태'),
+  ('汲', '급'),
+  ('汶', '문'),
+  ('決', '결'),
+  ('汽', '기'),
+  ('汾', '분'),
+  ('沁', '심'),
+  ('沂', '기'),
+  ('沃', '옥'),
+  ('沅', '원'),
+  ('沆', '항'),
+  ('沇', '연'),
+  ('沈', '침'),
+  ('沌', '돈'),
+  ('沐', '목'),
+  ('沒', '몰'),
+  ('沓', '답'),
+  ('沔', '면'),
+  ('沕', '물'),
+  ('沖', '충'),
+  ('沙', '사'),
+  ('沚', '지'),
+  ('沛', '패'),
+  ('没', '몰'),
+  ('沢', '택'),
+  ('沫', '말'),
+  ('沮', '저'),
+  ('河', '하'),
+  ('沸', '비'),
+  ('油', '유'),
+  ('治', '치'),
+  ('沼', '소'),
+  ('沽', '고'),
+  ('沾', '첨'),
+  ('沿', '연'),
+  ('況', '황'),
+  ('泂', '형'),
+  ('泄', '설'),
+  ('泉', '천'),
+  ('泊', '박'),
+  ('泌', '비'),
+  ('泓', '홍'),
+  ('法', '법'),
+  ('泗', '사'),
+  ('泛', '범'),
+  ('泡', '포'),
+  ('波', '파'),
+  ('泣', '읍'),
+  ('泥', '니'),
+  ('注', '주'),
+  ('泫', '현'),
+  ('泮', '반'),
+  ('泯', '민'),
+  ('泰', '태'),
+  ('泳', '영'),
+  ('洋', '양'),
+  ('洌', '렬'),
+  ('洑', '보'),
+  ('洗', '세'),
+  ('洙', '수'),
+  ('洛', '락'),
+  ('洞', '동'),
+  ('津', '진'),
+  ('洧', '유'),
+  ('洩', '설'),
+  ('洪', '홍'),
+  ('洲', '주'),
+  ('洵', '순'),
+  ('洶', '흉'),
+  ('洸', '광'),
+  ('洹', '원'),
+  ('活', '활'),
+  ('洽', '흡'),
+  ('派', '파'),
+  ('流', '류'),
+  ('浄', '정'),
+  ('浅', '천'),
+  ('浙', '절'),
+  ('浚', '준'),
+  ('浜', '빈'),
+  ('浣', '완'),
+  ('浦', '포'),
+  ('浩', '호'),
+  ('浪', '랑'),
+  ('浬', '리'),
+  ('浮', '부'),
+  ('浴', '욕'),
+  ('海', '해'),
+  ('浸', '침'),
+  ('浹', '협'),
+  ('浿', '패'),
+  ('涅', '열'),
+  ('涇', '경'),
+  ('消', '소'),
+  ('涉', '섭'),
+  ('涌', '용'),
+  ('涍', '효'),
+  ('涎', '연'),
+  ('涑', '속'),
+  ('涓', '연'),
+  ('涕', '체'),
+  ('涙', '루'),
+  ('涯', '애'),
+  ('液', '액'),
+  ('涵', '함'),
+  ('淀', '정'),
+  ('淃', '권'),
+  ('淄', '치'),
+  ('淅', '석'),
+  ('淆', '효'),
+  ('淇', '기'),
+  ('淋', '림'),
+  ('淏', '호'),
+  ('淑', '숙'),
+  ('淘', '도'),
+  ('淙', '종'),
+  ('淚', '루'),
+  ('淞', '송'),
+  ('淡', '담'),
+  ('淨', '정'),
+  ('淪', '륜'),
+  ('淫', '음'),
+  ('淮', '회'),
+  ('深', '심'),
+  ('淳', '순'),
+  ('淵', '연'),
+  ('混', '혼'),
+  ('淸', '청'),
+  ('淹', '엄'),
+  ('淺', '천'),
+  ('添', '첨'),
+  ('渇', '갈'),
+  ('済', '제'),
+  ('渋', '삽'),
+  ('渓', '계'),
+  ('渗', '삼'),
+  ('渙', '환'),
+  ('渚', '저'),
+  ('減', '감'),
+  ('渟', '정'),
+  ('渠', '거'),
+  ('渡', '도'),
+  ('渣', '사'),
+  ('渤', '발'),
+  ('渥', '악'),
+  ('渦', '와'),
+  ('温', '온'),
+  ('渫', '설'),
+  ('測', '측'),
+  ('渭', '위'),
+  ('港', '항'),
+  ('渲', '선'),
+  ('渴', '갈'),
+  ('渶', '영'),
+  ('游', '유'),
+  ('渺', '묘'),
+  ('渼', '미'),
+  ('渽', '재'),
+  ('渾', '혼'),
+  ('湃', '배'),
+  ('湄', '미'),
+  ('湊', '주'),
+  ('湍', '단'),
+  ('湖', '호'),
+  ('湘', '상'),
+  ('湛', '담'),
+  ('湜', '식'),
+  ('湞', '정'),
+  ('湟', '황'),
+  ('湧', '용'),
+  ('湫', '추'),
+  ('湮', '인'),
+  ('湯', '탕'),
+  ('湲', '원'),
+  ('湳', '남'),
+  ('湺', '보'),
+  ('湾', '만'),
+  ('湿', '습'),
+  ('満', '만'),
+  ('源', '원'),
+  ('準', '준'),
+  ('溜', '류'),
+  ('溝', '구'),
+  ('溟', '명'),
+  ('溢', '일'),
+  ('溥', '부'),
+  ('溪', '계'),
+  ('溫', '온'),
+  ('溯', '소'),
+  ('溱', '진'),
+  ('溶', '용'),
+  ('溺', '닉'),
+  ('滂', '방'),
+  ('滄', '창'),
+  ('滅', '멸'),
+  ('滉', '황'),
+  ('滋', '자'),
+  ('滌', '척'),
+  ('滎', '형'),
+  ('滑', '활'),
+  ('滓', '재'),
+  ('滔', '도'),
+  ('滝', '롱'),
+  ('滞', '체'),
+  ('滯', '체'),
+  ('滴', '적'),
+  ('滸', '호'),
+  ('滾', '곤'),
+  ('滿', '만'),
+  ('漁', '어'),
+  ('漂', '표'),
+  ('漆', '칠'),
+  ('漏', '루'),
+  ('漑', '개'),
+  ('演', '연'),
+  ('漕', '조'),
+  ('漠', '막'),
+  ('漢', '한'),
+  ('漣', '련'),
+  ('漫', '만'),
+  ('漬', '지'),
+  ('漱', '수'),
+  ('漲', '창'),
+  ('漸', '점'),
+  ('漿', '장'),
+  ('潁', '영'),
+  ('潑', '발'),
+  ('潔', '결'),
+  ('潗', '집'),
+  ('潘', '반'),
+  ('潚', '숙'),
+  ('潛', '잠'),
+  ('潜', '잠'),
+  ('潞', '로'),
+  ('潟', '석'),
+  ('潢', '황'),
+  ('潤', '윤'),
+  ('潭', '담'),
+  ('潮', '조'),
+  ('潰', '궤'),
+  ('潺', '잔'),
+  ('潼', '동'),
+  ('潽', '보'),
+  ('潾', '린'),
+  ('澁', '삽'),
+  ('澄', '징'),
+  ('澈', '철'),
+  ('澍', '주'),
+  ('澎', '팽'),
+  ('澐', '운'),
+  ('澔', '호'),
+  ('澗', '간'),
+  ('澣', '한'),
+  ('澤', '택'),
+  ('澧', '례'),
+  ('澮', '회'),
+  ('澯', '찬'),
+  ('澱', '전'),
+  ('澳', '오'),
+  ('澹', '담'),
+  ('澾', '달'),
+  ('激', '격'),
+  ('濁', '탁'),
+  ('濂', '렴'),
+  ('濃', '농'),
+  ('濊', '예'),
+  ('濕', '습'),
+  ('濚', '영'),
+  ('濟', '제'),
+  ('濠', '호'),
+  ('濡', '유'),
+  ('濤', '도'),
+  ('濩', '호'),
+  ('濫', '람'),
+  ('濬', '준'),
+  ('濯', '탁'),
+  ('濱', '빈'),
+  ('濾', '려'),
+  ('瀁', '양'),
+  ('瀅', '형'),
+  ('瀆', '독'),
+  ('瀉', '사'),
+  ('瀋', '심'),
+  ('瀏', '류'),
+  ('瀑', '폭'),
+  ('瀕', '빈'),
+  ('瀘', '로'),
+  ('瀚', '한'),
+  ('瀛', '영'),
+  ('瀜', '융'),
+  ('瀝', '력'),
+  ('瀞', '정'),
+  ('瀟', '소'),
+  ('瀣', '해'),
+  ('瀧', '롱'),
+  ('瀨', '뢰'),
+  ('瀬', '뢰'),
+  ('瀯', '영'),
+  ('瀷', '익'),
+  ('瀾', '란'),
+  ('灌', '관'),
+  ('灐', '형'),
+  ('灑', '쇄'),
+  ('灘', '탄'),
+  ('灝', '호'),
+  ('灣', '만'),
+  ('火', '화'),
+  ('灯', '등'),
+  ('灰', '회'),
+  ('灸', '구'),
+  ('灼', '작'),
+  ('災', '재'),
+  ('炅', '경'),
+  ('炉', '로'),
+  ('炊', '취'),
+  ('炎', '염'),
+  ('炒', '초'),
+  ('炘', '흔'),
+  ('炙', '자'),
+  ('炚', '광'),
+  ('炡', '정'),
+  ('炤', '소'),
+  ('炫', '현'),
+  ('炬', '거'),
+  ('炭', '탄'),
+  ('炯', '형'),
+  ('炳', '병'),
+  ('炷', '주'),
+  ('炸', '작'),
+  ('点', '점'),
+  ('烈', '렬'),
+  ('烋', '휴'),
+  ('烏', '오'),
+  ('烘', '홍'),
+  ('烙', '락'),
+  ('烝', '증'),
+  ('烟', '연'),
+  ('烱', '경'),
+  ('烹', '팽'),
+  ('烽', '봉'),
+  ('焄', '훈'),
+  ('焉', '언'),
+  ('焌', '준'),
+  ('焙', '배'),
+  ('焚', '분'),
+  ('焞', '돈'),
+  ('無', '무'),
+  ('焦', '초'),
+  ('焰', '염'),
+  ('然', '연'),
+  ('焼', '소'),
+  ('煇', '휘'),
+  ('煉', '련'),
+  ('煊', '훤'),
+  ('煌', '황'),
+  ('煎', '전'),
+  ('煐', '영'),
+  ('煖', '난'),
+  ('煙', '연'),
+  ('煜', '욱'),
+  ('煞', '살'),
+  ('煤', '매'),
+  ('煥', '환'),
+  ('煦', '후'),
+  ('照', '조'),
+  ('煩', '번'),
+  ('煬', '양'),
+  ('煮', '자'),
+  ('煽', '선'),
+  ('熄', '식'),
+  ('熉', '운'),
+  ('熊', '웅'),
+  ('熏', '훈'),
+  ('熒', '형'),
+  ('熔', '용'),
+  ('熙', '희'),
+  ('熟', '숙'),
+  ('熢', '봉'),
+  ('熬', '오'),
+  ('熱', '열'),
+  ('熹', '희'),
+  ('熺', '희'),
+  ('熾', '치'),
+  ('燁', '엽'),
+  ('燃', '연'),
+  ('燈', '등'),
+  ('燉', '돈'),
+  ('燎', '료'),
+  ('燐', '린'),
+  ('燒', '소'),
+  ('燔', '번'),
+  ('燕', '연'),
+  ('營', '영'),
+  ('燥', '조'),
+  ('燦', '찬'),
+  ('燧', '수'),
+  ('燭', '촉'),
+  ('燮', '섭'),
+  ('燻', '훈'),
+  ('燼', '신'),
+  ('燾', '도'),
+  ('燿', '요'),
+  ('爀', '혁'),
+  ('爆', '폭'),
+  ('爐', '로'),
+  ('爛', '란'),
+  ('爪', '조'),
+  ('爬', '파'),
+  ('爭', '쟁'),
+  ('爰', '원'),
+  ('爲', '위'),
+  ('爵', '작'),
+  ('父', '부'),
+  ('爺', '야'),
+  ('爻', '효'),
+  ('爽', '상'),
+  ('爾', '이'),
+  ('牀', '상'),
+  ('牆', '장'),
+  ('片', '편'),
+  ('版', '판'),
+  ('牌', '패'),
+  ('牒', '첩'),
+  ('牘', '독'),
+  ('牙', '아'),
+  ('牛', '우'),
+  ('牝', '빈'),
+  ('牟', '모'),
+  ('牡', '모'),
+  ('牢', '뢰'),
+  ('牧', '목'),
+  ('物', '물'),
+  ('牲', '생'),
+  ('特', '특'),
+  ('牽', '견'),
+  ('犀', '서'),
+  ('犁', '리'),
+  ('犠', '희'),
+  ('犢', '독'),
+  ('犧', '희'),
+  ('犬', '견'),
+  ('犯', '범'),
+  ('狀', '상'),
+  ('狂', '광'),
+  ('狄', '적'),
+  ('狎', '압'),
+  ('狐', '호'),
+  ('狗', '구'),
+  ('狙', '저'),
+  ('狡', '교'),
+  ('狩', '수'),
+  ('独', '독'),
+  ('狭', '협'),
+  ('狸', '리'),
+  ('狹', '협'),
+  ('狼', '랑'),
+  ('狽', '패'),
+  ('猊', '예'),
+  ('猖', '창'),
+  ('猛', '맹'),
+  ('猜', '시'),
+  ('猝', '졸'),
+  ('猟', '렵'),
+  ('猥', '외'),
+  ('猩', '성'),
+  ('猪', '저'),
+  ('猫', '묘'),
+  ('献', '헌'),
+  ('猶', '유'),
+  ('猷', '유'),
+  ('猾', '활'),
+  ('猿', '원'),
+  ('獄', '옥'),
+  ('獅', '사'),
+  ('獐', '장'),
+  ('獒', '오'),
+  ('獗', '궐'),
+  ('獣', '수'),
+  ('獨', '독'),
+  ('獪', '회'),
+  ('獰', '영'),
+  ('獲', '획'),
+  ('獵', '렵'),
+  ('獸', '수'),
+  ('獺', '달'),
+  ('獻', '헌'),
+  ('玄', '현'),
+  ('玆', '자'),
+  ('率', '솔'),
+  ('玉', '옥'),
+  ('王', '왕'),
+  ('玎', '정'),
+  ('玔', '천'),
+  ('玖', '구'),
+  ('玗', '우'),
+  ('玘', '기'),
+  ('玟', '민'),
+  ('玧', '윤'),
+  ('玩', '완'),
+  ('玭', '빈'),
+  ('玲', '령'),
+  ('玳', '대'),
+  ('玹', '현'),
+  ('珀', '박'),
+  ('珂', '가'),
+  ('珉', '민'),
+  ('珊', '산'),
+  ('珌', '필'),
+  ('珍', '진'),
+  ('珏', '각'),
+  ('珖', '광'),
+  ('珙', '공'),
+  ('珝', '후'),
+  ('珞', '락'),
+  ('珠', '주'),
+  ('珣', '순'),
+  ('珤', '보'),
+  ('珥', '이'),
+  ('珦', '향'),
+  ('珩', '형'),
+  ('珪', '규'),
+  ('班', '반'),
+  ('珷', '무'),
+  ('珹', '성'),
+  ('珽', '정'),
+  ('現', '현'),
+  ('琁', '선'),
+  ('球', '구'),
+  ('琅', '랑'),
+  ('理', '리'),
+  ('琇', '수'),
+  ('琉', '류'),
+  ('琓', '완'),
+  ('琛', '침'),
+  ('琠', '전'),
+  ('琡', '숙'),
+  ('琢', '탁'),
+  ('琥', '호'),
+  ('琦', '기'),
+  ('琨', '곤'),
+  ('琪', '기'),
+  ('琫', '봉'),
+  ('琬', '완'),
+  ('琮', '종'),
+  ('琯', '관'),
+  ('琰', '염'),
+  ('琳', '림'),
+  ('琴', '금'),
+  ('琵', '비'),
+  ('琶', '파'),
+  ('琸', '탁'),
+  ('琺', '법'),
+  ('琿', '혼'),
+  ('瑀', '우'),
+  ('瑁', '모'),
+  ('瑃', '춘'),
+  ('瑄', '선'),
+  ('瑋', '위'),
+  ('瑕', '하'),
+  ('瑗', '원'),
+  ('瑙', '노'),
+  ('瑚', '호'),
+  ('瑛', '영'),
+  ('瑜', '유'),
+  ('瑞', '서'),
+  ('瑟', '슬'),
+  ('瑠', '류'),
+  ('瑢', '용'),
+  ('瑤', '요'),
+  ('瑥', '온'),
+  ('瑨', '진'),
+  ('瑩', '영'),
+  ('瑪', '마'),
+  ('瑯', '랑'),
+  ('瑾', '근'),
+  ('璂', '기'),
+  ('璃', '리'),
+  ('璇', '선'),
+  ('璉', '련'),
+  ('璋', '장'),
+  ('璘', '린'),
+  ('璜', '황'),
+  ('璞', '박'),
+  ('璟', '경'),
+  ('璡', '진'),
+  ('璣', '기'),
+  ('璥', '경'),
+  ('璧', '벽'),
+  ('璨', '찬'),
+  ('璪', '조'),
+  ('環', '환'),
+  ('璲', '수'),
+  ('璵', '여'),
+  ('璹', '숙'),
+  ('璽', '새'),
+  ('璿', '선'),
+  ('瓆', '질'),
+  ('瓊', '경'),
+  ('瓏', '롱'),
+  ('瓔', '영'),
+  ('瓘', '관'),
+  ('瓚', '찬'),
+  ('瓜', '과'),
+  ('瓠', '호'),
+  ('瓢', '표'),
+  ('瓣', '판'),
+  ('瓦', '와'),
+  ('瓮', '옹'),
+  ('瓶', '병'),
+  ('瓷', '자'),
+  ('甁', '병'),
+  ('甄', '견'),
+  ('甑', '증'),
+  ('甕', '옹'),
+  ('甘', '감'),
+  ('甚', '심'),
+  ('甛', '첨'),
+  ('生', '생'),
+  ('産', '산'),
+  ('甥', '생'),
+  ('甦', '소'),
+  ('用', '용'),
+  ('甫', '보'),
+  ('甬', '용'),
+  ('田', '전'),
+  ('由', '유'),
+  ('甲', '갑'),
+  ('申', '신'),
+  ('男', '남'),
+  ('甸', '전'),
+  ('町', '정'),
+  ('画', '화'),
+  ('畇', '균'),
+  ('界', '계'),
+  ('畏', '외'),
+  ('畑', '전'),
+  ('畓', '답'),
+  ('畔', '반'),
+  ('留', '류'),
+  ('畛', '진'),
+  ('畜', '축'),
+  ('畝', '무'),
+  ('畢', '필'),
+  ('略', '략'),
+  ('畦', '휴'),
+  ('番', '번'),
+  ('畯', '준'),
+  ('異', '이'),
+  ('畵', '화'),
+  ('當', '당'),
+  ('畸', '기'),
+  ('畺', '강'),
+  ('畿', '기'),
+  ('疆', '강'),
+  ('疇', '주'),
+  ('疊', '첩'),
+  ('疋', '필'),
+  ('疎', '소'),
+  ('疏', '소'),
+  ('疑', '의'),
+  ('疝', '산'),
+  ('疥', '개'),
+  ('疫', '역'),
+  ('疱', '포'),
+  ('疲', '피'),
+  ('疳', '감'),
+  ('疵', '자'),
+  ('疸', '달'),
+  ('疹', '진'),
+  ('疼', '동'),
+  ('疽', '저'),
+  ('疾', '질'),
+  ('痂', '가'),
+  ('病', '병'),
+  ('症', '증'),
+  ('痍', '이'),
+  ('痒', '양'),
+  ('痔', '치'),
+  ('痕', '흔'),
+  ('痘', '두'),
+  ('痙', '경'),
+  ('痛', '통'),
+  ('痢', '리'),
+  ('痰', '담'),
+  ('痲', '마'),
+  ('痴', '치'),
+  ('痺', '비'),
+  ('痼', '고'),
+  ('瘀', '어'),
+  ('瘍', '양'),
+  ('瘙', '소'),
+  ('瘟', '온'),
+  ('瘠', '척'),
+  ('瘡', '창'),
+  ('瘢', '반'),
+  ('瘤', '류'),
+  ('瘦', '수'),
+  ('瘻', '루'),
+  ('療', '료'),
+  ('癌', '암'),
+  ('癎', '간'),
+  ('癒', '유'),
+  ('癖', '벽'),
+  ('癡', '치'),
+  ('癤', '절'),
+  ('癩', '라'),
+  ('癬', '선'),
+  ('癰', '옹'),
+  ('癲', '전'),
+  ('癸', '계'),
+  ('発', '발'),
+  ('登', '등'),
+  ('發', '발'),
+  ('白', '백'),
+  ('百', '백'),
+  ('的', '적'),
+  ('皆', '개'),
+  ('皇', '황'),
+  ('皎', '교'),
+  ('皐', '고'),
+  ('皓', '호'),
+  ('皮', '피'),
+  ('皺', '추'),
+  ('皿', '명'),
+  ('盂', '우'),
+  ('盃', '배'),
+  ('盆', '분'),
+  ('盈', '영'),
+  ('益', '익'),
+  ('盒', '합'),
+  ('盖', '개'),
+  ('盗', '도'),
+  ('盛', '성'),
+  ('盜', '도'),
+  ('盞', '잔'),
+  ('盟', '맹'),
+  ('盡', '진'),
+  ('監', '감'),
+  ('盤', '반'),
+  ('盧', '로'),
+  ('目', '목'),
+  ('盲', '맹'),
+  ('直', '직'),
+  ('相', '상'),
+  ('盼', '반'),
+  ('盾', '순'),
+  ('省', '성'),
+  ('眄', '면'),
+  ('眈', '탐'),
+  ('眉', '미'),
+  ('看', '간'),
+  ('県', '현'),
+  ('眞', '진'),
+  ('眠', '면'),
+  ('眩', '현'),
+  ('眷', '권'),
+  ('眸', '모'),
+  ('眺', '조'),
+  ('眼', '안'),
+  ('着', '착'),
+  ('睍', '현'),
+  ('睛', '정'),
+  ('睡', '수'),
+  ('督', '독'),
+  ('睦', '목'),
+  ('睫', '첩'),
+  ('睹', '도'),
+  ('睾', '고'),
+  ('睿', '예'),
+  ('瞋', '진'),
+  ('瞑', '명'),
+  ('瞞', '만'),
+  ('瞥', '별'),
+  ('瞬', '순'),
+  ('瞭', '료'),
+  ('瞰', '감'),
+  ('瞳', '동'),
+  ('瞻', '첨'),
+  ('瞼', '검'),
+  ('瞿', '구'),
+  ('矗', '촉'),
+  ('矛', '모'),
+  ('矜', '긍'),
+  ('矢', '시'),
+  ('矣', '의'),
+  ('知', '지'),
+  ('矩', '구'),
+  ('短', '단'),
+  ('矮', '왜'),
+  ('矯', '교'),
+  ('石', '석'),
+  ('砂', '사'),
+  ('砒', '비'),
+  ('研', '연'),
+  ('砕', '쇄'),
+  ('砥', '지'),
+  ('砦', '채'),
+  ('砧', '침'),
+  ('砬', '립'),
+  ('砲', '포'),
+  ('破', '파'),
+  ('硅', '규'),
+  ('硏', '연'),
+  ('硝', '초'),
+  ('硫', '류'),
+  ('硬', '경'),
+  ('硯', '연'),
+  ('硼', '붕'),
+  ('碁', '기'),
+  ('碇', '정'),
+  ('碌', '록'),
+  ('碍', '애'),
+  ('碎', '쇄'),
+  ('碑', '비'),
+  ('碗', '완'),
+  ('碣', '갈'),
+  ('碧', '벽'),
+  ('碩', '석'),
+  ('確', '확'),
+  ('碻', '확'),
+  ('碼', '마'),
+  ('磁', '자'),
+  ('磅', '방'),
+  ('磊', '뢰'),
+  ('磋', '차'),
+  ('磎', '계'),
+  ('磐', '반'),
+  ('磨', '마'),
+  ('磬', '경'),
+  ('磯', '기'),
+  ('磵', '간'),
+  ('磻', '반'),
+  ('礁', '초'),
+  ('礎', '초'),
+  ('礖', '여'),
+  ('礪', '려'),
+  ('礫', '력'),
+  ('礬', '반'),
+  ('示', '시'),
+  ('礼', '례'),
+  ('社', '사'),
+  ('祀', '사'),
+  ('祁', '기'),
+  ('祇', '기'),
+  ('祈', '기'),
+  ('祉', '지'),
+  ('祐', '우'),
+  ('祖', '조'),
+  ('祗', '지'),
+  ('祚', '조'),
+  ('祛', '거'),
+  ('祜', '호'),
+  ('祝', '축'),
+  ('神', '신'),
+  ('祠', '사'),
+  ('祥', '상'),
+  ('票', '표'),
+  ('祭', '제'),
+  ('祺', '기'),
+  ('祿', '록'),
+  ('禁', '금'),
+  ('禅', '선'),
+  ('禍', '화'),
+  ('禎', '정'),
+  ('福', '복'),
+  ('禑', '우'),
+  ('禦', '어'),
+  ('禧', '희'),
+  ('禪', '선'),
+  ('禮', '례'),
+  ('禱', '도'),
+  ('禳', '양'),
+  ('禹', '우'),
+  ('禽', '금'),
+  ('禾', '화'),
+  ('禿', '독'),
+  ('秀', '수'),
+  ('私', '사'),
+  ('秉', '병'),
+  ('秊', '년'),
+  ('秋', '추'),
+  ('科', '과'),
+  ('秒', '초'),
+  ('秕', '비'),
+  ('秘', '비'),
+  ('租', '조'),
+  ('秤', '칭'),
+  ('秦', '진'),
+  ('秧', '앙'),
+  ('秩', '질'),
+  ('称', '칭'),
+  ('移', '이'),
+  ('稀', '희'),
+  ('稅', '세'),
+  ('稈', '간'),
+  ('程', '정'),
+  ('稍', '초'),
+  ('稔', '임'),
+  ('稗', '패'),
+  ('稙', '직'),
+  ('稚', '치'),
+  ('稜', '릉'),
+  ('稟', '품'),
+  ('稠', '조'),
+  ('種', '종'),
+  ('稱', '칭'),
+  ('稲', '도'),
+  ('稶', '욱'),
+  ('稷', '직'),
+  ('稻', '도'),
+  ('稼', '가'),
+  ('稽', '계'),
+  ('稿', '고'),
+  ('穀', '곡'),
+  ('穂', '수'),
+  ('穆', '목'),
+  ('穉', '치'),
+  ('積', '적'),
+  ('穎', '영'),
+  ('穏', '온'),
+  ('穗', '수'),
+  ('穡', '색'),
+  ('穢', '예'),
+  ('穩', '온'),
+  ('穫', '확'),
+  ('穰', '양'),
+  ('穴', '혈'),
+  ('究', '구'),
+  ('穹', '궁'),
+  ('空', '공'),
+  ('穽', '정'),
+  ('穿', '천'),
+  ('突', '돌'),
+  ('窃', '절'),
+  ('窄', '착'),
+  ('窈', '요'),
+  ('窒', '질'),
+  ('窓', '창'),
+  ('窕', '조'),
+  ('窘', '군'),
+  ('窟', '굴'),
+  ('窩', '와'),
+  ('窪', '와'),
+  ('窮', '궁'),
+  ('窯', '요'),
+  ('窺', '규'),
+  ('竄', '찬'),
+  ('竅', '규'),
+  ('竇', '두'),
+  ('竊', '절'),
+  ('立', '립'),
+  ('竗', '묘'),
+  ('站', '참'),
+  ('竜', '룡'),
+  ('竝', '병'),
+  ('竟', '경'),
+  ('章', '장'),
+  ('竣', '준'),
+  ('童', '동'),
+  ('竪', '수'),
+  ('竭', '갈'),
+  ('端', '단'),
+  ('競', '경'),
+  ('竹', '죽'),
+  ('竺', '축'),
+  ('竿', '간'),
+  ('笏', '홀'),
+  ('笑', '소'),
+  ('笙', '생'),
+  ('笛', '적'),
+  ('笞', '태'),
+  ('笠', '립'),
+  ('符', '부'),
+  ('第', '제'),
+  ('笭', '령'),
+  ('笹', '세'),
+  ('筆', '필'),
+  ('等', '등'),
+  ('筋', '근'),
+  ('筌', '전'),
+  ('筍', '순'),
+  ('筏', '벌'),
+  ('筐', '광'),
+  ('筑', '축'),
+  ('筒', '통'),
+  ('答', '답'),
+  ('策', '책'),
+  ('筠', '균'),
+  ('筬', '성'),
+  ('筮', '서'),
+  ('筵', '연'),
+  ('筽', '오'),
+  ('箇', '개'),
+  ('箋', '전'),
+  ('箏', '쟁'),
+  ('箔', '박'),
+  ('箕', '기'),
+  ('算', '산'),
+  ('箚', '차'),
+  ('箝', '겸'),
+  ('管', '관'),
+  ('箭', '전'),
+  ('箱', '상'),
+  ('箴', '잠'),
+  ('箸', '저'),
+  ('節', '절'),
+  ('篁', '황'),
+  ('範', '범'),
+  ('篆', '전'),
+  ('篇', '편'),
+  ('築', '축'),
+  ('篒', '식'),
+  ('篠', '소'),
+  ('篤', '독'),
+  ('篩', '사'),
+  ('簇', '족'),
+  ('簒', '찬'),
+  ('簞', '단'),
+  ('簡', '간'),
+  ('簧', '황'),
+  ('簪', '잠'),
+  ('簫', '소'),
+  ('簽', '첨'),
+  ('簾', '렴'),
+  ('簿', '부'),
+  ('籃', '람'),
+  ('籌', '주'),
+  ('籍', '적'),
+  ('籠', '롱'),
+  ('籤', '첨'),
+  ('籬', '리'),
+  ('米', '미'),
+  ('粃', '비'),
+  ('粉', '분'),
+  ('粋', '수'),
+  ('粒', '립'),
+  ('粕', '박'),
+  ('粗', '조'),
+  ('粘', '점'),
+  ('粛', '숙'),
+  ('粟', '속'),
+  ('粥', '죽'),
+  ('粧', '장'),
+  ('粮', '량'),
+  ('粱', '량'),
+  ('粲', '찬'),
+  ('粳', '갱'),
+  ('粹', '수'),
+  ('精', '정'),
+  ('糊', '호'),
+  ('糖', '당'),
+  ('糞', '분'),
+  ('糟', '조'),
+  ('糠', '강'),
+  ('糧', '량'),
+  ('糸', '사'),
+  ('系', '계'),
+  ('糾', '규'),
+  ('紀', '기'),
+  ('紂', '주'),
+  ('約', '약'),
+  ('紅', '홍'),
+  ('紆', '우'),
+  ('紇', '흘'),
+  ('紈', '환'),
+  ('紊', '문'),
+  ('紋', '문'),
+  ('納', '납'),
+  ('紐', '뉴'),
+  ('純', '순'),
+  ('紗', '사'),
+  ('紘', '굉'),
+  ('紙', '지'),
+  ('級', '급'),
+  ('紛', '분'),
+  ('素', '소'),
+  ('紡', '방'),
+  ('索', '색'),
+  ('紫', '자'),
+  ('紬', '주'),
+  ('紮', '찰'),
+  ('累', '루'),
+  ('細', '세'),
+  ('紳', '신'),
+  ('紵', '저'),
+  ('紹', '소'),
+  ('紺', '감'),
+  ('終', '종'),
+  ('絃', '현'),
+  ('組', '조'),
+  ('絅', '경'),
+  ('絆', '반'),
+  ('経', '경'),
+  ('結', '결'),
+  ('絞', '교'),
+  ('絡', '락'),
+  ('絢', '현'),
+  ('給', '급'),
+  ('絨', '융'),
+  ('絪', '인'),
+  ('絮', '서'),
+  ('統', '통'),
+  ('絲', '사'),
+  ('絳', '강'),
+  ('絵', '회'),
+  ('絶', '절'),
+  ('絹', '견'),
+  ('絿', '구'),
+  ('綎', '정'),
+  ('綏', '수'),
+  ('經', '경'),
+  ('継', '계'),
+  ('続', '속'),
+  ('綜', '종'),
+  ('綠', '록'),
+  ('綢', '주'),
+  ('綬', '수'),
+  ('維', '유'),
+  ('綱', '강'),
+  ('網', '망'),
+  ('綴', '철'),
+  ('綵', '채'),
+  ('綸', '륜'),
+  ('綺', '기'),
+  ('綻', '탄'),
+  ('綽', '작'),
+  ('綾', '릉'),
+  ('綿', '면'),
+  ('緇', '치'),
+  ('緊', '긴'),
+  ('緋', '비'),
+  ('総', '총'),
+  ('緑', '록'),
+  ('緖', '서'),
+  ('緘', '함'),
+  ('線', '선'),
+  ('緝', '집'),
+  ('緞', '단'),
+  ('締', '체'),
+  ('緡', '민'),
+  ('緣', '연'),
+  ('編', '편'),
+  ('緩', '완'),
+  ('緬', '면'),
+  ('緯', '위'),
+  ('練', '련'),
+  ('緻', '치'),
+  ('縄', '승'),
+  ('縉', '진'),
+  ('縊', '액'),
+  ('縕', '온'),
+  ('縛', '박'),
+  ('縝', '진'),
+  ('縞', '호'),
+  ('縟', '욕'),
+  ('縡', '재'),
+  ('縣', '현'),
+  ('縦', '종'),
+  ('縫', '봉'),
+  ('縮', '축'),
+  ('縯', '연'),
+  ('縱', '종'),
+  ('縷', '루'),
+  ('總', '총'),
+  ('績', '적'),
+  ('繁', '번'),
+  ('繃', '붕'),
+  ('繆', '무'),
+  ('繇', '요'),
+  ('繊', '섬'),
+  ('繒', '증'),
+  ('織', '직'),
+  ('繕', '선'),
+  ('繞', '요'),
+  ('繡', '수'),
+  ('繩', '승'),
+  ('繪', '회'),
+  ('繫', '계'),
+  ('繭', '견'),
+  ('繰', '조'),
+  ('繹', '역'),
+  ('繼', '계'),
+  ('纂', '찬'),
+  ('續', '속'),
+  ('纏', '전'),
+  ('纓', '영'),
+  ('纖', '섬'),
+  ('纘', '찬'),
+  ('纛', '독'),
+  ('纜', '람'),
+  ('缶', '부'),
+  ('缸', '항'),
+  ('缺', '결'),
+  ('罌', '앵'),
+  ('罐', '관'),
+  ('罔', '망'),
+  ('罕', '한'),
+  ('罪', '죄'),
+  ('罫', '괘'),
+  ('置', '치'),
+  ('罰', '벌'),
+  ('署', '서'),
+  ('罵', '매'),
+  ('罷', '파'),
+  ('罹', '리'),
+  ('羅', '라'),
+  ('羈', '기'),
+  ('羊', '양'),
+  ('羌', '강'),
+  ('美', '미'),
+  ('羔', '고'),
+  ('羚', '령'),
+  ('羞', '수'),
+  ('群', '군'),
+  ('羨', '선'),
+  ('義', '의'),
+  ('羲', '희'),
+  ('羸', '리'),
+  ('羹', '갱'),
+  ('羽', '우'),
+  ('翁', '옹'),
+  ('翅', '시'),
+  ('翊', '익'),
+  ('翌', '익'),
+  ('翎', '령'),
+  ('習', '습'),
+  ('翔', '상'),
+  ('翕', '흡'),
+  ('翟', '적'),
+  ('翠', '취'),
+  ('翡', '비'),
+  ('翩', '편'),
+  ('翫', '완'),
+  ('翰', '한'),
+  ('翹', '교'),
+  ('翻', '번'),
+  ('翼', '익'),
+  ('耀', '요'),
+  ('老', '로'),
+  ('考', '고'),
+  ('者', '자'),
+  ('耆', '기'),
+  ('耉', '구'),
+  ('而', '이'),
+  ('耐', '내'),
+  ('耕', '경'),
+  ('耗', '모'),
+  ('耘', '운'),
+  ('耭', '기'),
+  ('耳', '이'),
+  ('耶', '야'),
+  ('耽', '탐'),
+  ('耿', '경'),
+  ('聃', '담'),
+  ('聆', '령'),
+  ('聊', '료'),
+  ('聖', '성'),
+  ('聘', '빙'),
+  ('聚', '취'),
+  ('聞', '문'),
+  ('聯', '련'),
+  ('聰', '총'),
+  ('聲', '성'),
+  ('聳', '용'),
+  ('聴', '청'),
+  ('職', '직'),
+  ('聽', '청'),
+  ('聾', '롱'),
+  ('聿', '율'),
+  ('肄', '이'),
+  ('肅', '숙'),
+  ('肆', '사'),
+  ('肇', '조'),
+  ('肉', '육'),
+  ('肋', '륵'),
+  ('肌', '기'),
+  ('肖', '초'),
+  ('肛', '항'),
+  ('肝', '간'),
+  ('股', '고'),
+  ('肢', '지'),
+  ('肥', '비'),
+  ('肩', '견'),
+  ('肪', '방'),
+  ('肯', '긍'),
+  ('肱', '굉'),
+  ('育', '육'),
+  ('肴', '효'),
+  ('肺', '폐'),
+  ('胃', '위'),
+  ('胄', '주'),
+  ('胆', '담'),
+  ('背', '배'),
+  ('胎', '태'),
+  ('胚', '배'),
+  ('胛', '갑'),
+  ('胞', '포'),
+  ('胡', '호'),
+  ('胤', '윤'),
+  ('胥', '서'),
+  ('胱', '광'),
+  ('胴', '동'),
+  ('胸', '흉'),
+  ('能', '능'),
+  ('脂', '지'),
+  ('脅', '협'),
+  ('脆', '취'),
+  ('脇', '협'),
+  ('脈', '맥'),
+  ('脊', '척'),
+  ('脘', '완'),
+  ('脚', '각'),
+  ('脛', '경'),
+  ('脣', '순'),
+  ('脩', '수'),
+  ('脫', '탈'),
+  ('脯', '포'),
+  ('脳', '뇌'),
+  ('脹', '창'),
+  ('脾', '비'),
+  ('腋', '액'),
+  ('腎', '신'),
+  ('腐', '부'),
+  ('腑', '부'),
+  ('腔', '강'),
+  ('腕', '완'),
+  ('腥', '성'),
+  ('腦', '뇌'),
+  ('腫', '종'),
+  ('腰', '요'),
+  ('腱', '건'),
+  ('腸', '장'),
+  ('腹', '복'),
+  ('腺', '선'),
+  ('腿', '퇴'),
+  ('膀', '방'),
+  ('膈', '격'),
+  ('膊', '박'),
+  ('膏', '고'),
+  ('膚', '부'),
+  ('膜', '막'),
+  ('膝', '슬'),
+  ('膠', '교'),
+  ('膣', '질'),
+  ('膨', '팽'),
+  ('膳', '선'),
+  ('膵', '췌'),
+  ('膺', '응'),
+  ('膽', '담'),
+  ('膾', '회'),
+  ('膿', '농'),
+  ('臀', '둔'),
+  ('臂', '비'),
+  ('臆', '억'),
+  ('臍', '제'),
+  ('臓', '장'),
+  ('臘', '랍'),
+  ('臟', '장'),
+  ('臣', '신'),
+  ('臥', '와'),
+  ('臧', '장'),
+  ('臨', '림'),
+  ('自', '자'),
+  ('臭', '취'),
+  ('至', '지'),
+  ('致', '치'),
+  ('臺', '대'),
+  ('臻', '진'),
+  ('臼', '구'),
+  ('臾', '유'),
+  ('舅', '구'),
+  ('與', '여'),
+  ('興', '흥'),
+  ('舊', '구'),
+  ('舌', '설'),
+  ('舍', '사'),
+  ('舒', '서'),
+  ('舗', '포'),
+  ('舛', '천'),
+  ('舜', '순'),
+  ('舞', '무'),
+  ('舟', '주'),
+  ('舡', '강'),
+  ('航', '항'),
+  ('舫', '방'),
+  ('般', '반'),
+  ('舵', '타'),
+  ('舶', '박'),
+  ('舷', '현'),
+  ('船', '선'),
+  ('艀', '부'),
+  ('艅', '여'),
+  ('艇', '정'),
+  ('艙', '창'),
+  ('艤', '의'),
+  ('艦', '함'),
+  ('艮', '간'),
+  ('良', '량'),
+  ('艱', '간'),
+  ('色', '색'),
+  ('艶', '염'),
+  ('艸', '초'),
+  ('艾', '애'),
+  ('芋', '우'),
+  ('芍', '작'),
+  ('芎', '궁'),
+  ('芒', '망'),
+  ('芙', '부'),
+  ('芚', '둔'),
+  ('芝', '지'),
+  ('芟', '삼'),
+  ('芥', '개'),
+  ('芦', '호'),
+  ('芩', '금'),
+  ('芬', '분'),
+  ('芭', '파'),
+  ('芮', '예'),
+  ('芯', '심'),
+  ('花', '화'),
+  ('芳', '방'),
+  ('芷', '지'),
+  ('芸', '운'),
+  ('芹', '근'),
+  ('芻', '추'),
+  ('芼', '모'),
+  ('芽', '아'),
+  ('芿', '잉'),
+  ('苑', '원'),
+  ('苒', '염'),
+  ('苔', '태'),
+  ('苕', '초'),
+  ('苗', '묘'),
+  ('苛', '가'),
+  ('苞', '포'),
+  ('苟', '구'),
+  ('苡', '이'),
+  ('若', '약'),
+  ('苦', '고'),
+  ('苧', '저'),
+  ('英', '영'),
+  ('苽', '고'),
+  ('苾', '필'),
+  ('茁', '줄'),
+  ('茂', '무'),
+  ('范', '범'),
+  ('茄', '가'),
+  ('茅', '모'),
+  ('茉', '말'),
+  ('茎', '경'),
+  ('茗', '명'),
+  ('茨', '자'),
+  ('茫', '망'),
+  ('茯', '복'),
+  ('茱', '수'),
+  ('茴', '회'),
+  ('茵', '인'),
+  ('茶', '다'),
+  ('茸', '용'),
+  ('茹', '여'),
+  ('荀', '순'),
+  ('荇', '행'),
+  ('草', '초'),
+  ('荊', '형'),
+  ('荏', '임'),
+  ('荑', '이'),
+  ('荒', '황'),
+  ('荘', '장'),
+  ('荳', '두'),
+  ('荷', '하'),
+  ('荻', '적'),
+  ('莉', '리'),
+  ('莊', '장'),
+  ('莎', '사'),
+  ('莖', '경'),
+  ('莘', '신'),
+  ('莞', '완'),
+  ('莢', '협'),
+  ('莩', '부'),
+  ('莪', '아'),
+  ('莫', '막'),
+  ('莽', '망'),
+  ('菁', '청'),
+  ('菅', '관'),
+  ('菉', '록'),
+  ('菊', '국'),
+  ('菌', '균'),
+  ('菓', '과'),
+  ('菖', '창'),
+  ('菜', '채'),
+  ('菩', '보'),
+  ('菫', '근'),
+  ('華', '화'),
+  ('菰', '고'),
+  ('菱', '릉'),
+  ('菲', '비'),
+  ('菴', '암'),
+  ('菹', '저'),
+  ('菽', '숙'),
+  ('萃', '췌'),
+  ('萄', '도'),
+  ('萊', '래'),
+  ('萌', '맹'),
+  ('萍', '평'),
+  ('萎', '위'),
+  ('萩', '추'),
+  ('萬', '만'),
+  ('萱', '훤'),
+  ('萸', '유'),
+  ('落', '락'),
+  ('葉', '엽'),
+  ('著', '저'),
+  ('葛', '갈'),
+  ('葡', '포'),
+  ('董', '동'),
+  ('葦', '위'),
+  ('葫', '호'),
+  ('葬', '장'),
+  ('葯', '약'),
+  ('葵', '규'),
+  ('葺', '즙'),
+  ('蒐', '수'),
+  ('蒔', '시'),
+  ('蒙', '몽'),
+  ('蒜', '산'),
+  ('蒡', '방'),
+  ('蒲', '포'),
+  ('蒸', '증'),
+  ('蒻', '약'),
+  ('蒼', '창'),
+  ('蒿', '호'),
+  ('蓀', '손'),
+  ('蓂', '명'),
+  ('蓄', '축'),
+  ('蓆', '석'),
+  ('蓉', '용'),
+  ('蓋', '개'),
+  ('蓍', '시'),
+  ('蓑', '사'),
+  ('蓚', '수'),
+  ('蓬', '봉'),
+  ('蓮', '련'),
+  ('蓴', '순'),
+  ('蓼', '료'),
+  ('蔑', '멸'),
+  ('蔓', '만'),
+  ('蔔', '복'),
+  ('蔗', '자'),
+  ('蔘', '삼'),
+  ('蔚', '울'),
+  ('蔞', '루'),
+  ('蔡', '채'),
+  ('蔣', '장'),
+  ('蔥', '총'),
+  ('蔬', '소'),
+  ('蔭', '음'),
+  ('蔯', '진'),
+  ('蔵', '장'),
+  ('蔽', '폐'),
+  ('蔿', '위'),
+  ('蕁', '담'),
+  ('蕃', '번'),
+  ('蕉', '초'),
+  ('蕎', '교'),
+  ('蕓', '운'),
+  ('蕙', '혜'),
+  ('蕣', '순'),
+  ('蕨', '궐'),
+  ('蕩', '탕'),
+  ('蕪', '무'),
+  ('蕭', '소'),
+  ('薄', '박'),
+  ('薇', '미'),
+  ('薏', '의'),
+  ('薑', '강'),
+  ('薔', '장'),
+  ('薛', '설'),
+  ('薦', '천'),
+  ('薨', '훙'),
+  ('薩', '살'),
+  ('薪', '신'),
+  ('薫', '훈'),
+  ('薬', '약'),
+  ('薯', '서'),
+  ('薰', '훈'),
+  ('薺', '제'),
+  ('藁', '고'),
+  ('藉', '자'),
+  ('藍', '람'),
+  ('藎', '신'),
+  ('藏', '장'),
+  ('藕', '우'),
+  ('藜', '려'),
+  ('藝', '예'),
+  ('藤', '등'),
+  ('藥', '약'),
+  ('藩', '번'),
+  ('藪', '수'),
+  ('藷', '저'),
+  ('藺', '린'),
+  ('藻', '조'),
+  ('藿', '곽'),
+  ('蘂', '예'),
+  ('蘆', '로'),
+  ('蘇', '소'),
+  ('蘊', '온'),
+  ('蘖', '얼'),
+  ('蘗', '벽'),
+  ('蘚', '선'),
+  ('蘭', '란'),
+  ('蘿', '라'),
+  ('虎', '호'),
+  ('虐', '학'),
+  ('虔', '건'),
+  ('處', '처'),
+  ('虚', '허'),
+  ('虛', '허'),
+  ('虜', '로'),
+  ('虞', '우'),
+  ('號', '호'),
+  ('虧', '휴'),
+  ('虫', '충'),
+  ('虹', '홍'),
+  ('蚊', '문'),
+  ('蚌', '방'),
+  ('蚓', '인'),
+  ('蚕', '잠'),
+  ('蚣', '공'),
+  ('蚤', '조'),
+  ('蚩', '치'),
+  ('蛇', '사'),
+  ('蛋', '단'),
+  ('蛍', '형'),
+  ('蛔', '회'),
+  ('蛙', '와'),
+  ('蛛', '주'),
+  ('蛟', '교'),
+  ('蛤', '합'),
+  ('蛭', '질'),
+  ('蛮', '만'),
+  ('蛾', '아'),
+  ('蜀', '촉'),
+  ('蜂', '봉'),
+  ('蜃', '신'),
+  ('蜈', '오'),
+  ('蜘', '지'),
+  ('蜚', '비'),
+  ('蜜', '밀'),
+  ('蝎', '갈'),
+  ('蝕', '식'),
+  ('蝗', '황'),
+  ('蝟', '위'),
+  ('蝦', '하'),
+  ('蝨', '슬'),
+  ('蝴', '호'),
+  ('蝶', '접'),
+  ('蝸', '와'),
+  ('螂', '랑'),
+  ('融', '융'),
+  ('螟', '명'),
+  ('螢', '형'),
+  ('螳', '당'),
+  ('螺', '라'),
+  ('蟄', '칩'),
+  ('蟠', '반'),
+  ('蟬', '선'),
+  ('蟯', '요'),
+  ('蟲', '충'),
+  ('蟹', '해'),
+  ('蟻', '의'),
+  ('蟾', '섬'),
+  ('蠅', '승'),
+  ('蠟', '랍'),
+  ('蠢', '준'),
+  ('蠣', '려'),
+  ('蠱', '고'),
+  ('蠶', '잠'),
+  ('蠻', '만'),
+  ('血', '혈'),
+  ('衆', '중'),
+  ('行', '행'),
+  ('衍', '연'),
+  ('衒', '현'),
+  ('術', '술'),
+  ('街', '가'),
+  ('衙', '아'),
+  ('衛', '위'),
+  ('衝', '충'),
+  ('衡', '형'),
+  ('衢', '구'),
+  ('衣', '의'),
+  ('表', '표'),
+  ('衫', '삼'),
+  ('衰', '쇠'),
+  ('衲', '납'),
+  ('衷', '충'),
+  ('衾', '금'),
+  ('衿', '금'),
+  ('袁', '원'),
+  ('袂', '몌'),
+  ('袈', '가'),
+  ('袋', '대'),
+  ('袍', '포'),
+  ('袒', '단'),
+  ('袖', '수'),
+  ('袗', '진'),
+  ('袞', '곤'),
+  ('被', '피'),
+  ('袴', '고'),
+  ('裁', '재'),
+  ('裂', '렬'),
+  ('装', '장'),
+  ('裏', '리'),
+  ('裔', '예'),
+  ('裕', '유'),
+  ('裙', '군'),
+  ('補', '보'),
+  ('裝', '장'),
+  ('裟', '사'),
+  ('裡', '리'),
+  ('裨', '비'),
+  ('裳', '상'),
+  ('裴', '배'),
+  ('裵', '배'),
+  ('裸', '라'),
+  ('製', '제'),
+  ('複', '복'),
+  ('褐', '갈'),
+  ('褒', '포'),
+  ('褓', '보'),
+  ('褘', '위'),
+  ('褙', '배'),
+  ('褥', '욕'),
+  ('褪', '퇴'),
+  ('褶', '습'),
+  ('褸', '루'),
+  ('褻', '설'),
+  ('襁', '강'),
+  ('襄', '양'),
+  ('襟', '금'),
+  ('襤', '람'),
+  ('襪', '말'),
+  ('襲', '습'),
+  ('西', '서'),
+  ('要', '요'),
+  ('覃', '담'),
+  ('覆', '복'),
+  ('覇', '패'),
+  ('見', '견'),
+  ('規', '규'),
+  ('覓', '멱'),
+  ('視', '시'),
+  ('覚', '각'),
+  ('覡', '격'),
+  ('覧', '람'),
+  ('覩', '도'),
+  ('親', '친'),
+  ('覲', '근'),
+  ('観', '관'),
+  ('覺', '각'),
+  ('覽', '람'),
+  ('觀', '관'),
+  ('角', '각'),
+  ('解', '해'),
+  ('触', '촉'),
+  ('觴', '상'),
+  ('觸', '촉'),
+  ('言', '언'),
+  ('訂', '정'),
+  ('訃', '부'),
+  ('計', '계'),
+  ('訊', '신'),
+  ('訌', '홍'),
+  ('討', '토'),
+  ('訓', '훈'),
+  ('訖', '흘'),
+  ('託', '탁'),
+  ('記', '기'),
+  ('訛', '와'),
+  ('訝', '아'),
+  ('訟', '송'),
+  ('訣', '결'),
+  ('訥', '눌'),
+  ('訪', '방'),
+  ('設', '설'),
+  ('許', '허'),
+  ('訳', '역'),
+  ('訴', '소'),
+  ('訶', '가'),
+  ('診', '진'),
+  ('註', '주'),
+  ('証', '증'),
+  ('詐', '사'),
+  ('詔', '조'),
+  ('評', '평'),
+  ('詛', '저'),
+  ('詞', '사'),
+  ('詠', '영'),
+  ('詢', '순'),
+  ('詣', '예'),
+  ('試', '시'),
+  ('詩', '시'),
+  ('詭', '궤'),
+  ('詮', '전'),
+  ('詰', '힐'),
+  ('話', '화'),
+  ('該', '해'),
+  ('詳', '상'),
+  ('詵', '선'),
+  ('詹', '첨'),
+  ('誅', '주'),
+  ('誇', '과'),
+  ('誉', '예'),
+  ('誌', '지'),
+  ('認', '인'),
+  ('誓', '서'),
+  ('誕', '탄'),
+  ('誘', '유'),
+  ('語', '어'),
+  ('誠', '성'),
+  ('誡', '계'),
+  ('誣', '무'),
+  ('誤', '오'),
+  ('誥', '고'),
+  ('誦', '송'),
+  ('誨', '회'),
+  ('說', '설'),
+  ('読', '독'),
+  ('誰', '수'),
+  ('課', '과'),
+  ('誹', '비'),
+  ('誼', '의'),
+  ('誾', '은'),
+  ('調', '조'),
+  ('諂', '첨'),
+  ('諄', '순'),
+  ('談', '담'),
+  ('請', '청'),
+  ('諍', '쟁'),
+  ('諏', '추'),
+  ('諒', '량'),
+  ('論', '론'),
+  ('諛', '유'),
+  ('諜', '첩'),
+  ('諡', '시'),
+  ('諦', '체'),
+  ('諧', '해'),
+  ('諪', '정'),
+  ('諫', '간'),
+  ('諭', '유'),
+  ('諮', '자'),
+  ('諱', '휘'),
+  ('諶', '심'),
+  ('諷', '풍'),
+  ('諸', '제'),
+  ('諺', '언'),
+  ('諾', '낙'),
+  ('謀', '모'),
+  ('謁', '알'),
+  ('謂', '위'),
+  ('謄', '등'),
+  ('謎', '미'),
+  ('謐', '밀'),
+  ('謔', '학'),
+  ('謖', '속'),
+  ('謗', '방'),
+  ('謙', '겸'),
+  ('謚', '익'),
+  ('講', '강'),
+  ('謝', '사'),
+  ('謠', '요'),
+  ('謡', '요'),
+  ('謨', '모'),
+  ('謫', '적'),
+  ('謬', '류'),
+  ('謳', '구'),
+  ('謹', '근'),
+  ('譁', '화'),
+  ('證', '증'),
+  ('譎', '휼'),
+  ('譏', '기'),
+  ('識', '식'),
+  ('譚', '담'),
+  ('譜', '보'),
+  ('警', '경'),
+  ('譬', '비'),
+  ('譯', '역'),
+  ('議', '의'),
+  ('譲', '양'),
+  ('譴', '견'),
+  ('護', '호'),
+  ('譽', '예'),
+  ('讀', '독'),
+  ('變', '변'),
+  ('讐', '수'),
+  ('讒', '참'),
+  ('讓', '양'),
+  ('讖', '참'),
+  ('讚', '찬'),
+  ('谷', '곡'),
+  ('谿', '계'),
+  ('豁', '활'),
+  ('豆', '두'),
+  ('豈', '기'),
+  ('豊', '풍'),
+  ('豌', '완'),
+  ('豕', '시'),
+  ('豚', '돈'),
+  ('象', '상'),
+  ('豪', '호'),
+  ('豫', '예'),
+  ('豹', '표'),
+  ('豺', '시'),
+  ('貂', '초'),
+  ('貊', '맥'),
+  ('貌', '모'),
+  ('貝', '패'),
+  ('貞', '정'),
+  ('負', '부'),
+  ('財', '재'),
+  ('貢', '공'),
+  ('貧', '빈'),
+  ('貨', '화'),
+  ('販', '판'),
+  ('貪', '탐'),
+  ('貫', '관'),
+  ('責', '책'),
+  ('貯', '저'),
+  ('貰', '세'),
+  ('貳', '이'),
+  ('貴', '귀'),
+  ('貶', '폄'),
+  ('買', '매'),
+  ('貸', '대'),
+  ('費', '비'),
+  ('貼', '첩'),
+  ('貽', '이'),
+  ('貿', '무'),
+  ('賀', '하'),
+  ('賁', '분'),
+  ('賂', '뢰'),
+  ('賃', '임'),
+  ('賄', '회'),
+  ('資', '자'),
+  ('賈', '가'),
+  ('賊', '적'),
+  ('賑', '진'),
+  ('賓', '빈'),
+  ('賚', '뢰'),
+  ('賛', '찬'),
+  ('賜', '사'),
+  ('賞', '상'),
+  ('賠', '배'),
+  ('賢', '현'),
+  ('賣', '매'),
+  ('賤', '천'),
+  ('賦', '부'),
+  ('質', '질'),
+  ('賭', '도'),
+  ('賴', '뢰'),
+  ('賻', '부'),
+  ('購', '구'),
+  ('賽', '새'),
+  ('贄', '지'),
+  ('贅', '췌'),
+  ('贇', '윤'),
+  ('贈', '증'),
+  ('贊', '찬'),
+  ('贍', '섬'),
+  ('贓', '장'),
+  ('贖', '속'),
+  ('赤', '적'),
+  ('赦', '사'),
+  ('赫', '혁'),
+  ('走', '주'),
+  ('赳', '규'),
+  ('赴', '부'),
+  ('起', '기'),
+  ('超', '초'),
+  ('越', '월'),
+  ('趙', '조'),
+  ('趣', '취'),
+  ('趨', '추'),
+  ('足', '족'),
+  ('趺', '부'),
+  ('趾', '지'),
+  ('跆', '태'),
+  ('跋', '발'),
+  ('跌', '질'),
+  ('跏', '가'),
+  ('跛', '파'),
+  ('距', '거'),
+  ('跡', '적'),
+  ('跣', '선'),
+  ('跨', '과'),
+  ('路', '로'),
+  ('跳', '도'),
+  ('践', '천'),
+  ('踊', '용'),
+  ('踏', '답'),
+  ('踐', '천'),
+  ('踞', '거'),
+  ('踪', '종'),
+  ('踰', '유'),
+  ('踵', '종'),
+  ('蹂', '유'),
+  ('蹄', '제'),
+  ('蹇', '건'),
+  ('蹈', '도'),
+  ('蹉', '차'),
+  ('蹊', '혜'),
+  ('蹙', '축'),
+  ('蹟', '적'),
+  ('蹠', '척'),
+  ('蹴', '축'),
+  ('蹶', '궐'),
+  ('躁', '조'),
+  ('躇', '저'),
+  ('躊', '주'),
+  ('躍', '약'),
+  ('躪', '린'),
+  ('身', '신'),
+  ('躬', '궁'),
+  ('軀', '구'),
+  ('車', '차'),
+  ('軋', '알'),
+  ('軌', '궤'),
+  ('軍', '군'),
+  ('軒', '헌'),
+  ('軟', '연'),
+  ('転', '전'),
+  ('軫', '진'),
+  ('軸', '축'),
+  ('軻', '가'),
+  ('軽', '경'),
+  ('軾', '식'),
+  ('較', '교'),
+  ('輅', '로'),
+  ('載', '재'),
+  ('輒', '첩'),
+  ('輓', '만'),
+  ('輔', '보'),
+  ('輕', '경'),
+  ('輛', '량'),
+  ('輜', '치'),
+  ('輝', '휘'),
+  ('輞', '망'),
+  ('輟', '철'),
+  ('輦', '련'),
+  ('輧', '병'),
+  ('輩', '배'),
+  ('輪', '륜'),
+  ('輯', '집'),
+  ('輳', '주'),
+  ('輸', '수'),
+  ('輹', '복'),
+  ('輻', '복'),
+  ('輾', '전'),
+  ('輿', '여'),
+  ('轄', '할'),
+  ('轅', '원'),
+  ('轉', '전'),
+  ('轍', '철'),
+  ('轎', '교'),
+  ('轝', '여'),
+  ('轟', '굉'),
+  ('轢', '력'),
+  ('辛', '신'),
+  ('辜', '고'),
+  ('辞', '사'),
+  ('辣', '랄'),
+  ('辦', '판'),
+  ('辨', '변'),
+  ('辭', '사'),
+  ('辯', '변'),
+  ('辰', '진'),
+  ('辱', '욕'),
+  ('農', '농'),
+  ('辺', '변'),
+  ('迂', '우'),
+  ('迅', '신'),
+  ('迎', '영'),
+  ('近', '근'),
+  ('返', '반'),
+  ('迦', '가'),
+  ('迪', '적'),
+  ('迫', '박'),
+  ('迭', '질'),
+  ('述', '술'),
+  ('迲', '겁'),
+  ('迷', '미'),
+  ('迹', '적'),
+  ('追', '추'),
+  ('退', '퇴'),
+  ('送', '송'),
+  ('适', '괄'),
+  ('逃', '도'),
+  ('逅', '후'),
+  ('逆', '역'),
+  ('逈', '형'),
+  ('逋', '포'),
+  ('逍', '소'),
+  ('透', '투'),
+  ('逐', '축'),
+  ('逑', '구'),
+  ('逓', '체'),
+  ('途', '도'),
+  ('逕', '경'),
+  ('逗', '두'),
+  ('這', '저'),
+  ('通', '통'),
+  ('逝', '서'),
+  ('逞', '령'),
+  ('速', '속'),
+  ('造', '조'),
+  ('逡', '준'),
+  ('逢', '봉'),
+  ('連', '련'),
+  ('逮', '체'),
+  ('週', '주'),
+  ('進', '진'),
+  ('逵', '규'),
+  ('逸', '일'),
+  ('逼', '핍'),
+  ('逾', '유'),
+  ('遁', '둔'),
+  ('遂', '수'),
+  ('遅', '지'),
+  ('遇', '우'),
+  ('遊', '유'),
+  ('運', '운'),
+  ('遍', '편'),
+  ('過', '과'),
+  ('遐', '하'),
+  ('遑', '황'),
+  ('道', '도'),
+  ('達', '달'),
+  ('違', '위'),
+  ('遙', '요'),
+  ('遜', '손'),
+  ('遝', '답'),
+  ('遞', '체'),
+  ('遠', '원'),
+  ('遡', '소'),
+  ('遣', '견'),
+  ('適', '적'),
+  ('遭', '조'),
+  ('遮', '차'),
+  ('遯', '둔'),
+  ('遲', '지'),
+  ('遵', '준'),
+  ('遷', '천'),
+  ('選', '선'),
+  ('遺', '유'),
+  ('遼', '료'),
+  ('遽', '거'),
+  ('避', '피'),
+  ('邀', '요'),
+  ('邁', '매'),
+  ('邂', '해'),
+  ('邃', '수'),
+  ('還', '환'),
+  ('邇', '이'),
+  ('邈', '막'),
+  ('邊', '변'),
+  ('邏', '라'),
+  ('邑', '읍'),
+  ('邕', '옹'),
+  ('邙', '망'),
+  ('邢', '형'),
+  ('那', '나'),
+  ('邦', '방'),
+  ('邨', '촌'),
+  ('邪', '사'),
+  ('邯', '감'),
+  ('邰', '태'),
+  ('邱', '구'),
+  ('邵', '소'),
+  ('邸', '저'),
+  ('郁', '욱'),
+  ('郊', '교'),
+  ('郎', '랑'),
+  ('郞', '랑'),
+  ('郡', '군'),
+  ('部', '부'),
+  ('郭', '곽'),
+  ('郵', '우'),
+  ('郷', '향'),
+  ('都', '도'),
+  ('鄂', '악'),
+  ('鄒', '추'),
+  ('鄕', '향'),
+  ('鄙', '비'),
+  ('鄧', '등'),
+  ('鄭', '정'),
+  ('鄲', '단'),
+  ('酉', '유'),
+  ('酊', '정'),
+  ('酋', '추'),
+  ('酌', '작'),
+  ('配', '배'),
+  ('酎', '주'),
+  ('酒', '주'),
+  ('酔', '취'),
+  ('酢', '초'),
+  ('酩', '명'),
+  ('酪', '락'),
+  ('酬', '수'),
+  ('酵', '효'),
+  ('酷', '혹'),
+  ('酸', '산'),
+  ('醇', '순'),
+  ('醉', '취'),
+  ('醋', '초'),
+  ('醍', '제'),
+  ('醒', '성'),
+  ('醜', '추'),
+  ('醫', '의'),
+  ('醬', '장'),
+  ('醮', '초'),
+  ('醯', '혜'),
+  ('醱', '발'),
+  ('醴', '례'),
+  ('醵', '갹'),
+  ('醸', '양'),
+  ('釀', '양'),
+  ('采', '채'),
+  ('釈', '석'),
+  ('釉', '유'),
+  ('釋', '석'),
+  ('里', '리'),
+  ('重', '중'),
+  ('野', '야'),
+  ('量', '량'),
+  ('釐', '리'),
+  ('金', '금'),
+  ('釗', '쇠'),
+  ('釘', '정'),
+  ('釜', '부'),
+  ('針', '침'),
+  ('釣', '조'),
+  ('釧', '천'),
+  ('釪', '우'),
+  ('釵', '채'),
+  ('鈍', '둔'),
+  ('鈐', '검'),
+  ('鈑', '판'),
+  ('鈒', '삽'),
+  ('鈗', '윤'),
+  ('鈞', '균'),
+  ('鈴', '령'),
+  ('鈺', '옥'),
+  ('鈿', '전'),
+  ('鉀', '갑'),
+  ('鉄', '철'),
+  ('鉅', '거'),
+  ('鉉', '현'),
+  ('鉗', '겸'),
+  ('鉛', '연'),
+  ('鉞', '월'),
+  ('鉢', '발'),
+  ('鉤', '구'),
+  ('鉥', '술'),
+  ('鉦', '정'),
+  ('鉱', '광'),
+  ('銀', '은'),
+  ('銃', '총'),
+  ('銅', '동'),
+  ('銑', '선'),
+  ('銓', '전'),
+  ('銖', '수'),
+  ('銘', '명'),
+  ('銜', '함'),
+  ('銭', '전'),
+  ('銳', '예'),
+  ('銶', '구'),
+  ('銷', '소'),
+  ('銹', '수'),
+  ('鋌', '정'),
+  ('鋏', '협'),
+  ('鋒', '봉'),
+  ('鋤', '서'),
+  ('鋪', '포'),
+  ('鋳', '주'),
+  ('鋸', '거'),
+  ('鋼', '강'),
+  ('錄', '록'),
+  ('錐', '추'),
+  ('錘', '추'),
+  ('錚', '쟁'),
+  ('錞', '순'),
+  ('錟', '담'),
+  ('錠', '정'),
+  ('錡', '기'),
+  ('錢', '전'),
+  ('錤', '기'),
+  ('錦', '금'),
+  ('錨', '묘'),
+  ('錫', '석'),
+  ('錮', '고'),
+  ('錯', '착'),
+  ('録', '록'),
+  ('鍈', '영'),
+  ('鍊', '련'),
+  ('鍋', '과'),
+  ('鍍', '도'),
+  ('鍔', '악'),
+  ('鍛', '단'),
+  ('鍮', '유'),
+  ('鍵', '건'),
+  ('鍼', '침'),
+  ('鍾', '종'),
+  ('鎌', '겸'),
+  ('鎔', '용'),
+  ('鎖', '쇄'),
+  ('鎚', '추'),
+  ('鎣', '형'),
+  ('鎧', '개'),
+  ('鎬', '호'),
+  ('鎭', '진'),
+  ('鎮', '진'),
+  ('鎰', '일'),
+  ('鏃', '족'),
+  ('鏑', '적'),
+  ('鏞', '용'),
+  ('鏡', '경'),
+  ('鏤', '루'),
+  ('鏶', '집'),
+  ('鐄', '횡'),
+  ('鐘', '종'),
+  ('鐥', '선'),
+  ('鐫', '전'),
+  ('鐵', '철'),
+  ('鐸', '탁'),
+  ('鑄', '주'),
+  ('鑑', '감'),
+  ('鑒', '감'),
+  ('鑛', '광'),
+  ('鑽', '찬'),
+  ('鑿', '착'),
+  ('長', '장'),
+  ('門', '문'),
+  ('閃', '섬'),
+  ('閉', '폐'),
+  ('開', '개'),
+  ('閏', '윤'),
+  ('閑', '한'),
+  ('閒', '한'),
+  ('間', '간'),
+  ('閔', '민'),
+  ('閘', '갑'),
+  ('関', '관'),
+  ('閣', '각'),
+  ('閤', '합'),
+  ('閥', '벌'),
+  ('閨', '규'),
+  ('閭', '려'),
+  ('閱', '열'),
+  ('閻', '염'),
+  ('閼', '알'),
+  ('闇', '암'),
+  ('闊', '활'),
+  ('闔', '합'),
+  ('闕', '궐'),
+  ('闖', '틈'),
+  ('闘', '투'),
+  ('關', '관'),
+  ('闡', '천'),
+  ('闢', '벽'),
+  ('阜', '부'),
+  ('阡', '천'),
+  ('阪', '판'),
+  ('阮', '완'),
+  ('防', '방'),
+  ('阻', '조'),
+  ('阿', '아'),
+  ('陀', '타'),
+  ('陂', '피'),
+  ('附', '부'),
+  ('陋', '루'),
+  ('陌', '맥'),
+  ('降', '강'),
+  ('限', '한'),
+  ('陛', '폐'),
+  ('陜', '합'),
+  ('陝', '섬'),
+  ('陞', '승'),
+  ('陟', '척'),
+  ('院', '원'),
+  ('陣', '진'),
+  ('除', '제'),
+  ('陥', '함'),
+  ('陪', '배'),
+  ('陰', '음'),
+  ('陳', '진'),
+  ('陵', '릉'),
+  ('陶', '도'),
+  ('陷', '함'),
+  ('陸', '륙'),
+  ('険', '험'),
+  ('陽', '양'),
+  ('隅', '우'),
+  ('隆', '륭'),
+  ('隊', '대'),
+  ('隋', '수'),
+  ('隍', '황'),
+  ('階', '계'),
+  ('随', '수'),
+  ('隔', '격'),
+  ('隕', '운'),
+  ('隘', '애'),
+  ('隙', '극'),
+  ('際', '제'),
+  ('障', '장'),
+  ('隠', '은'),
+  ('隣', '린'),
+  ('隧', '수'),
+  ('隨', '수'),
+  ('險', '험'),
+  ('隱', '은'),
+  ('隷', '례'),
+  ('隻', '척'),
+  ('雀', '작'),
+  ('雁', '안'),
+  ('雄', '웅'),
+  ('雅', '아'),
+  ('集', '집'),
+  ('雇', '고'),
+  ('雉', '치'),
+  ('雋', '준'),
+  ('雌', '자'),
+  ('雍', '옹'),
+  ('雎', '저'),
+  ('雑', '잡'),
+  ('雕', '조'),
+  ('雖', '수'),
+  ('雙', '쌍'),
+  ('雛', '추'),
+  ('雜', '잡'),
+  ('離', '리'),
+  ('難', '난'),
+  ('雨', '우'),
+  ('雩', '우'),
+  ('雪', '설'),
+  ('雯', '문'),
+  ('雰', '분'),
+  ('雲', '운'),
+  ('零', '령'),
+  ('雷', '뢰'),
+  ('雹', '박'),
+  ('電', '전'),
+  ('需', '수'),
+  ('霆', '정'),
+  ('震', '진'),
+  ('霊', '령'),
+  ('霑', '점'),
+  ('霓', '예'),
+  ('霖', '림'),
+  ('霙', '영'),
+  ('霜', '상'),
+  ('霞', '하'),
+  ('霧', '무'),
+  ('霰', '산'),
+  ('露', '로'),
+  ('霹', '벽'),
+  ('霽', '제'),
+  ('靂', '력'),
+  ('靄', '애'),
+  ('靈', '령'),
+  ('靑', '청'),
+  ('靖', '정'),
+  ('静', '정'),
+  ('靜', '정'),
+  ('非', '비'),
+  ('靡', '미'),
+  ('面', '면'),
+  ('革', '혁'),
+  ('靭', '인'),
+  ('靴', '화'),
+  ('靷', '인'),
+  ('靺', '말'),
+  ('鞋', '혜'),
+  ('鞍', '안'),
+  ('鞏', '공'),
+  ('鞠', '국'),
+  ('鞨', '갈'),
+  ('鞫', '국'),
+  ('鞭', '편'),
+  ('韆', '천'),
+  ('韋', '위'),
+  ('韓', '한'),
+  ('韜', '도'),
+  ('音', '음'),
+  ('韶', '소'),
+  ('韻', '운'),
+  ('響', '향'),
+  ('頀', '호'),
+  ('頁', '혈'),
+  ('頂', '정'),
+  ('頃', '경'),
+  ('項', '항'),
+  ('順', '순'),
+  ('須', '수'),
+  ('頊', '욱'),
+  ('頌', '송'),
+  ('預', '예'),
+  ('頑', '완'),
+  ('頒', '반'),
+  ('頓', '돈'),
+  ('頗', '파'),
+  ('領', '령'),
+  ('頭', '두'),
+  ('頰', '협'),
+  ('頸', '경'),
+  ('頹', '퇴'),
+  ('頻', '빈'),
+  ('頼', '뢰'),
+  ('顆', '과'),
+  ('題', '제'),
+  ('額', '액'),
+  ('顎', '악'),
+  ('顔', '안'),
+  ('顕', '현'),
+  ('願', '원'),
+  ('顚', '전'),
+  ('類', '류'),
+  ('顥', '호'),
+  ('顧', '고'),
+  ('顫', '전'),
+  ('顯', '현'),
+  ('風', '풍'),
+  ('颯', '삽'),
+  ('颱', '태'),
+  ('飄', '표'),
+  ('飇', '표'),
+  ('飛', '비'),
+  ('飜', '번'),
+  ('食', '식'),
+  ('飡', '손'),
+  ('飢', '기'),
+  ('飭', '칙'),
+  ('飮', '음'),
+  ('飯', '반'),
+  ('飴', '이'),
+  ('飼', '사'),
+  ('飽', '포'),
+  ('飾', '식'),
+  ('餃', '교'),
+  ('餉', '향'),
+  ('養', '양'),
+  ('餌', '이'),
+  ('餐', '찬'),
+  ('餓', '아'),
+  ('餘', '여'),
+  ('餞', '전'),
+  ('餠', '병'),
+  ('館', '관'),
+  ('饅', '만'),
+  ('饉', '근'),
+  ('饋', '궤'),
+  ('饌', '찬'),
+  ('饍', '선'),
+  ('饑', '기'),
+  ('饒', '요'),
+  ('饔', '옹'),
+  ('饗', '향'),
+  ('首', '수'),
+  ('香', '향'),
+  ('馝', '필'),
+  ('馥', '복'),
+  ('馨', '형'),
+  ('馬', '마'),
+  ('馭', '어'),
+  ('馮', '풍'),
+  ('馱', '타'),
+  ('馳', '치'),
+  ('馴', '순'),
+  ('馹', '일'),
+  ('駁', '박'),
+  ('駅', '역'),
+  ('駆', '구'),
+  ('駐', '주'),
+  ('駑', '노'),
+  ('駒', '구'),
+  ('駕', '가'),
+  ('駙', '부'),
+  ('駝', '타'),
+  ('駟', '사'),
+  ('駭', '해'),
+  ('駱', '락'),
+  ('駿', '준'),
+  ('騁', '빙'),
+  ('騈', '병'),
+  ('騎', '기'),
+  ('騏', '기'),
+  ('騒', '소'),
+  ('験', '험'),
+  ('騙', '편'),
+  ('騫', '건'),
+  ('騰', '등'),
+  ('騶', '추'),
+  ('騷', '소'),
+  ('驀', '맥'),
+  ('驃', '표'),
+  ('驅', '구'),
+  ('驍', '효'),
+  ('驕', '교'),
+  ('驗', '험'),
+  ('驚', '경'),
+  ('驛', '역'),
+  ('驟', '취'),
+  ('驢', '려'),
+  ('驥', '기'),
+  ('驩', '환'),
+  ('驪', '려'),
+  ('骨', '골'),
+  ('骸', '해'),
+  ('髄', '수'),
+  ('髓', '수'),
+  ('體', '체'),
+  ('高', '고'),
+  ('髥', '염'),
+  ('髪', '발'),
+  ('髮', '발'),
+  ('鬚', '수'),
+  ('鬧', '료'),
+  ('鬪', '투'),
+  ('鬱', '울'),
+  ('鬼', '귀'),
+  ('魁', '괴'),
+  ('魂', '혼'),
+  ('魃', '발'),
+  ('魄', '백'),
+  ('魅', '매'),
+  ('魏', '위'),
+  ('魔', '마'),
+  ('魚', '어'),
+  ('魯', '로'),
+  ('鮎', '점'),
+  ('鮑', '포'),
+  ('鮟', '안'),
+  ('鮫', '교'),
+  ('鮮', '선'),
+  ('鯉', '리'),
+  ('鯖', '청'),
+  ('鯤', '곤'),
+  ('鯨', '경'),
+  ('鰍', '추'),
+  ('鰐', '악'),
+  ('鰒', '복'),
+  ('鰕', '하'),
+  ('鰥', '환'),
+  ('鰲', '오'),
+  ('鰻', '만'),
+  ('鱇', '강'),
+  ('鱉', '별'),
+  ('鱗', '린'),
+  ('鳥', '조'),
+  ('鳧', '부'),
+  ('鳩', '구'),
+  ('鳳', '봉'),
+  ('鳴', '명'),
+  ('鳶', '연'),
+  ('鴉', '아'),
+  ('鴛', '원'),
+  ('鴦', '앙'),
+  ('鴨', '압'),
+  ('鴻', '홍'),
+  ('鵑', '견'),
+  ('鵝', '아'),
+  ('鵠', '곡'),
+  ('鵡', '무'),
+  ('鵬', '붕'),
+  ('鵲', '작'),
+  ('鶏', '계'),
+  ('鶩', '목'),
+  ('鶯', '앵'),
+  ('鶴', '학'),
+  ('鷄', '계'),
+  ('鷗', '구'),
+  ('鷲', '취'),
+  ('鷸', '휼'),
+  ('鷹', '응'),
+  ('鷺', '로'),
+  ('鸚', '앵'),
+  ('鸞', '란'),
+  ('鹵', '로'),
+  ('鹹', '함'),
+  ('鹽', '염'),
+  ('鹿', '록'),
+  ('麒', '기'),
+  ('麓', '록'),
+  ('麗', '려'),
+  ('麝', '사'),
+  ('麟', '린'),
+  ('麥', '맥'),
+  ('麦', '맥'),
+  ('麴', '국'),
+  ('麵', '면'),
+  ('麻', '마'),
+  ('麾', '휘'),
+  ('黃', '황'),
+  ('黄', '황'),
+  ('黍', '서'),
+  ('黎', '려'),
+  ('黑', '흑'),
+  ('黒', '흑'),
+  ('黔', '검'),
+  ('默', '묵'),
+  ('黛', '대'),
+  ('黜', '출'),
+  ('點', '점'),
+  ('黨', '당'),
+  ('黴', '미'),
+  ('鼇', '오'),
+  ('鼈', '별'),
+  ('鼎', '정'),
+  ('鼓', '고'),
+  ('鼠', '서'),
+  ('鼻', '비'),
+  ('齊', '제'),
+  ('齋', '재'),
+  ('齎', '재'),
+  ('齒', '치'),
+  ('齟', '저'),
+  ('齡', '령'),
+  ('齢', '령'),
+  ('齧', '설'),
+  ('齪', '착'),
+  ('齬', '어'),
+  ('齷', '악'),
+  ('龍', '룡'),
+  ('龐', '방'),
+  ('龕', '감'),
+  ('龜', '구'),
+  ('\u{F900}', '개'),
+  ('\u{F901}', '갱'),
+  ('\u{F902}', '거'),
+  ('\u{F903}', '고'),
+  ('\u{F904}', '골'),
+  ('\u{F905}', '관'),
+  ('\u{F906}', '귀'),
+  ('\u{F907}', '귀'),
+  ('\u{F908}', '균'),
+  ('\u{F909}', '글'),
+  ('\u{F90A}', '금'),
+  ('\u{F90B}', '라'),
+  ('\u{F90C}', '나'),
+  ('\u{F90D}', '라'),
+  ('\u{F90E}', '라'),
+  ('\u{F90F}', '라'),
+  ('\u{F910}', '라'),
+  ('\u{F911}', '라'),
+  ('\u{F912}', '라'),
+  ('\u{F913}', '라'),
+  ('\u{F914}', '락'),
+  ('\u{F915}', '락'),
+  ('\u{F916}', '락'),
+  ('\u{F917}', '락'),
+  ('\u{F918}', '락'),
+  ('\u{F919}', '락'),
+  ('\u{F91A}', '락'),
+  ('\u{F91B}', '란'),
+  ('\u{F91C}', '란'),
+  ('\u{F91D}', '란'),
+  ('\u{F91E}', '란'),
+  ('\u{F91F}', '란'),
+  ('\u{F920}', '란'),
+  ('\u{F921}', '람'),
+  ('\u{F922}', '람'),
+  ('\u{F923}', '람'),
+  ('\u{F924}', '람'),
+  ('\u{F925}', '랍'),
+  ('\u{F926}', '랍'),
+  ('\u{F927}', '랍'),
+  ('\u{F928}', '랑'),
+  ('\u{F929}', '랑'),
+  ('\u{F92A}', '랑'),
+  ('\u{F92B}', '랑'),
+  ('\u{F92C}', '낭'),
+  ('\u{F92D}', '래'),
+  ('\u{F92E}', '랭'),
+  ('\u{F92F}', '로'),
+  ('\u{F930}', '로'),
+  ('\u{F931}', '로'),
+  ('\u{F932}', '로'),
+  ('\u{F933}', '로'),
+  ('\u{F934}', '로'),
+  ('\u{F935}', '로'),
+  ('\u{F936}', '로'),
+  ('\u{F937}', '로'),
+  ('\u{F938}', '로'),
+  ('\u{F939}', '로'),
+  ('\u{F93A}', '로'),
+  ('\u{F93B}', '록'),
+  ('\u{F93C}', '록'),
+  ('\u{F93D}', '록'),
+  ('\u{F93E}', '록'),
+  ('\u{F93F}', '록'),
+  ('\u{F940}', '록'),
+  ('\u{F941}', '론'),
+  ('\u{F942}', '롱'),
+  ('\u{F943}', '롱'),
+  ('\u{F944}', '롱'),
+  ('\u{F945}', '롱'),
+  ('\u{F946}', '뢰'),
+  ('\u{F947}', '뢰'),
+  ('\u{F948}', '뢰'),
+  ('\u{F949}', '뢰'),
+  ('\u{F94A}', '루'),
+  ('\u{F94B}', '루'),
+  ('\u{F94C}', '루'),
+  ('\u{F94D}', '루'),
+  ('\u{F94E}', '루'),
+  ('\u{F94F}', '루'),
+  ('\u{F950}', '루'),
+  ('\u{F951}', '루'),
+  ('\u{F952}', '륵'),
+  ('\u{F953}', '륵'),
+  ('\u{F954}', '름'),
+  ('\u{F955}', '릉'),
+  ('\u{F956}', '릉'),
+  ('\u{F957}', '릉'),
+  ('\u{F958}', '릉'),
+  ('\u{F959}', '릉'),
+  ('\u{F95A}', '두'),
+  ('\u{F95B}', '라'),
+  ('\u{F95C}', '락'),
+  ('\u{F95D}', '락'),
+  ('\u{F95E}', '란'),
+  ('\u{F95F}', '령'),
+  ('\u{F960}', '로'),
+  ('\u{F961}', '률'),
+  ('\u{F962}', '리'),
+  ('\u{F963}', '배'),
+  ('\u{F964}', '번'),
+  ('\u{F965}', '변'),
+  ('\u{F966}', '부'),
+  ('\u{F967}', '불'),
+  ('\u{F968}', '비'),
+  ('\u{F969}', '삭'),
+  ('\u{F96A}', '삭'),
+  ('\u{F96B}', '삼'),
+  ('\u{F96C}', '색'),
+  ('\u{F96D}', '생'),
+  ('\u{F96E}', '섭'),
+  ('\u{F96F}', '세'),
+  ('\u{F970}', '쇄'),
+  ('\u{F971}', '신'),
+  ('\u{F972}', '심'),
+  ('\u{F973}', '십'),
+  ('\u{F974}', '야'),
+  ('\u{F975}', '략'),
+  ('\u{F976}', '략'),
+  ('\u{F977}', '량'),
+  ('\u{F978}', '량'),
+  ('\u{F979}', '량'),
+  ('\u{F97A}', '량'),
+  ('\u{F97B}', '량'),
+  ('\u{F97C}', '량'),
+  ('\u{F97D}', '량'),
+  ('\u{F97E}', '량'),
+  ('\u{F97F}', '려'),
+  ('\u{F980}', '려'),
+  ('\u{F981}', '녀'),
+  ('\u{F982}', '려'),
+  ('\u{F983}', '려'),
+  ('\u{F984}', '려'),
+  ('\u{F985}', '려'),
+  ('\u{F986}', '려'),
+  ('\u{F987}', '려'),
+  ('\u{F988}', '려'),
+  ('\u{F989}', '려'),
+  ('\u{F98A}', '력'),
+  ('\u{F98B}', '력'),
+  ('\u{F98C}', '력'),
+  ('\u{F98D}', '력'),
+  ('\u{F98E}', '년'),
+  ('\u{F98F}', '련'),
+  ('\u{F990}', '련'),
+  ('\u{F991}', '년'),
+  ('\u{F992}', '련'),
+  ('\u{F993}', '련'),
+  ('\u{F994}', '련'),
+  ('\u{F995}', '년'),
+  ('\u{F996}', '련'),
+  ('\u{F997}', '련'),
+  ('\u{F998}', '련'),
+  ('\u{F999}', '련'),
+  ('\u{F99A}', '련'),
+  ('\u{F99B}', '련'),
+  ('\u{F99C}', '렬'),
+  ('\u{F99D}', '렬'),
+  ('\u{F99E}', '열'),
+  ('\u{F99F}', '렬'),
+  ('\u{F9A0}', '렬'),
+  ('\u{F9A1}', '열'),
+  ('\u{F9A2}', '렴'),
+  ('\u{F9A3}', '념'),
+  ('\u{F9A4}', '념'),
+  ('\u{F9A5}', '렴'),
+  ('\u{F9A6}', '렴'),
+  ('\u{F9A7}', '렵'),
+  ('\u{F9A8}', '령'),
+  ('\u{F9A9}', '령'),
+  ('\u{F9AA}', '녕'),
+  ('\u{F9AB}', '령'),
+  ('\u{F9AC}', '령'),
+  ('\u{F9AD}', '령'),
+  ('\u{F9AE}', '영'),
+  ('\u{F9AF}', '령'),
+  ('\u{F9B0}', '령'),
+  ('\u{F9B1}', '령'),
+  ('\u{F9B2}', '령'),
+  ('\u{F9B3}', '령'),
+  ('\u{F9B4}', '령'),
+  ('\u{F9B5}', '례'),
+  ('\u{F9B6}', '례'),
+  ('\u{F9B7}', '례'),
+  ('\u{F9B8}', '예'),
+  ('\u{F9B9}', '오'),
+  ('\u{F9BA}', '료'),
+  ('\u{F9BB}', '료'),
+  ('\u{F9BC}', '료'),
+  ('\u{F9BD}', '뇨'),
+  ('\u{F9BE}', '료'),
+  ('\u{F9BF}', '요'),
+  ('\u{F9C0}', '료'),
+  ('\u{F9C1}', '료'),
+  ('\u{F9C2}', '료'),
+  ('\u{F9C3}', '료'),
+  ('\u{F9C4}', '룡'),
+  ('\u{F9C5}', '운'),
+  ('\u{F9C6}', '원'),
+  ('\u{F9C7}', '류'),
+  ('\u{F9C8}', '뉴'),
+  ('\u{F9C9}', '류'),
+  ('\u{F9CA}', '류'),
+  ('\u{F9CB}', '류'),
+  ('\u{F9CC}', '류'),
+  ('\u{F9CD}', '류'),
+  ('\u{F9CE}', '류'),
+  ('\u{F9CF}', '뉴'),
+  ('\u{F9D0}', '류'),
+  ('\u{F9D1}', '륙'),
+  ('\u{F9D2}', '륙'),
+  ('\u{F9D3}', '륙'),
+  ('\u{F9D4}', '륜'),
+  ('\u{F9D5}', '륜'),
+  ('\u{F9D6}', '륜'),
+  ('\u{F9D7}', '륜'),
+  ('\u{F9D8}', '률'),
+  ('\u{F9D9}', '률'),
+  ('\u{F9DA}', '률'),
+  ('\u{F9DB}', '률'),
+  ('\u{F9DC}', '륭'),
+  ('\u{F9DD}', '리'),
+  ('\u{F9DE}', '리'),
+  ('\u{F9DF}', '리'),
+  ('\u{F9E0}', '이'),
+  ('\u{F9E1}', '리'),
+  ('\u{F9E2}', '리'),
+  ('\u{F9E3}', '니'),
+  ('\u{F9E4}', '리'),
+  ('\u{F9E5}', '리'),
+  ('\u{F9E6}', '리'),
+  ('\u{F9E7}', '리'),
+  ('\u{F9E8}', '리'),
+  ('\u{F9E9}', '리'),
+  ('\u{F9EA}', '리'),
+  ('\u{F9EB}', '닉'),
+  ('\u{F9EC}', '닉'),
+  ('\u{F9ED}', '린'),
+  ('\u{F9EE}', '린'),
+  ('\u{F9EF}', '린'),
+  ('\u{F9F0}', '린'),
+  ('\u{F9F1}', '린'),
+  ('\u{F9F2}', '린'),
+  ('\u{F9F3}', '린'),
+  ('\u{F9F4}', '림'),
+  ('\u{F9F5}', '림'),
+  ('\u{F9F6}', '림'),
+  ('\u{F9F7}', '립'),
+  ('\u{F9F8}', '립'),
+  ('\u{F9F9}', '립'),
+  ('\u{F9FA}', '장'),
+  ('\u{F9FB}', '적'),
+  ('\u{F9FC}', '지'),
+  ('\u{F9FD}', '집'),
+  ('\u{F9FE}', '차'),
+  ('\u{F9FF}', '척'),
+  ('\u{FA00}', '체'),
+  ('\u{FA01}', '탁'),
+  ('\u{FA02}', '탁'),
+  ('\u{FA03}', '탕'),
+  ('\u{FA04}', '택'),
+  ('\u{FA05}', '통'),
+  ('\u{FA06}', '포'),
+  ('\u{FA07}', '폭'),
+  ('\u{FA08}', '항'),
+  ('\u{FA09}', '항'),
+  ('\u{FA0A}', '현'),
+  ('\u{FA0B}', '확'),
+];
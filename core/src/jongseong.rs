@@ -1,3 +1,12 @@
+use core::num::NonZeroU8;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::choseong::Choseong;
+use crate::halfwidth::halfwidth_to_compatibility;
+use crate::hangul_letter::JamoError;
+
 // 조합형 종성 범위
 const JONGSEONG_BASE: u32 = 0x11A8;
 const JONGSEONG_LAST: u32 = 0x11C2;
@@ -18,84 +27,162 @@ const COMPLEX_JONGSEONG_MAPPING: [u32; 11] = [
   0x3133, 0x3135, 0x3136, 0x313A, 0x313B, 0x313C, 0x313D, 0x313E, 0x313F, 0x3140, 0x3144,
 ];
 
-#[derive(Debug)]
-pub struct Jongseong {
-  pub conjoining_value: char,
-  pub conjoining_unicode: u32,
-  pub compatibility_value: char,
-  pub compatibility_unicode: u32,
-}
+// 겹받침 구성 테이블 (겹받침, 앞 자음, 뒤 자음)
+const COMPLEX_JONGSEONG_COMPONENTS: [(u32, u32, u32); 11] = [
+  (0x3133, 0x3131, 0x3145),
+  (0x3135, 0x3134, 0x3148),
+  (0x3136, 0x3134, 0x314E),
+  (0x313A, 0x3139, 0x3131),
+  (0x313B, 0x3139, 0x3141),
+  (0x313C, 0x3139, 0x3142),
+  (0x313D, 0x3139, 0x3145),
+  (0x313E, 0x3139, 0x314C),
+  (0x313F, 0x3139, 0x314D),
+  (0x3140, 0x3139, 0x314E),
+  (0x3144, 0x3142, 0x3145),
+];
+
+// 종성 인덱스는 유니코드 음절 산식과 같이 1–27을 사용하고, 0(종성 없음)은 `Option::None`으로 표현
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Jongseong(NonZeroU8);
 
 impl Jongseong {
   #[inline]
   pub fn new(unicode: u32) -> Self {
+    Self::try_new(unicode).unwrap_or_else(|| panic!("유효한 종성 유니코드가 아닙니다: {}", unicode))
+  }
+
+  #[inline]
+  pub(crate) fn try_new(unicode: u32) -> Option<Self> {
     // 조합형 종성 범위 확인
-    if JONGSEONG_BASE <= unicode && unicode <= JONGSEONG_LAST {
-      let offset = unicode - JONGSEONG_BASE;
-      let compatibility_jamo = COMPATIBILITY_JONGSEONG_MAPPING[offset as usize];
-
-      return Self {
-        conjoining_value: unsafe { std::char::from_u32_unchecked(unicode) },
-        conjoining_unicode: unicode,
-        compatibility_value: unsafe { std::char::from_u32_unchecked(compatibility_jamo) },
-        compatibility_unicode: compatibility_jamo,
-      };
+    if Self::is_conjoining_jongseong(unicode) {
+      return Some(Self::from_index_unchecked(
+        (unicode - JONGSEONG_BASE) as u8 + 1,
+      ));
     }
 
     // 호환형 종성 범위 확인
-    if COMPAT_JONGSEONG_BASE <= unicode && unicode <= COMPAT_JONGSEONG_LAST {
-      if let Some(position) = COMPATIBILITY_JONGSEONG_MAPPING
+    if Self::is_compatibility_jongseong(unicode) {
+      return COMPATIBILITY_JONGSEONG_MAPPING
         .iter()
         .position(|&x| x == unicode)
-      {
-        let conjoining_jamo = JONGSEONG_BASE + position as u32;
-
-        return Self {
-          conjoining_value: unsafe { std::char::from_u32_unchecked(conjoining_jamo) },
-          conjoining_unicode: conjoining_jamo,
-          compatibility_value: unsafe { std::char::from_u32_unchecked(unicode) },
-          compatibility_unicode: unicode,
-        };
-      }
+        .map(|position| Self::from_index_unchecked(position as u8 + 1));
     }
 
-    panic!("유효한 종성 유니코드가 아닙니다: {}", unicode)
+    None
+  }
+
+  // 조합형, 호환형, 반각 자모와 자음과 모양이 비슷한 문자(o → ㅇ)로 생성
+  pub fn from_confusable(ch: char) -> Option<Self> {
+    let ch = halfwidth_to_compatibility(ch).unwrap_or(ch);
+
+    Self::try_new(ch as u32).or_else(|| {
+      Choseong::from_confusable(ch)
+        .and_then(|choseong| Self::try_new(choseong.compatibility_unicode()))
+    })
+  }
+
+  // 종성 인덱스(1–27)로 생성
+  #[inline]
+  pub(crate) fn from_index(index: u8) -> Option<Self> {
+    (1..=COMPATIBILITY_JONGSEONG_MAPPING.len() as u8)
+      .contains(&index)
+      .then(|| Self::from_index_unchecked(index))
+  }
+
+  // 종성 인덱스(1–27)로 생성, 범위는 호출자가 보장
+  #[inline]
+  const fn from_index_unchecked(index: u8) -> Self {
+    Self(unsafe { NonZeroU8::new_unchecked(index) })
+  }
+
+  // 종성 인덱스(1–27)
+  #[inline]
+  pub fn index(&self) -> u8 {
+    self.0.get()
+  }
+
+  #[inline]
+  pub fn conjoining_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.conjoining_unicode()) }
+  }
+
+  #[inline]
+  pub fn conjoining_unicode(&self) -> u32 {
+    JONGSEONG_BASE + self.index() as u32 - 1
+  }
+
+  #[inline]
+  pub fn compatibility_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.compatibility_unicode()) }
+  }
+
+  #[inline]
+  pub fn compatibility_unicode(&self) -> u32 {
+    COMPATIBILITY_JONGSEONG_MAPPING[self.index() as usize - 1]
   }
 
   #[inline]
   pub fn is_conjoining_jongseong(jongseong_code: u32) -> bool {
-    JONGSEONG_BASE <= jongseong_code && jongseong_code <= JONGSEONG_LAST
+    (JONGSEONG_BASE..=JONGSEONG_LAST).contains(&jongseong_code)
   }
 
+  // 호환형 종성 확인, 종성이 될 수 없는 자음(ㄸ, ㅃ, ㅉ)은 제외
   #[inline]
   pub fn is_compatibility_jongseong(unicode: u32) -> bool {
-    COMPAT_JONGSEONG_BASE <= unicode && unicode <= COMPAT_JONGSEONG_LAST
+    (COMPAT_JONGSEONG_BASE..=COMPAT_JONGSEONG_LAST).contains(&unicode)
+      && COMPATIBILITY_JONGSEONG_MAPPING.contains(&unicode)
   }
 
   #[inline]
   pub fn is_complex_jongseong(&self) -> bool {
-    COMPLEX_JONGSEONG_MAPPING.contains(&self.compatibility_unicode)
+    COMPLEX_JONGSEONG_MAPPING.contains(&self.compatibility_unicode())
   }
 
+  #[cfg(feature = "alloc")]
   #[inline]
   pub fn decompose_complex_jongseong(&self) -> Vec<char> {
-    match self.compatibility_unicode {
-      0x3133 => vec!['ㄱ', 'ㅅ'],
-      0x3135 => vec!['ㄴ', 'ㅈ'],
-      0x3136 => vec!['ㄴ', 'ㅎ'],
-      0x313A => vec!['ㄹ', 'ㄱ'],
-      0x313B => vec!['ㄹ', 'ㅁ'],
-      0x313C => vec!['ㄹ', 'ㅂ'],
-      0x313D => vec!['ㄹ', 'ㅅ'],
-      0x313E => vec!['ㄹ', 'ㅌ'],
-      0x313F => vec!['ㄹ', 'ㅍ'],
-      0x3140 => vec!['ㄹ', 'ㅎ'],
-      0x3144 => vec!['ㅂ', 'ㅅ'],
-      _ => vec![self.compatibility_value],
+    match self.split() {
+      (first, Some(second)) => vec![first.compatibility_value(), second.compatibility_value()],
+      (_, None) => vec![self.compatibility_value()],
+    }
+  }
+
+  // 두 종성을 겹받침으로 결합 (ㄹ + ㄱ → ㄺ)
+  #[inline]
+  pub fn combine(&self, next: &Jongseong) -> Option<Jongseong> {
+    let (first, second) = (self.compatibility_unicode(), next.compatibility_unicode());
+
+    COMPLEX_JONGSEONG_COMPONENTS
+      .iter()
+      .find(|&&(_, a, b)| a == first && b == second)
+      .map(|&(complex, _, _)| Jongseong::new(complex))
+  }
+
+  // 겹받침을 앞뒤 종성으로 분리 (ㄺ → ㄹ, ㄱ), 겹받침이 아니면 (자기 자신, None)
+  #[inline]
+  pub fn split(&self) -> (Jongseong, Option<Jongseong>) {
+    let unicode = self.compatibility_unicode();
+
+    match COMPLEX_JONGSEONG_COMPONENTS
+      .iter()
+      .find(|&&(complex, _, _)| complex == unicode)
+    {
+      Some(&(_, first, second)) => (Jongseong::new(first), Some(Jongseong::new(second))),
+      None => (*self, None),
     }
   }
 }
 
+// 조합형 또는 호환형 자모로 생성, 종성이 될 수 없으면 (ㄸ, ㅃ, ㅉ) 오류
+impl TryFrom<char> for Jongseong {
+  type Error = JamoError;
+
+  fn try_from(ch: char) -> Result<Self, Self::Error> {
+    Self::try_new(ch as u32).ok_or(JamoError::InvalidJongseong(ch))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -120,36 +207,39 @@ mod tests {
     assert!(!Jongseong::is_compatibility_jongseong(0x3130));
     assert!(!Jongseong::is_compatibility_jongseong(0x314F));
     assert!(!Jongseong::is_compatibility_jongseong(0x11A8));
+    assert!(!Jongseong::is_compatibility_jongseong('ㄸ' as u32));
+    assert!(!Jongseong::is_compatibility_jongseong('ㅃ' as u32));
+    assert!(!Jongseong::is_compatibility_jongseong('ㅉ' as u32));
   }
 
   #[test]
   fn test_new_from_conjoining_jamo() {
     let jongseong = Jongseong::new(0x11A8);
-    assert_eq!(jongseong.conjoining_unicode, 0x11A8);
-    assert_eq!(jongseong.conjoining_value, 'ᆨ');
-    assert_eq!(jongseong.compatibility_unicode, 0x3131);
-    assert_eq!(jongseong.compatibility_value, 'ㄱ');
+    assert_eq!(jongseong.conjoining_unicode(), 0x11A8);
+    assert_eq!(jongseong.conjoining_value(), 'ᆨ');
+    assert_eq!(jongseong.compatibility_unicode(), 0x3131);
+    assert_eq!(jongseong.compatibility_value(), 'ㄱ');
 
     let jongseong = Jongseong::new(0x11AC);
-    assert_eq!(jongseong.conjoining_unicode, 0x11AC);
-    assert_eq!(jongseong.conjoining_value, 'ᆬ');
-    assert_eq!(jongseong.compatibility_unicode, 0x3135);
-    assert_eq!(jongseong.compatibility_value, 'ㄵ');
+    assert_eq!(jongseong.conjoining_unicode(), 0x11AC);
+    assert_eq!(jongseong.conjoining_value(), 'ᆬ');
+    assert_eq!(jongseong.compatibility_unicode(), 0x3135);
+    assert_eq!(jongseong.compatibility_value(), 'ㄵ');
   }
 
   #[test]
   fn test_new_from_compatibility_jamo() {
     let jongseong = Jongseong::new(0x3131);
-    assert_eq!(jongseong.conjoining_unicode, 0x11A8);
-    assert_eq!(jongseong.conjoining_value, 'ᆨ');
-    assert_eq!(jongseong.compatibility_unicode, 0x3131);
-    assert_eq!(jongseong.compatibility_value, 'ㄱ');
+    assert_eq!(jongseong.conjoining_unicode(), 0x11A8);
+    assert_eq!(jongseong.conjoining_value(), 'ᆨ');
+    assert_eq!(jongseong.compatibility_unicode(), 0x3131);
+    assert_eq!(jongseong.compatibility_value(), 'ㄱ');
 
     let jongseong = Jongseong::new(0x3135);
-    assert_eq!(jongseong.conjoining_unicode, 0x11AC);
-    assert_eq!(jongseong.conjoining_value, 'ᆬ');
-    assert_eq!(jongseong.compatibility_unicode, 0x3135);
-    assert_eq!(jongseong.compatibility_value, 'ㄵ');
+    assert_eq!(jongseong.conjoining_unicode(), 0x11AC);
+    assert_eq!(jongseong.conjoining_value(), 'ᆬ');
+    assert_eq!(jongseong.compatibility_unicode(), 0x3135);
+    assert_eq!(jongseong.compatibility_value(), 'ㄵ');
   }
 
   #[test]
@@ -172,6 +262,7 @@ mod tests {
   }
 
   #[test]
+  #[cfg(feature = "alloc")]
   fn test_decompose_complex_jongseong() {
     assert_eq!(
       Jongseong::new(0x3133).decompose_complex_jongseong(),
@@ -232,9 +323,50 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_combine_and_split() {
+    let rieul = Jongseong::new('ㄹ' as u32);
+    let giyeok = Jongseong::new('ㄱ' as u32);
+
+    assert_eq!(rieul.combine(&giyeok), Some(Jongseong::new('ㄺ' as u32)));
+    assert_eq!(giyeok.combine(&rieul), None);
+
+    assert_eq!(Jongseong::new('ㄺ' as u32).split(), (rieul, Some(giyeok)));
+    assert_eq!(
+      Jongseong::new('ㅄ' as u32).split().0,
+      Jongseong::new('ㅂ' as u32)
+    );
+    assert_eq!(rieul.split(), (rieul, None));
+  }
+
   #[test]
   #[should_panic(expected = "유효한 종성 유니코드가 아닙니다")]
   fn test_invalid_unicode() {
     Jongseong::new(0x1100);
   }
+
+  #[test]
+  fn test_try_from_char() {
+    assert_eq!(Jongseong::try_from('ㄺ'), Ok(Jongseong::new(0x11B0)));
+    assert_eq!(Jongseong::try_from('ᆨ'), Ok(Jongseong::new('ㄱ' as u32)));
+    for ch in ['ㄸ', 'ㅃ', 'ㅉ', 'ᄀ', 'ㅏ'] {
+      assert_eq!(
+        Jongseong::try_from(ch),
+        Err(JamoError::InvalidJongseong(ch))
+      );
+    }
+    assert_eq!(Jongseong::from_index(27), Some(Jongseong::new('ㅎ' as u32)));
+    assert_eq!(Jongseong::from_index(0), None);
+    assert_eq!(Jongseong::from_index(28), None);
+  }
+
+  #[test]
+  fn test_from_confusable() {
+    assert_eq!(
+      Jongseong::from_confusable('\u{11AA}'),
+      Jongseong::try_new(0x3133)
+    );
+    assert_eq!(Jongseong::from_confusable('o'), Jongseong::try_new(0x3147));
+    assert_eq!(Jongseong::from_confusable('ㄸ'), None);
+  }
 }
@@ -1,3 +1,5 @@
+use crate::halfwidth::halfwidth_to_compatibility;
+use crate::hangul_letter::JamoError;
 use crate::utils::is_compatibility_jamo;
 
 // 조합형 중성 범위
@@ -14,60 +16,146 @@ const COMPATIBILITY_JUNGSEONG_MAPPING: [u32; 21] = [
   0x315B, 0x315C, 0x315D, 0x315E, 0x315F, 0x3160, 0x3161, 0x3162, 0x3163,
 ];
 
-#[derive(Debug)]
-pub struct Jungseong {
-  pub conjoining_value: char,
-  pub conjoining_unicode: u32,
-  pub compatibility_value: char,
-  pub compatibility_unicode: u32,
-}
+// 이중 모음 구성 테이블 (이중 모음, 앞 모음, 뒤 모음)
+const COMPLEX_JUNGSEONG_COMPONENTS: [(u32, u32, u32); 7] = [
+  (0x3158, 0x3157, 0x314F),
+  (0x3159, 0x3157, 0x3150),
+  (0x315A, 0x3157, 0x3163),
+  (0x315D, 0x315C, 0x3153),
+  (0x315E, 0x315C, 0x3154),
+  (0x315F, 0x315C, 0x3163),
+  (0x3162, 0x3161, 0x3163),
+];
+
+// 모음과 모양이 비슷한 문자와 그 호환형 모음, UTS #39 confusables.txt가 아닌 직접 고른 목록
+// 라틴 문자는 대소문자를 접어 찾으므로 소문자로 적음
+const CONFUSABLE_JUNGSEONG: [(char, char); 13] = [
+  ('一', 'ㅡ'),
+  ('ー', 'ㅡ'),
+  ('―', 'ㅡ'),
+  ('—', 'ㅡ'),
+  ('─', 'ㅡ'),
+  ('l', 'ㅣ'),
+  ('1', 'ㅣ'),
+  ('|', 'ㅣ'),
+  ('丨', 'ㅣ'),
+  ('ト', 'ㅏ'),
+  ('卜', 'ㅏ'),
+  ('⊥', 'ㅗ'),
+  ('丁', 'ㅜ'),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Jungseong(u8);
 
 impl Jungseong {
   #[inline]
   pub fn new(unicode: u32) -> Self {
+    Self::try_new(unicode).unwrap_or_else(|| panic!("유효한 중성 유니코드가 아닙니다: {}", unicode))
+  }
+
+  #[inline]
+  pub(crate) fn try_new(unicode: u32) -> Option<Self> {
     // 조합형 중성 범위 확인
     if Self::is_conjoining_jungseong(unicode) {
-      let conjoining_jamo = unicode;
-      let compatibility_jamo = COMPATIBILITY_JUNGSEONG_MAPPING[(unicode - JUNGSEONG_BASE) as usize];
-
-      return Self {
-        conjoining_value: unsafe { std::char::from_u32_unchecked(conjoining_jamo) },
-        conjoining_unicode: conjoining_jamo,
-        compatibility_value: unsafe { std::char::from_u32_unchecked(compatibility_jamo) },
-        compatibility_unicode: compatibility_jamo,
-      };
+      return Some(Self((unicode - JUNGSEONG_BASE) as u8));
     }
 
     // 호환형 중성 범위 확인
     if Self::is_compatibility_jungseong(unicode) {
-      if let Some(position) = COMPATIBILITY_JUNGSEONG_MAPPING
+      return COMPATIBILITY_JUNGSEONG_MAPPING
         .iter()
         .position(|&x| x == unicode)
-      {
-        let conjoining_jamo = JUNGSEONG_BASE + position as u32;
-
-        return Self {
-          conjoining_value: unsafe { std::char::from_u32_unchecked(conjoining_jamo) },
-          conjoining_unicode: conjoining_jamo,
-          compatibility_value: unsafe { std::char::from_u32_unchecked(unicode) },
-          compatibility_unicode: unicode,
-        };
-      }
+        .map(|position| Self(position as u8));
+    }
+
+    None
+  }
+
+  // 조합형, 호환형, 반각 자모와 모양이 비슷한 문자(一 → ㅡ, l, L → ㅣ)로 생성
+  pub fn from_confusable(ch: char) -> Option<Self> {
+    let ch = halfwidth_to_compatibility(ch).unwrap_or(ch.to_ascii_lowercase());
+
+    Self::try_new(ch as u32).or_else(|| {
+      CONFUSABLE_JUNGSEONG
+        .iter()
+        .find(|&&(confusable, _)| confusable == ch)
+        .and_then(|&(_, jamo)| Self::try_new(jamo as u32))
+    })
+  }
+
+  // 중성 인덱스(0–20)로 생성
+  #[inline]
+  pub(crate) fn from_index(index: u8) -> Option<Self> {
+    (index < COMPATIBILITY_JUNGSEONG_MAPPING.len() as u8).then_some(Self(index))
+  }
+
+  // 중성 인덱스(0–20)
+  #[inline]
+  pub fn index(&self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub fn conjoining_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.conjoining_unicode()) }
+  }
+
+  #[inline]
+  pub fn conjoining_unicode(&self) -> u32 {
+    JUNGSEONG_BASE + self.0 as u32
+  }
+
+  #[inline]
+  pub fn compatibility_value(&self) -> char {
+    unsafe { core::char::from_u32_unchecked(self.compatibility_unicode()) }
+  }
+
+  #[inline]
+  pub fn compatibility_unicode(&self) -> u32 {
+    COMPATIBILITY_JUNGSEONG_MAPPING[self.0 as usize]
+  }
+
+  // 두 모음을 이중 모음으로 결합 (ㅗ + ㅏ → ㅘ)
+  #[inline]
+  pub fn combine(&self, next: &Jungseong) -> Option<Jungseong> {
+    let (first, second) = (self.compatibility_unicode(), next.compatibility_unicode());
+
+    COMPLEX_JUNGSEONG_COMPONENTS
+      .iter()
+      .find(|&&(_, a, b)| a == first && b == second)
+      .map(|&(complex, _, _)| Jungseong::new(complex))
+  }
+
+  // 이중 모음을 앞뒤 모음으로 분리 (ㅘ → ㅗ, ㅏ), 이중 모음이 아니면 (자기 자신, None)
+  #[inline]
+  pub fn split(&self) -> (Jungseong, Option<Jungseong>) {
+    let unicode = self.compatibility_unicode();
+
+    match COMPLEX_JUNGSEONG_COMPONENTS
+      .iter()
+      .find(|&&(complex, _, _)| complex == unicode)
+    {
+      Some(&(_, first, second)) => (Jungseong::new(first), Some(Jungseong::new(second))),
+      None => (*self, None),
     }
+  }
 
-    panic!("유효한 중성 유니코드가 아닙니다: {}", unicode)
+  #[inline]
+  pub fn is_complex_jungseong(&self) -> bool {
+    self.split().1.is_some()
   }
 
   // 조합형 중성 확인
   #[inline]
   pub fn is_conjoining_jungseong(jungseong_code: u32) -> bool {
-    JUNGSEONG_BASE <= jungseong_code && jungseong_code <= JUNGSEONG_LAST
+    (JUNGSEONG_BASE..=JUNGSEONG_LAST).contains(&jungseong_code)
   }
 
   // 호환형 중성 확인
   #[inline]
   pub fn is_compatibility_jungseong(unicode: u32) -> bool {
-    COMPAT_JUNGSEONG_BASE <= unicode && unicode <= COMPAT_JUNGSEONG_LAST
+    (COMPAT_JUNGSEONG_BASE..=COMPAT_JUNGSEONG_LAST).contains(&unicode)
   }
 
   #[inline]
@@ -95,6 +183,15 @@ impl Jungseong {
   }
 }
 
+// 조합형 또는 호환형 자모로 생성, 모음이 아니면 오류
+impl TryFrom<char> for Jungseong {
+  type Error = JamoError;
+
+  fn try_from(ch: char) -> Result<Self, Self::Error> {
+    Self::try_new(ch as u32).ok_or(JamoError::InvalidJungseong(ch))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -192,16 +289,46 @@ mod tests {
   #[test]
   fn test_jungseong_new() {
     let jungseong = Jungseong::new(0x1161);
-    assert_eq!(jungseong.conjoining_unicode, 0x1161);
-    assert_eq!(jungseong.conjoining_value, 'ᅡ');
-    assert_eq!(jungseong.compatibility_unicode, 0x314F);
-    assert_eq!(jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(jungseong.conjoining_unicode(), 0x1161);
+    assert_eq!(jungseong.conjoining_value(), 'ᅡ');
+    assert_eq!(jungseong.compatibility_unicode(), 0x314F);
+    assert_eq!(jungseong.compatibility_value(), 'ㅏ');
 
     let jungseong = Jungseong::new(0x314F);
-    assert_eq!(jungseong.conjoining_unicode, 0x1161);
-    assert_eq!(jungseong.conjoining_value, 'ᅡ');
-    assert_eq!(jungseong.compatibility_unicode, 0x314F);
-    assert_eq!(jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(jungseong.conjoining_unicode(), 0x1161);
+    assert_eq!(jungseong.conjoining_value(), 'ᅡ');
+    assert_eq!(jungseong.compatibility_unicode(), 0x314F);
+    assert_eq!(jungseong.compatibility_value(), 'ㅏ');
+  }
+
+  #[test]
+  fn test_combine() {
+    let o = Jungseong::new('ㅗ' as u32);
+    let a = Jungseong::new('ㅏ' as u32);
+
+    assert_eq!(o.combine(&a), Some(Jungseong::new('ㅘ' as u32)));
+    assert_eq!(
+      Jungseong::new('ㅡ' as u32).combine(&Jungseong::new('ㅣ' as u32)),
+      Some(Jungseong::new('ㅢ' as u32))
+    );
+    assert_eq!(a.combine(&o), None);
+  }
+
+  #[test]
+  fn test_split() {
+    let wa = Jungseong::new('ㅘ' as u32);
+    assert_eq!(
+      wa.split(),
+      (
+        Jungseong::new('ㅗ' as u32),
+        Some(Jungseong::new('ㅏ' as u32))
+      )
+    );
+    assert!(wa.is_complex_jungseong());
+
+    let a = Jungseong::new(0x1161);
+    assert_eq!(a.split(), (a, None));
+    assert!(!a.is_complex_jungseong());
   }
 
   #[test]
@@ -210,21 +337,47 @@ mod tests {
     Jungseong::new(0x3131);
   }
 
+  #[test]
+  fn test_try_from_char() {
+    assert_eq!(Jungseong::try_from('ㅘ'), Ok(Jungseong::new(0x116A)));
+    assert_eq!(
+      Jungseong::try_from('ㄱ'),
+      Err(JamoError::InvalidJungseong('ㄱ'))
+    );
+    assert_eq!(Jungseong::from_index(20), Some(Jungseong::new('ㅣ' as u32)));
+    assert_eq!(Jungseong::from_index(21), None);
+  }
+
   #[test]
   fn test_jungseong_conversion() {
     let jungseong = Jungseong::new('ᅡ' as u32);
-    assert_eq!(jungseong.conjoining_unicode, 0x1161);
-    assert_eq!(jungseong.conjoining_value, 'ᅡ');
-    assert_eq!(jungseong.compatibility_unicode, 0x314F);
-    assert_eq!(jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(jungseong.conjoining_unicode(), 0x1161);
+    assert_eq!(jungseong.conjoining_value(), 'ᅡ');
+    assert_eq!(jungseong.compatibility_unicode(), 0x314F);
+    assert_eq!(jungseong.compatibility_value(), 'ㅏ');
   }
 
   #[test]
   fn test_jungseong_from_compatibility() {
     let jungseong = Jungseong::new('ㅏ' as u32);
-    assert_eq!(jungseong.conjoining_unicode, 0x1161);
-    assert_eq!(jungseong.conjoining_value, 'ᅡ');
-    assert_eq!(jungseong.compatibility_unicode, 0x314F);
-    assert_eq!(jungseong.compatibility_value, 'ㅏ');
+    assert_eq!(jungseong.conjoining_unicode(), 0x1161);
+    assert_eq!(jungseong.conjoining_value(), 'ᅡ');
+    assert_eq!(jungseong.compatibility_unicode(), 0x314F);
+    assert_eq!(jungseong.compatibility_value(), 'ㅏ');
+  }
+
+  #[test]
+  fn test_from_confusable() {
+    assert_eq!(Jungseong::from_confusable('一'), Jungseong::try_new(0x3161));
+    assert_eq!(Jungseong::from_confusable('ー'), Jungseong::try_new(0x3161));
+    assert_eq!(
+      Jungseong::from_confusable('\u{1173}'),
+      Jungseong::try_new(0x3161)
+    );
+    assert_eq!(Jungseong::from_confusable('l'), Jungseong::try_new(0x3163));
+    assert_eq!(Jungseong::from_confusable('L'), Jungseong::try_new(0x3163));
+    assert_eq!(Jungseong::from_confusable('H'), None);
+    assert_eq!(Jungseong::from_confusable('1'), Jungseong::try_new(0x3163));
+    assert_eq!(Jungseong::from_confusable('ㄱ'), None);
   }
 }
@@ -0,0 +1,507 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::conjugate::{
+  class_of, conjugate_as, jongseong, ConjugationClass, DIGEUT_IRREGULAR, SIOT_IRREGULAR,
+};
+use crate::hangul_letter::HangulLetter;
+use crate::jungseong::Jungseong;
+
+// 내장 어미 목록, `conjugate`의 어미 형식과 같음
+pub const COMMON_ENDINGS: [&str; 27] = [
+  "-아/어",
+  "-아/어요",
+  "-아/어서",
+  "-아/어도",
+  "-아/어야",
+  "-아/어주세요",
+  "-아/어줘",
+  "-아/어줘요",
+  "-았/었다",
+  "-았/었어",
+  "-았/었어요",
+  "-았/었습니다",
+  "-았/었는데",
+  "-(스)ㅂ니다",
+  "-(스)ㅂ니까",
+  "-(으)세요",
+  "-(으)셨어요",
+  "-(으)면",
+  "-(으)니까",
+  "-(으)ㄴ",
+  "-(으)ㄹ",
+  "-고",
+  "-지",
+  "-지만",
+  "-는",
+  "-네요",
+  "-게",
+];
+
+// 받침 ㅆ은 있다(맛있다, 재미있다)를 빼면 선어말 어미 -았/었-, -겠-에서만 나옴
+// 먹었+어를 먹었다로, 갔+습니다를 갔다로 보지 않음
+const SSANGSIOT_STEM: char = '있';
+// 어간 끝에 오지 않는 어미 첫 음절 (먹어+었을 먹어다로 보지 않음)
+const ENDING_INITIAL: [char; 4] = ['아', '어', '여', '으'];
+
+// 어미와 합쳐지며 바뀐 중성을 어간의 중성으로 되돌리는 후보 (봐 → 보, 해 → 하, 써 → 쓰)
+const UNCONTRACTED: [(char, &[char]); 8] = [
+  ('ㅘ', &['ㅗ']),
+  ('ㅝ', &['ㅜ']),
+  ('ㅙ', &['ㅚ']),
+  ('ㅕ', &['ㅣ']),
+  ('ㅐ', &['ㅏ', 'ㅓ']),
+  ('ㅒ', &['ㅑ', 'ㅕ']),
+  ('ㅓ', &['ㅡ', 'ㅜ']),
+  ('ㅏ', &['ㅡ']),
+];
+
+// ㅡ 탈락 어간의 끝 음절 (써 → 쓰, 바빠 → 바쁘), 쁘·프는 두 음절 이상에서만 (아파 → 아프, 퍼 → 프는 아님)
+// 나머지(가, 서, 나)는 어간 그대로 봄 (나가 → 나그, 만나 → 만느는 아님)
+const EU_DROP: [char; 7] = ['쓰', '크', '뜨', '끄', '트', '쁘', '프'];
+
+// 불규칙 활용에서 바뀌거나 탈락한 받침 후보 (들 → 듣, 도 → 돕, 사 → 살, 그런 → 그렇)
+const RESTORED_JONGSEONG: [char; 5] = ['ㄹ', 'ㅎ', 'ㅂ', 'ㅅ', 'ㄷ'];
+
+// 어간 후보를 만든 방법, 앞의 것을 먼저 반환
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Origin {
+  // 활용형의 음절 그대로 (먹+었어, 간 → 가)
+  Literal,
+  // 축약된 모음이나 불규칙 활용으로 바뀐 받침을 되돌림 (봐 → 보, 들 → 듣, 몰라 → 모르)
+  Irregular,
+  // ㅎ 불규칙 (그래 → 그렇, 하얀 → 하얗)
+  Hieut,
+  // ㄹ 탈락 (사는 → 살)
+  Rieul,
+  // 어미 모음이 어간 끝 모음에 흡수된 음절 그대로 (가요 → 가, 써요 → 써)
+  Contracted,
+}
+
+// 기본형, 어간으로 본 활용형 음절 수, 어간 후보를 만든 방법
+type Match = (Lemma, usize, Origin);
+
+struct Candidate {
+  stem: String,
+  // 어간으로 본 활용형 음절 수
+  end: usize,
+  origin: Origin,
+}
+
+// 활용형에서 찾은 기본형과 떼어 낸 어미
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lemma {
+  pub lemma: String,
+  pub ending: String,
+}
+
+// 규칙 기반 기본형 추출기, 어간 후보를 `conjugate_as`로 다시 활용해 입력과 같은 것만 남김
+// 기본값은 내장 어미 목록을 사용하고 기본형 사전 없이 그럴듯한 후보부터 반환
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lemmatizer {
+  endings: Vec<String>,
+  dictionary: Vec<String>,
+}
+
+impl Default for Lemmatizer {
+  fn default() -> Self {
+    Self {
+      endings: COMMON_ENDINGS
+        .iter()
+        .map(|ending| ending.to_string())
+        .collect(),
+      dictionary: Vec::new(),
+    }
+  }
+}
+
+impl Lemmatizer {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  // 내장 어미 목록 대신 주어진 어미만 사용
+  pub(crate) fn with_endings(endings: &[&str]) -> Self {
+    Self {
+      endings: endings.iter().map(|ending| ending.to_string()).collect(),
+      dictionary: Vec::new(),
+    }
+  }
+
+  // 어미 추가 ("-더라고요", "-(으)ㄹ게요")
+  pub fn ending(mut self, ending: &str) -> Self {
+    if !self.endings.iter().any(|existing| existing == ending) {
+      self.endings.push(ending.to_string());
+    }
+    self
+  }
+
+  // 기본형 사전에 추가, 사전이 비어 있지 않으면 사전에 있는 기본형만 반환
+  pub fn lemma(mut self, lemma: &str) -> Self {
+    if !self.dictionary.iter().any(|existing| existing == lemma) {
+      self.dictionary.push(lemma.to_string());
+    }
+    self
+  }
+
+  pub fn lemmas<'a>(self, lemmas: impl IntoIterator<Item = &'a str>) -> Self {
+    lemmas.into_iter().fold(self, Self::lemma)
+  }
+
+  // 활용형의 기본형 후보 ("먹었습니다" → 먹다 + -았/었습니다, "들었어" → 들다, 듣다)
+  // 활용형 음절 그대로인 어간, 불규칙 활용을 되돌린 어간, ㄹ 탈락 어간 순서
+  pub fn lemmatize(&self, word: &str) -> Vec<Lemma> {
+    let Some(letters) = word
+      .chars()
+      .map(HangulLetter::parse_from_char)
+      .collect::<Option<Vec<_>>>()
+    else {
+      return Vec::new();
+    };
+
+    let mut matches: Vec<Match> = Vec::new();
+    for candidate in stem_candidates(&letters) {
+      let stem = &candidate.stem;
+      if stem.chars().any(is_pre_final)
+        || stem
+          .chars()
+          .last()
+          .is_some_and(|ch| ENDING_INITIAL.contains(&ch))
+      {
+        continue;
+      }
+      let predicate = stem.clone() + "다";
+      if !self.dictionary.is_empty() && !self.dictionary.contains(&predicate) {
+        continue;
+      }
+
+      let class = class_of(stem);
+      let conjugates_to = |ending: &str, class| {
+        conjugate_as(&predicate, ending, class).is_ok_and(|form| form == word)
+      };
+      for ending in &self.endings {
+        // 규칙으로도 활용하는 어간 (묻다: 물어/묻어)
+        let matched = conjugates_to(ending, class)
+          || (class != ConjugationClass::Regular
+            && conjugates_to(ending, ConjugationClass::Regular));
+        if !matched {
+          continue;
+        }
+
+        let origin = if candidate.origin == Origin::Literal
+          && is_contracted(&letters, candidate.end, ending)
+        {
+          Origin::Contracted
+        } else {
+          candidate.origin
+        };
+        let lemma = Lemma {
+          lemma: predicate.clone(),
+          ending: ending.clone(),
+        };
+        if !matches.iter().any(|(existing, _, _)| *existing == lemma) {
+          matches.push((lemma, candidate.end, origin));
+        }
+      }
+    }
+
+    prune(&mut matches);
+    matches.sort_by_key(|&(_, _, origin)| origin);
+    matches.into_iter().map(|(lemma, _, _)| lemma).collect()
+  }
+}
+
+// 같은 활용형을 설명하는 후보 중 어미가 만든 음절을 어간으로 잘못 본 것을 버림
+fn prune(matches: &mut Vec<Match>) {
+  // 하+여 → 해를 핳, 헣으로 보지 않음 (감사해요 → 감사하다)
+  retain_unless(matches, |(_, end, origin), (_, other_end, other)| {
+    *origin == Origin::Hieut && other_end == end && *other == Origin::Irregular
+  });
+  // ㅎ 불규칙 어간이 맞으면 같은 음절의 나머지 후보를 버림 (하얀 → 하얗다, 하야다 아님)
+  retain_unless(matches, |(_, end, origin), (_, other_end, other)| {
+    *origin != Origin::Hieut && other_end == end && *other == Origin::Hieut
+  });
+  // 도와, 고와는 돕다, 곱다에서만 나옴 (도오다 아님)
+  retain_unless(
+    matches,
+    |(lemma, end, origin), (other_lemma, other_end, other)| {
+      *origin == Origin::Irregular
+        && lemma.lemma.ends_with("오다")
+        && *other_end + 1 == *end
+        && *other == Origin::Irregular
+        && ends_with_bieup(&other_lemma.lemma)
+    },
+  );
+  // 더 짧은 불규칙 어간이 맞으면 ㄹ 탈락 후보를 버림 (도와주세요 → 돕다, 도와줄다 아님)
+  retain_unless(matches, |(_, end, origin), (_, other_end, other)| {
+    *origin == Origin::Rieul && other_end < end && *other == Origin::Irregular
+  });
+  // 더 짧은 어간이 음절 그대로 맞으면 어미 음절을 어간에 넣은 후보를 버림 (사는 → 사느다, 가세요 → 가세다 아님)
+  retain_unless(matches, |(_, end, origin), (_, other_end, other)| {
+    !matches!(origin, Origin::Irregular | Origin::Hieut)
+      && other_end < end
+      && *other == Origin::Literal
+  });
+
+  // 축약된 음절 그대로인 어간은 다른 후보가 없을 때만 (써요 → 쓰다, 해요 → 하다, 가요 → 가다)
+  if matches
+    .iter()
+    .any(|&(_, _, origin)| origin != Origin::Contracted)
+  {
+    matches.retain(|&(_, _, origin)| origin != Origin::Contracted);
+  }
+}
+
+// `shadowed(후보, 다른 후보)`가 참인 다른 후보가 있으면 버림
+fn retain_unless(matches: &mut Vec<Match>, shadowed: impl Fn(&Match, &Match) -> bool) {
+  let snapshot = matches.clone();
+  matches.retain(|found| !snapshot.iter().any(|other| shadowed(found, other)));
+}
+
+fn ends_with_bieup(lemma: &str) -> bool {
+  lemma
+    .trim_end_matches('다')
+    .chars()
+    .last()
+    .and_then(HangulLetter::parse_from_char)
+    .and_then(|letter| letter.jongseong)
+    .is_some_and(|jamo| jamo.compatibility_value() == 'ㅂ')
+}
+
+// 모음 조화 어미의 아/어가 어간 끝 모음에 흡수됐는지 (가+아요 → 가요, 먹+어요 → 먹어요는 아님)
+// 한 음절 ㅕ 어간(펴다, 켜다)은 피다, 키다와 함께 어간 그대로 봄
+fn is_contracted(letters: &[HangulLetter], end: usize, ending: &str) -> bool {
+  let body = ending.strip_prefix('-').unwrap_or(ending);
+  let dark = body.split_once('/').map_or(body, |(_, dark)| dark);
+  if !dark.starts_with(['아', '어', '았', '었']) {
+    return false;
+  }
+  if end == 1 && letters[0].jungseong.compatibility_value() == 'ㅕ' {
+    return false;
+  }
+  letters.len() + 1 == end + dark.chars().count()
+}
+
+fn is_pre_final(ch: char) -> bool {
+  ch != SSANGSIOT_STEM
+    && HangulLetter::parse_from_char(ch)
+      .and_then(|letter| letter.jongseong)
+      .is_some_and(|jamo| jamo.compatibility_value() == 'ㅆ')
+}
+
+// 내장 어미 목록으로 기본형 후보 추출
+pub fn lemmatize(word: &str) -> Vec<Lemma> {
+  Lemmatizer::new().lemmatize(word)
+}
+
+// 앞에서부터 k번째 음절까지를 어간으로 보고, 마지막 음절의 받침과 중성을 되돌린 후보를 만듦
+fn stem_candidates(letters: &[HangulLetter]) -> Vec<Candidate> {
+  let mut candidates: Vec<Candidate> = Vec::new();
+
+  for end in 1..=letters.len() {
+    let head_letters = &letters[..end - 1];
+    let head: String = head_letters
+      .iter()
+      .map(|&letter| char::from(letter))
+      .collect();
+    let last = letters[end - 1];
+
+    let mut tails: Vec<(String, Origin)> = Vec::new();
+    let mut push_letter =
+      |letter: HangulLetter, origin| tails.push((char::from(letter).to_string(), origin));
+
+    push_letter(last, Origin::Literal);
+    push_letter(last.remove_jongseong(), Origin::Literal);
+    for jamo in RESTORED_JONGSEONG {
+      let restored = last.with_jongseong(jongseong(jamo));
+      match jamo {
+        'ㄹ' => push_letter(restored, Origin::Rieul),
+        'ㅎ' if is_hieut_stem(head_letters, restored) => push_letter(restored, Origin::Hieut),
+        'ㅎ' => {}
+        // 되돌린 받침은 불규칙 어간일 때만 (만들 → 만듣은 아님, 알아들 → 알아듣은 맞음)
+        _ if is_irregular_stem(&(head.clone() + &char::from(restored).to_string())) => {
+          push_letter(restored, Origin::Irregular)
+        }
+        _ => {}
+      }
+    }
+
+    let vowel = last.jungseong.compatibility_value();
+    if let Some((_, originals)) = UNCONTRACTED
+      .iter()
+      .find(|(contracted, _)| *contracted == vowel)
+    {
+      for &original in originals.iter() {
+        let base = last
+          .with_jungseong(Jungseong::new(original as u32))
+          .remove_jongseong();
+        if original != 'ㅡ' || is_eu_drop(head_letters, base) {
+          push_letter(base, Origin::Irregular);
+        }
+        let hieut = base.with_jongseong(jongseong('ㅎ'));
+        if is_hieut_stem(head_letters, hieut) {
+          push_letter(hieut, Origin::Hieut);
+        }
+      }
+    }
+
+    // 르 불규칙 (불러 → 부르)
+    if last.jongseong.map(|jamo| jamo.compatibility_value()) == Some('ㄹ') {
+      let mut tail = String::new();
+      tail.push(char::from(last.remove_jongseong()));
+      tail.push('르');
+      tails.push((tail, Origin::Irregular));
+    }
+
+    for (tail, origin) in tails {
+      let stem = head.clone() + &tail;
+      if !candidates.iter().any(|candidate| candidate.stem == stem) {
+        candidates.push(Candidate { stem, end, origin });
+      }
+    }
+  }
+
+  candidates
+}
+
+// ㅡ 탈락 어간 후보인지 (EU_DROP)
+fn is_eu_drop(head: &[HangulLetter], letter: HangulLetter) -> bool {
+  match char::from(letter) {
+    '쁘' | '프' => !head.is_empty(),
+    ch => EU_DROP.contains(&ch),
+  }
+}
+
+// ㅎ 불규칙 어간 후보인지, 두 음절 이상이고 앞 음절과 모음 조화가 맞아야 함 (파랗다, 그렇다, 동그랗다)
+fn is_hieut_stem(head: &[HangulLetter], letter: HangulLetter) -> bool {
+  let bright = head
+    .iter()
+    .rev()
+    .find_map(|letter| match letter.jungseong.compatibility_value() {
+      'ㅏ' | 'ㅑ' | 'ㅗ' | 'ㅛ' | 'ㅘ' | 'ㅙ' => Some(true),
+      'ㅓ' | 'ㅕ' | 'ㅜ' | 'ㅠ' | 'ㅝ' | 'ㅞ' => Some(false),
+      _ => None,
+    })
+    .unwrap_or(false);
+
+  !head.is_empty()
+    && match letter.jungseong.compatibility_value() {
+      'ㅏ' | 'ㅑ' => bright,
+      'ㅓ' | 'ㅕ' => !bright,
+      _ => false,
+    }
+}
+
+// 받침을 되돌린 어간이 불규칙 활용 어간인지, ㄷ·ㅅ 불규칙은 목록의 어간이나 -아/어 합성어만 (알아듣다)
+fn is_irregular_stem(stem: &str) -> bool {
+  let listed = |list: &[&str]| {
+    list.iter().any(|entry| {
+      stem
+        .strip_suffix(entry)
+        .is_some_and(|rest| rest.is_empty() || rest.ends_with(['아', '어']))
+    })
+  };
+
+  match class_of(stem) {
+    ConjugationClass::Regular => false,
+    ConjugationClass::DigeutIrregular => listed(&DIGEUT_IRREGULAR),
+    ConjugationClass::SiotIrregular => listed(&SIOT_IRREGULAR),
+    _ => true,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn lemmas(word: &str) -> Vec<String> {
+    lemmatize(word)
+      .into_iter()
+      .map(|lemma| lemma.lemma)
+      .collect()
+  }
+
+  #[test]
+  fn test_lemmatize_regular() {
+    assert_eq!(
+      lemmatize("먹었습니다"),
+      [Lemma {
+        lemma: "먹다".to_string(),
+        ending: "-았/었습니다".to_string(),
+      }]
+    );
+    assert_eq!(lemmas("봤어요"), ["보다"]);
+    assert_eq!(lemmas("가요"), ["가다"]);
+    assert_eq!(lemmas("서요"), ["서다"]);
+    assert_eq!(lemmas("만나요"), ["만나다"]);
+    assert_eq!(lemmas("마셔요"), ["마시다"]);
+    assert_eq!(lemmas("써요"), ["쓰다"]);
+    assert_eq!(lemmas("예뻐요"), ["예쁘다"]);
+    assert_eq!(lemmas("아파요"), ["아프다"]);
+    assert_eq!(lemmas("보내요"), ["보내다"]);
+    assert_eq!(lemmas("만들어요"), ["만들다"]);
+    assert_eq!(lemmas("펴요"), ["펴다", "피다"]);
+    assert_eq!(lemmas("사는"), ["사다", "살다"]);
+    assert_eq!(lemmas("가세요"), ["가다", "갈다"]);
+  }
+
+  #[test]
+  fn test_lemmatize_irregular() {
+    assert_eq!(
+      lemmatize("도와주세요"),
+      [
+        Lemma {
+          lemma: "도와주다".to_string(),
+          ending: "-(으)세요".to_string(),
+        },
+        Lemma {
+          lemma: "돕다".to_string(),
+          ending: "-아/어주세요".to_string(),
+        },
+      ]
+    );
+
+    assert_eq!(lemmas("들었어"), ["들다", "듣다"]);
+    assert_eq!(lemmas("알아들었어요"), ["알아들다", "알아듣다"]);
+    assert_eq!(lemmas("해요"), ["하다"]);
+    assert_eq!(lemmas("했습니다"), ["하다"]);
+    assert_eq!(lemmas("감사해요"), ["감사하다"]);
+    assert_eq!(lemmas("몰라요"), ["모르다"]);
+    assert_eq!(lemmas("불러요"), ["부르다"]);
+    assert_eq!(lemmas("나았다"), ["낫다"]);
+    assert_eq!(lemmas("그래요"), ["그렇다"]);
+    assert_eq!(lemmas("파래요"), ["파랗다"]);
+    assert_eq!(lemmas("하얘요"), ["하얗다"]);
+    assert_eq!(lemmas("하얀"), ["하얗다"]);
+    assert_eq!(lemmas("개요"), ["개다"]);
+    assert_eq!(lemmas("추워요")[0], "춥다");
+    assert_eq!(lemmas("나와요"), ["나오다"]);
+    assert_eq!(lemmas("푸르러요"), ["푸르다"]);
+    assert_eq!(lemmas("퍼요"), ["푸다"]);
+  }
+
+  #[test]
+  fn test_lemmatizer_dictionary() {
+    let lemmatizer = Lemmatizer::new().lemmas(["듣다", "도와주다"]);
+
+    assert_eq!(
+      lemmatizer
+        .lemmatize("들었어")
+        .into_iter()
+        .map(|lemma| lemma.lemma)
+        .collect::<Vec<_>>(),
+      ["듣다"]
+    );
+    assert_eq!(lemmatizer.lemmatize("도와주세요")[0].lemma, "도와주다");
+    assert!(lemmatizer.lemmatize("먹었어").is_empty());
+  }
+
+  #[test]
+  fn test_lemmatizer_custom_ending() {
+    assert!(lemmatize("먹더라고요").is_empty());
+
+    let lemmatizer = Lemmatizer::new().ending("-더라고요");
+    assert_eq!(lemmatizer.lemmatize("먹더라고요")[0].lemma, "먹다");
+    assert!(lemmatize("hello").is_empty());
+  }
+}
@@ -1,11 +1,93 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod assemble;
 mod choseong;
+mod classify;
+#[cfg(feature = "alloc")]
+mod confusable;
+#[cfg(feature = "alloc")]
+mod conjugate;
+#[cfg(feature = "alloc")]
+mod dueum;
+mod enclosed;
+mod halfwidth;
+#[cfg(feature = "alloc")]
 mod hangul;
 mod hangul_letter;
+mod hanja;
+mod hanja_table;
 mod jongseong;
 mod jungseong;
+#[cfg(feature = "alloc")]
+mod lemma;
+#[cfg(feature = "alloc")]
+mod moderation;
 mod nfc;
 mod nfd;
+mod old_hangul;
+#[cfg(feature = "alloc")]
+mod options;
+#[cfg(feature = "alloc")]
+mod romanize;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "alloc")]
+mod speech_level;
+#[cfg(feature = "alloc")]
+mod stream;
 mod utils;
 
-pub use crate::hangul::Hangul;
-pub use crate::hangul_letter::HangulLetter;
+#[cfg(feature = "alloc")]
+pub use crate::assemble::assemble;
+pub use crate::choseong::Choseong;
+pub use crate::classify::{
+  can_be_choseong, can_be_jongseong, is_complex_jongseong, is_complex_vowel, is_consonant,
+  is_double_consonant, is_jamo, is_vowel, jamo_block, JamoBlock,
+};
+#[cfg(feature = "alloc")]
+pub use crate::confusable::{confusable_skeleton, is_confusable};
+#[cfg(feature = "alloc")]
+pub use crate::conjugate::{
+  conjugate, conjugate_as, conjugation_class, ConjugateError, ConjugationClass,
+};
+#[cfg(feature = "alloc")]
+pub use crate::dueum::{apply_dueum, dueum_originals};
+pub use crate::enclosed::{
+  enclosed_jamo, enclosed_style, enclosed_syllable, expand_enclosed, is_enclosed_hangul,
+  to_enclosed, EnclosedStyle,
+};
+#[cfg(feature = "alloc")]
+pub use crate::halfwidth::fold_halfwidth;
+pub use crate::halfwidth::{
+  compatibility_to_halfwidth, halfwidth_to_compatibility, is_halfwidth_jamo,
+};
+#[cfg(feature = "alloc")]
+pub use crate::hangul::{CharUnit, CharUnits, Hangul};
+pub use crate::hangul_letter::{HangulLetter, JamoError};
+pub use crate::hanja::hanja_reading;
+#[cfg(feature = "alloc")]
+pub use crate::hanja::hanja_to_hangul;
+pub use crate::jongseong::Jongseong;
+pub use crate::jungseong::Jungseong;
+#[cfg(feature = "alloc")]
+pub use crate::lemma::{lemmatize, Lemma, Lemmatizer, COMMON_ENDINGS};
+#[cfg(feature = "alloc")]
+pub use crate::moderation::{moderation_skeleton, ModerationFilter, ModerationMatch};
+pub use crate::nfd::{NormalizeError, NFD};
+#[cfg(feature = "alloc")]
+pub use crate::old_hangul::fill_incomplete_syllables;
+pub use crate::old_hangul::{conjoining_to_compatibility, OldHangul};
+#[cfg(feature = "alloc")]
+pub use crate::options::{DisassembleOptions, ExtractOptions, JamoForm, NonHangul};
+#[cfg(feature = "alloc")]
+pub use crate::romanize::romanize;
+#[cfg(feature = "alloc")]
+pub use crate::speech_level::{speech_level, to_honorific, to_speech_level, SpeechLevel};
+#[cfg(feature = "std")]
+pub use crate::stream::{choseong_stream, disassemble_stream};
+#[cfg(feature = "alloc")]
+pub use crate::stream::{HangulStream, StreamError};
@@ -0,0 +1,207 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::choseong::Choseong;
+use crate::hangul::Hangul;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+use crate::options::DisassembleOptions;
+
+// 옛한글 자모를 소리나 모양이 가까운 현대 자모로 바꿈 (ㆍ → ㅏ, ㅿ → ㅅ)
+const OLD_JAMO: [(char, char); 10] = [
+  ('ㆍ', 'ㅏ'),
+  ('ㆎ', 'ㅐ'),
+  ('ㅿ', 'ㅅ'),
+  ('ㆁ', 'ㅇ'),
+  ('ㆆ', 'ㅇ'),
+  ('ㆀ', 'ㅇ'),
+  ('ㅸ', 'ㅂ'),
+  ('ㅱ', 'ㅁ'),
+  ('ㆄ', 'ㅍ'),
+  ('ㆅ', 'ㅎ'),
+];
+
+// 호환형 채움 문자와 조합형 초성·중성 채움 문자
+const FILLERS: [char; 3] = ['\u{3164}', '\u{115F}', '\u{1160}'];
+
+// 블록리스트 단어가 나타난 원본 문자열의 바이트 범위
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModerationMatch {
+  pub word: String,
+  pub range: Range<usize>,
+}
+
+// 자모 분리, 띄어쓰기·기호 삽입, 비슷한 문자 치환, 옛한글, NFD/NFC 혼용을 무시하고 블록리스트 단어를 찾음
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModerationFilter {
+  words: Vec<(String, Vec<char>)>,
+}
+
+impl ModerationFilter {
+  pub fn new<'a>(blocklist: impl IntoIterator<Item = &'a str>) -> Self {
+    let words = blocklist
+      .into_iter()
+      .map(|word| {
+        (
+          word.to_string(),
+          moderation_skeleton(word).chars().collect::<Vec<_>>(),
+        )
+      })
+      .filter(|(_, skeleton)| !skeleton.is_empty())
+      .collect();
+
+    Self { words }
+  }
+
+  // 블록리스트 단어가 나타난 원본 범위, 시작 위치 순서
+  pub fn find_matches(&self, text: &str) -> Vec<ModerationMatch> {
+    let hangul = Hangul::new(text);
+    let skeleton = skeleton(&hangul);
+    let mut result = Vec::new();
+
+    for (word, pattern) in &self.words {
+      let mut start = 0;
+      while start + pattern.len() <= skeleton.len() {
+        let window = &skeleton[start..start + pattern.len()];
+        if !window.iter().map(|&(ch, _)| ch).eq(pattern.iter().copied()) {
+          start += 1;
+          continue;
+        }
+
+        let (first, last) = (window[0].1, window[window.len() - 1].1);
+        result.push(ModerationMatch {
+          word: word.clone(),
+          range: hangul.byte_offset(first)..hangul.byte_offset(last + 1),
+        });
+        start += pattern.len();
+      }
+    }
+
+    result.sort_by_key(|found| (found.range.start, found.range.end));
+    result
+  }
+
+  pub fn is_match(&self, text: &str) -> bool {
+    !self.find_matches(text).is_empty()
+  }
+}
+
+// 비교용 자모 문자열 ("시 발", "ㅅㅣㅂㅏㄹ", "ᄉᆡ발" → "ㅅㅣㅂㅏㄹ")
+// 호환형 자모로 분해하고 이중 모음과 겹받침을 나누며, 공백과 기호는 버리고 영문자는 소문자로 바꿈
+pub fn moderation_skeleton(text: &str) -> String {
+  skeleton(&Hangul::new(text))
+    .into_iter()
+    .map(|(ch, _)| ch)
+    .collect()
+}
+
+// 비교용 자모와 그 자모가 나온 글자 인덱스
+fn skeleton(hangul: &Hangul) -> Vec<(char, usize)> {
+  let options = DisassembleOptions::new()
+    .split_complex_jungseong(true)
+    .expand_enclosed(true);
+  let mut result = Vec::with_capacity(hangul.len() * 3);
+
+  for (index, unit) in hangul.iter().enumerate() {
+    let mut push = |ch: char| result.push((fold_old_jamo(ch), index));
+    if options.push_unit_jamo(&unit, &mut push) {
+      continue;
+    }
+
+    for ch in unit.as_str().chars() {
+      push_char(ch, &mut push);
+    }
+  }
+
+  result.retain(|&(ch, _)| !FILLERS.contains(&ch));
+  result
+}
+
+// 음절이 아닌 문자 하나를 비교용 자모로 바꿔 `push`에 전달
+// 대소문자를 먼저 접고, 자모와 비슷한 모양의 문자(o → ㅇ, 1 → ㅣ, 人 → ㅅ)는 `from_confusable`로 자모로 봄
+fn push_char(ch: char, push: &mut impl FnMut(char)) {
+  for ch in ch.to_lowercase() {
+    if let Some(jungseong) = Jungseong::from_confusable(ch) {
+      let (first, second) = jungseong.split();
+      push(first.compatibility_value());
+      if let Some(second) = second {
+        push(second.compatibility_value());
+      }
+    } else if let Some(jongseong) = Jongseong::from_confusable(ch) {
+      let (first, second) = jongseong.split();
+      push(first.compatibility_value());
+      if let Some(second) = second {
+        push(second.compatibility_value());
+      }
+    } else if let Some(choseong) = Choseong::from_confusable(ch) {
+      push(choseong.compatibility_value());
+    } else if ch.is_alphanumeric() {
+      push(ch);
+    }
+  }
+}
+
+fn fold_old_jamo(ch: char) -> char {
+  OLD_JAMO
+    .iter()
+    .find(|&&(old, _)| old == ch)
+    .map_or(ch, |&(_, modern)| modern)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::halfwidth::compatibility_to_halfwidth;
+
+  #[test]
+  fn test_moderation_skeleton() {
+    let expected = "ㅅㅣㅂㅏㄹ";
+
+    assert_eq!(moderation_skeleton("시발"), expected);
+    assert_eq!(moderation_skeleton("ㅅㅣㅂㅏㄹ"), expected);
+    assert_eq!(moderation_skeleton("시 . 발"), expected);
+    assert_eq!(moderation_skeleton("ㅅ1ㅂㅏㄹ"), expected);
+    assert_eq!(moderation_skeleton("人丨ㅂトㄹ"), expected);
+    assert_eq!(moderation_skeleton("\u{1109}\u{1175}발"), expected);
+    let halfwidth: String = expected
+      .chars()
+      .filter_map(compatibility_to_halfwidth)
+      .collect();
+    assert_eq!(moderation_skeleton(&halfwidth), expected);
+    assert_eq!(moderation_skeleton("SHIT"), "shit");
+    assert_eq!(moderation_skeleton("SHIT"), moderation_skeleton("shit"));
+    assert_eq!(moderation_skeleton("괜 ㅙ ㄺ"), "ㄱㅗㅐㄴㅗㅐㄹㄱ");
+    assert_eq!(moderation_skeleton("ᄉᆞᆯ"), "ㅅㅏㄹ");
+    assert_eq!(moderation_skeleton("Ab-c"), "abc");
+  }
+
+  #[test]
+  fn test_find_matches() {
+    let filter = ModerationFilter::new(["시발", "개새끼"]);
+    let text = "야 ㅅ ㅣ ㅂ ㅏ ㄹ, 개 새 끼야";
+    let matches = filter.find_matches(text);
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].word, "시발");
+    assert_eq!(&text[matches[0].range.clone()], "ㅅ ㅣ ㅂ ㅏ ㄹ");
+    assert_eq!(matches[1].word, "개새끼");
+    assert_eq!(&text[matches[1].range.clone()], "개 새 끼");
+
+    let nfd = "\u{1109}\u{1175}\u{1107}\u{1161}\u{11AF}";
+    assert_eq!(filter.find_matches(nfd)[0].range, 0..nfd.len());
+    assert!(filter.is_match("ㅅ1발"));
+    assert!(!filter.is_match("시계 발견"));
+    assert!(!filter.is_match("안녕하세요"));
+  }
+
+  #[test]
+  fn test_find_matches_case() {
+    let filter = ModerationFilter::new(["shit", "hello"]);
+
+    assert!(filter.is_match("SHIT"));
+    assert!(filter.is_match("ShIt"));
+    assert!(filter.is_match("Hello"));
+    assert!(filter.is_match("HELLO"));
+  }
+}
@@ -1,6 +1,7 @@
 const HANGUL_BASE: u32 = 0xAC00;
 const HANGUL_LAST: u32 = 0xD7A3;
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct NFC;
 
 impl NFC {
@@ -21,7 +22,7 @@ impl NFC {
   }
 
   pub fn is_complete_hangul(unicode: u32) -> bool {
-    HANGUL_BASE <= unicode && unicode <= HANGUL_LAST
+    (HANGUL_BASE..=HANGUL_LAST).contains(&unicode)
   }
 }
 
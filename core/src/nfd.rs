@@ -1,5 +1,4 @@
-use std::error::Error;
-use std::fmt;
+use core::fmt;
 
 use crate::choseong::Choseong;
 use crate::jongseong::Jongseong;
@@ -18,9 +17,10 @@ const JONGSEONG_BASE: u32 = 0x11A8;
 
 const JUNGSEONG_AND_JONGSEONG_NUMBER_OF_CASES: u32 = JUNGSEONG_COUNT * JONGSEONG_COUNT;
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct NFD(pub u32, pub u32, pub Option<u32>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NormalizeError {
   InvalidHangul,
 }
@@ -33,7 +33,8 @@ impl fmt::Display for NormalizeError {
   }
 }
 
-impl Error for NormalizeError {}
+#[cfg(feature = "std")]
+impl std::error::Error for NormalizeError {}
 
 impl NFD {
   pub fn normalize(nfc_letter_unicode: u32) -> Result<Self, NormalizeError> {
@@ -59,32 +60,27 @@ impl NFD {
   }
 
   pub fn is_nfd_hangul(string: &str) -> bool {
-    let chars: Vec<char> = string.chars().collect();
-    let chars_len = chars.len();
+    let mut chars = string.chars();
 
-    if chars_len != 2 && chars_len != 3 {
+    let (Some(choseong), Some(jungseong)) = (chars.next(), chars.next()) else {
       return false;
-    }
-
-    let choseong_unicode = chars[0] as u32;
-    let jungseong_unicode = chars[1] as u32;
+    };
 
-    if !Choseong::is_conjoining_choseong(choseong_unicode) {
+    if !Choseong::is_conjoining_choseong(choseong as u32) {
       return false;
     }
 
-    if !Jungseong::is_conjoining_jungseong(jungseong_unicode) {
+    if !Jungseong::is_conjoining_jungseong(jungseong as u32) {
       return false;
     }
 
-    if chars_len == 3 {
-      let jongseong_unicode = chars[2] as u32;
-      if !Jongseong::is_conjoining_jongseong(jongseong_unicode) {
+    if let Some(jongseong) = chars.next() {
+      if !Jongseong::is_conjoining_jongseong(jongseong as u32) {
         return false;
       }
     }
 
-    true
+    chars.next().is_none()
   }
 }
 
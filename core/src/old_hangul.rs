@@ -0,0 +1,455 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::hangul_letter::HangulLetter;
+#[cfg(feature = "alloc")]
+use crate::nfc::NFC;
+
+// 채움 문자 (KS X 1026-1)
+#[cfg(feature = "alloc")]
+const CHOSEONG_FILLER: char = '\u{115F}';
+#[cfg(feature = "alloc")]
+const JUNGSEONG_FILLER: char = '\u{1160}';
+
+// 한 글자 단위는 `UnitSpan::len`(u32)에 담기므로 옛한글 음절의 최대 바이트 길이를 제한
+const MAX_CLUSTER_LEN: usize = u32::MAX as usize;
+
+// 현대 초성·중성 조합형 자모, 자모마다 3바이트
+const MODERN_CHOSEONG: &str = "\u{1100}\u{1101}\u{1102}\u{1103}\u{1104}\u{1105}\u{1106}\u{1107}\u{1108}\u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\u{1112}";
+const MODERN_JUNGSEONG: &str = "\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\u{116D}\u{116E}\u{116F}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}";
+
+// 조합형 자모(현대·옛한글, 확장-A/B)와 호환형 자모 대응 테이블 (조합형, 호환형), 조합형 기준 정렬
+// 호환 자모의 호환 분해(NFKD)와, 같은 이름의 초성·종성 짝으로 구성
+const COMPATIBILITY_MAPPING: [(u32, u32); 140] = [
+  (0x1100, 0x3131),
+  (0x1101, 0x3132),
+  (0x1102, 0x3134),
+  (0x1103, 0x3137),
+  (0x1104, 0x3138),
+  (0x1105, 0x3139),
+  (0x1106, 0x3141),
+  (0x1107, 0x3142),
+  (0x1108, 0x3143),
+  (0x1109, 0x3145),
+  (0x110A, 0x3146),
+  (0x110B, 0x3147),
+  (0x110C, 0x3148),
+  (0x110D, 0x3149),
+  (0x110E, 0x314A),
+  (0x110F, 0x314B),
+  (0x1110, 0x314C),
+  (0x1111, 0x314D),
+  (0x1112, 0x314E),
+  (0x1114, 0x3165),
+  (0x1115, 0x3166),
+  (0x111A, 0x3140),
+  (0x111C, 0x316E),
+  (0x111D, 0x3171),
+  (0x111E, 0x3172),
+  (0x1120, 0x3173),
+  (0x1121, 0x3144),
+  (0x1122, 0x3174),
+  (0x1123, 0x3175),
+  (0x1127, 0x3176),
+  (0x1129, 0x3177),
+  (0x112B, 0x3178),
+  (0x112C, 0x3179),
+  (0x112D, 0x317A),
+  (0x112E, 0x317B),
+  (0x112F, 0x317C),
+  (0x1132, 0x317D),
+  (0x1136, 0x317E),
+  (0x1140, 0x317F),
+  (0x1147, 0x3180),
+  (0x114C, 0x3181),
+  (0x1157, 0x3184),
+  (0x1158, 0x3185),
+  (0x1159, 0x3186),
+  (0x115B, 0x3167),
+  (0x115C, 0x3135),
+  (0x115D, 0x3136),
+  (0x1161, 0x314F),
+  (0x1162, 0x3150),
+  (0x1163, 0x3151),
+  (0x1164, 0x3152),
+  (0x1165, 0x3153),
+  (0x1166, 0x3154),
+  (0x1167, 0x3155),
+  (0x1168, 0x3156),
+  (0x1169, 0x3157),
+  (0x116A, 0x3158),
+  (0x116B, 0x3159),
+  (0x116C, 0x315A),
+  (0x116D, 0x315B),
+  (0x116E, 0x315C),
+  (0x116F, 0x315D),
+  (0x1170, 0x315E),
+  (0x1171, 0x315F),
+  (0x1172, 0x3160),
+  (0x1173, 0x3161),
+  (0x1174, 0x3162),
+  (0x1175, 0x3163),
+  (0x1184, 0x3187),
+  (0x1185, 0x3188),
+  (0x1188, 0x3189),
+  (0x1191, 0x318A),
+  (0x1192, 0x318B),
+  (0x1194, 0x318C),
+  (0x119E, 0x318D),
+  (0x11A1, 0x318E),
+  (0x11A8, 0x3131),
+  (0x11A9, 0x3132),
+  (0x11AA, 0x3133),
+  (0x11AB, 0x3134),
+  (0x11AC, 0x3135),
+  (0x11AD, 0x3136),
+  (0x11AE, 0x3137),
+  (0x11AF, 0x3139),
+  (0x11B0, 0x313A),
+  (0x11B1, 0x313B),
+  (0x11B2, 0x313C),
+  (0x11B3, 0x313D),
+  (0x11B4, 0x313E),
+  (0x11B5, 0x313F),
+  (0x11B6, 0x3140),
+  (0x11B7, 0x3141),
+  (0x11B8, 0x3142),
+  (0x11B9, 0x3144),
+  (0x11BA, 0x3145),
+  (0x11BB, 0x3146),
+  (0x11BC, 0x3147),
+  (0x11BD, 0x3148),
+  (0x11BE, 0x314A),
+  (0x11BF, 0x314B),
+  (0x11C0, 0x314C),
+  (0x11C1, 0x314D),
+  (0x11C2, 0x314E),
+  (0x11C6, 0x3166),
+  (0x11C7, 0x3167),
+  (0x11C8, 0x3168),
+  (0x11CC, 0x3169),
+  (0x11CE, 0x316A),
+  (0x11D3, 0x316B),
+  (0x11D7, 0x316C),
+  (0x11D9, 0x316D),
+  (0x11DC, 0x316E),
+  (0x11DD, 0x316F),
+  (0x11DF, 0x3170),
+  (0x11E2, 0x3171),
+  (0x11E6, 0x3178),
+  (0x11E7, 0x317A),
+  (0x11E8, 0x317C),
+  (0x11EA, 0x317D),
+  (0x11EB, 0x317F),
+  (0x11EE, 0x3180),
+  (0x11F0, 0x3181),
+  (0x11F1, 0x3182),
+  (0x11F2, 0x3183),
+  (0x11F4, 0x3184),
+  (0x11F9, 0x3186),
+  (0x11FF, 0x3165),
+  (0xA964, 0x313A),
+  (0xA966, 0x316A),
+  (0xA968, 0x313B),
+  (0xA969, 0x313C),
+  (0xA96C, 0x313D),
+  (0xA971, 0x316F),
+  (0xD7CD, 0x3138),
+  (0xD7E3, 0x3173),
+  (0xD7E6, 0x3143),
+  (0xD7E7, 0x3175),
+  (0xD7E8, 0x3176),
+  (0xD7EF, 0x317E),
+  (0xD7F9, 0x3149),
+];
+
+// 초성 자리 자모 (채움 문자 포함)
+pub(crate) fn is_leading_jamo(ch: char) -> bool {
+  matches!(ch as u32, 0x1100..=0x115F | 0xA960..=0xA97C)
+}
+
+// 중성 자리 자모 (채움 문자 포함)
+pub(crate) fn is_vowel_jamo(ch: char) -> bool {
+  matches!(ch as u32, 0x1160..=0x11A7 | 0xD7B0..=0xD7C6)
+}
+
+// 종성 자리 자모
+pub(crate) fn is_trailing_jamo(ch: char) -> bool {
+  matches!(ch as u32, 0x11A8..=0x11FF | 0xD7CB..=0xD7FB)
+}
+
+// 조합형 자모를 호환형 자모로 변환 (ᄫ → ㅸ, ᆞ → ㆍ, ᇰ → ㆁ), 대응하는 호환형이 없으면 `None`
+pub fn conjoining_to_compatibility(ch: char) -> Option<char> {
+  COMPATIBILITY_MAPPING
+    .binary_search_by_key(&(ch as u32), |&(conjoining, _)| conjoining)
+    .ok()
+    .and_then(|index| char::from_u32(COMPATIBILITY_MAPPING[index].1))
+}
+
+// 완성형이 없는 옛한글 음절 (초성+ 중성+ 종성*), 채움 문자로 시작하는 음절 포함
+// 종성 없는 완성형 음절 뒤에 조합형 종성이 붙은 음절(가+ᇰ)도 포함
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OldHangul<'a> {
+  text: &'a str,
+  syllable: Option<HangulLetter>,
+  vowel_start: usize,
+  trailing_start: usize,
+}
+
+impl<'a> OldHangul<'a> {
+  // 문자열 전체가 한 음절이면 파싱, 현대 한글 조합형 음절(ᄀ+ᅡ)도 받음
+  pub fn parse(text: &'a str) -> Option<Self> {
+    Self::parse_prefix(text).filter(|cluster| cluster.text.len() == text.len())
+  }
+
+  // 문자열 앞의 가장 긴 음절
+  pub(crate) fn parse_prefix(text: &'a str) -> Option<Self> {
+    let first = text.chars().next()?;
+    if let Some(syllable) = HangulLetter::parse_from_char(first) {
+      let trailing_start = first.len_utf8();
+      let len = take_jamo(text, trailing_start, is_trailing_jamo);
+      if syllable.jongseong.is_some() || len == trailing_start {
+        return None;
+      }
+
+      return Some(Self {
+        text: &text[..len],
+        syllable: Some(syllable),
+        vowel_start: trailing_start,
+        trailing_start,
+      });
+    }
+
+    let vowel_start = take_jamo(text, 0, is_leading_jamo);
+    let trailing_start = take_jamo(text, vowel_start, is_vowel_jamo);
+    if vowel_start == 0 || trailing_start == vowel_start {
+      return None;
+    }
+    let len = take_jamo(text, trailing_start, is_trailing_jamo);
+
+    Some(Self {
+      text: &text[..len],
+      syllable: None,
+      vowel_start,
+      trailing_start,
+    })
+  }
+
+  pub fn as_str(&self) -> &'a str {
+    self.text
+  }
+
+  // 초성 부분 (채움 문자 포함), 완성형 음절로 시작하면 그 초성의 조합형 자모
+  pub fn choseong(&self) -> &'a str {
+    match self.syllable {
+      Some(syllable) => modern_jamo(
+        MODERN_CHOSEONG,
+        syllable.choseong.conjoining_value(),
+        0x1100,
+      ),
+      None => &self.text[..self.vowel_start],
+    }
+  }
+
+  // 중성 부분 (채움 문자 포함), 완성형 음절로 시작하면 그 중성의 조합형 자모
+  pub fn jungseong(&self) -> &'a str {
+    match self.syllable {
+      Some(syllable) => modern_jamo(
+        MODERN_JUNGSEONG,
+        syllable.jungseong.conjoining_value(),
+        0x1161,
+      ),
+      None => &self.text[self.vowel_start..self.trailing_start],
+    }
+  }
+
+  pub fn jongseong(&self) -> &'a str {
+    &self.text[self.trailing_start..]
+  }
+
+  pub fn has_batchim(&self) -> bool {
+    self.trailing_start < self.text.len()
+  }
+}
+
+// 현대 자모 문자열 `table`에서 `jamo`에 해당하는 부분
+fn modern_jamo(table: &'static str, jamo: char, base: u32) -> &'static str {
+  let start = (jamo as u32 - base) as usize * 3;
+  &table[start..start + 3]
+}
+
+// `start`부터 `is_jamo`를 만족하는 자모가 이어지는 끝 위치, 최대 길이를 넘지 않음
+fn take_jamo(text: &str, start: usize, is_jamo: fn(char) -> bool) -> usize {
+  let mut end = start;
+
+  for ch in text[start..].chars() {
+    if !is_jamo(ch) || end + ch.len_utf8() > MAX_CLUSTER_LEN {
+      break;
+    }
+    end += ch.len_utf8();
+  }
+
+  end
+}
+
+// 채움 문자를 뺀 자모
+#[cfg(feature = "alloc")]
+pub(crate) fn jamo(part: &str) -> impl Iterator<Item = char> + '_ {
+  part
+    .chars()
+    .filter(|&ch| ch != CHOSEONG_FILLER && ch != JUNGSEONG_FILLER)
+}
+
+// KS X 1026-1에 따라 불완전한 조합형 음절에 채움 문자를 넣어 표준 음절(초성+ 중성+ 종성*)로 만듦
+// 초성만 있으면 중성 채움 문자, 중성으로 시작하면 초성 채움 문자, 종성만 있으면 둘 다 넣음
+#[cfg(feature = "alloc")]
+pub fn fill_incomplete_syllables(text: &str) -> String {
+  #[derive(Clone, Copy, PartialEq, Eq)]
+  enum Position {
+    Other,
+    Leading,
+    Vowel,
+    Trailing,
+  }
+
+  let mut result = String::with_capacity(text.len());
+  let mut previous = Position::Other;
+
+  for ch in text.chars() {
+    let position = if is_leading_jamo(ch) {
+      Position::Leading
+    } else if is_vowel_jamo(ch) {
+      if !matches!(previous, Position::Leading | Position::Vowel) {
+        result.push(CHOSEONG_FILLER);
+      }
+      Position::Vowel
+    } else if is_trailing_jamo(ch) {
+      // 초성 뒤라면 아래에서 중성 채움 문자를 넣음
+      if previous == Position::Other {
+        result.push(CHOSEONG_FILLER);
+        result.push(JUNGSEONG_FILLER);
+      }
+      Position::Trailing
+    } else if NFC::is_complete_hangul(ch as u32) {
+      // 종성이 없는 완성형 음절 뒤에만 종성이 이어질 수 있음 (가+ᇰ), 각+ᆺ의 ᆺ은 따로 채움
+      match (ch as u32 - 0xAC00) % 28 {
+        0 => Position::Vowel,
+        _ => Position::Other,
+      }
+    } else {
+      Position::Other
+    };
+
+    if previous == Position::Leading && !matches!(position, Position::Leading | Position::Vowel) {
+      result.push(JUNGSEONG_FILLER);
+    }
+
+    result.push(ch);
+    previous = position;
+  }
+
+  if previous == Position::Leading {
+    result.push(JUNGSEONG_FILLER);
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_mapping_is_sorted() {
+    assert!(COMPATIBILITY_MAPPING
+      .windows(2)
+      .all(|pair| pair[0].0 < pair[1].0));
+  }
+
+  #[test]
+  fn test_conjoining_to_compatibility() {
+    assert_eq!(conjoining_to_compatibility('ᄀ'), Some('ㄱ'));
+    assert_eq!(conjoining_to_compatibility('ᆨ'), Some('ㄱ'));
+    assert_eq!(conjoining_to_compatibility('ᄫ'), Some('ㅸ'));
+    assert_eq!(conjoining_to_compatibility('ᅀ'), Some('ㅿ'));
+    assert_eq!(conjoining_to_compatibility('ᆞ'), Some('ㆍ'));
+    assert_eq!(conjoining_to_compatibility('ᇰ'), Some('ㆁ'));
+    assert_eq!(conjoining_to_compatibility('\u{A964}'), Some('ㄺ'));
+    assert_eq!(conjoining_to_compatibility('\u{1160}'), None);
+    assert_eq!(conjoining_to_compatibility('\u{A960}'), None);
+    assert_eq!(conjoining_to_compatibility('ㄱ'), None);
+  }
+
+  #[test]
+  fn test_parse() {
+    // ᄒᆞᆫ (훈민정음의 "한")
+    let cluster = OldHangul::parse("\u{1112}\u{119E}\u{11AB}").unwrap();
+    assert_eq!(cluster.choseong(), "\u{1112}");
+    assert_eq!(cluster.jungseong(), "\u{119E}");
+    assert_eq!(cluster.jongseong(), "\u{11AB}");
+    assert!(cluster.has_batchim());
+
+    // ᄭᅵ (어두 자음군)
+    let cluster = OldHangul::parse("\u{112D}\u{1175}").unwrap();
+    assert_eq!(cluster.choseong(), "\u{112D}");
+    assert!(!cluster.has_batchim());
+
+    let cluster = OldHangul::parse("\u{1109}\u{1100}\u{1161}\u{11AF}\u{11A8}").unwrap();
+    assert_eq!(cluster.choseong(), "\u{1109}\u{1100}");
+    assert_eq!(cluster.jongseong(), "\u{11AF}\u{11A8}");
+
+    // 가ᇰ (완성형 음절 뒤의 옛한글 종성)
+    let cluster = OldHangul::parse("가\u{11F0}").unwrap();
+    assert_eq!(cluster.choseong(), "\u{1100}");
+    assert_eq!(cluster.jungseong(), "\u{1161}");
+    assert_eq!(cluster.jongseong(), "\u{11F0}");
+    assert!(OldHangul::parse("가").is_none());
+    assert!(OldHangul::parse("각\u{11F0}").is_none());
+
+    assert!(OldHangul::parse("\u{1100}").is_none());
+    assert!(OldHangul::parse("\u{1161}").is_none());
+    assert!(OldHangul::parse("\u{1100}\u{1161}a").is_none());
+    assert!(OldHangul::parse("").is_none());
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_jamo_skips_fillers() {
+    let cluster = OldHangul::parse("\u{115F}\u{119E}").unwrap();
+    assert_eq!(jamo(cluster.choseong()).count(), 0);
+    assert!(jamo(cluster.jungseong()).eq(['\u{119E}']));
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn test_fill_incomplete_syllables() {
+    assert_eq!(fill_incomplete_syllables("\u{1100}"), "\u{1100}\u{1160}");
+    assert_eq!(fill_incomplete_syllables("\u{1161}"), "\u{115F}\u{1161}");
+    assert_eq!(
+      fill_incomplete_syllables("\u{11A8}"),
+      "\u{115F}\u{1160}\u{11A8}"
+    );
+    assert_eq!(
+      fill_incomplete_syllables("\u{1100}\u{11A8}"),
+      "\u{1100}\u{1160}\u{11A8}"
+    );
+    assert_eq!(
+      fill_incomplete_syllables("a\u{1100}b"),
+      "a\u{1100}\u{1160}b"
+    );
+
+    // 완성된 음절은 그대로
+    for text in ["\u{1112}\u{119E}\u{11AB}", "가\u{11F0}", "한글 abc"] {
+      assert_eq!(fill_incomplete_syllables(text), text);
+    }
+    assert_eq!(
+      fill_incomplete_syllables("각\u{1161}"),
+      "각\u{115F}\u{1161}"
+    );
+    // 종성이 있는 완성형 음절은 종성을 더 받지 않음
+    assert_eq!(
+      fill_incomplete_syllables("각\u{11BA}"),
+      "각\u{115F}\u{1160}\u{11BA}"
+    );
+  }
+}
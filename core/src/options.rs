@@ -0,0 +1,421 @@
+use alloc::string::String;
+
+use crate::choseong::Choseong;
+use crate::enclosed::expand_enclosed;
+use crate::hangul::CharUnit;
+use crate::hangul_letter::HangulLetter;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+use crate::old_hangul::{self, conjoining_to_compatibility, OldHangul};
+
+// 자모 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JamoForm {
+  // 호환형 자모 (U+3131–318E, "ㄱ")
+  #[default]
+  Compatibility,
+  // 조합형 자모 (U+1100–11FF, "ᄀ")
+  Conjoining,
+}
+
+// 한글이 아닌 문자 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NonHangul {
+  // 그대로 출력
+  #[default]
+  Keep,
+  // 출력하지 않음
+  Remove,
+  // 글자마다 지정한 문자로 바꿔 출력
+  Replace(char),
+}
+
+impl NonHangul {
+  // 한글이 아닌 글자 하나를 처리, 출력한 내용이 있으면 true
+  pub(crate) fn push(self, text: &str, keep_whitespace: bool, result: &mut String) -> bool {
+    match self {
+      NonHangul::Keep => result.push_str(text),
+      _ if keep_whitespace && text.chars().all(char::is_whitespace) => result.push_str(text),
+      NonHangul::Remove => return false,
+      NonHangul::Replace(replacement) => result.push(replacement),
+    }
+
+    true
+  }
+}
+
+// `Hangul::disassemble_with` 출력 형식, 기본값은 `Hangul::disassemble`과 같음
+// 호환형 자모, 겹받침 분리(ㄺ → ㄹㄱ), 이중 모음 유지(ㅘ), 구분자 없음, 한글이 아닌 문자 유지
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisassembleOptions {
+  pub(crate) jamo_form: JamoForm,
+  pub(crate) split_complex_jongseong: bool,
+  pub(crate) split_complex_jungseong: bool,
+  pub(crate) separator: Option<String>,
+  pub(crate) non_hangul: NonHangul,
+  pub(crate) expand_enclosed: bool,
+}
+
+impl Default for DisassembleOptions {
+  fn default() -> Self {
+    Self {
+      jamo_form: JamoForm::Compatibility,
+      split_complex_jongseong: true,
+      split_complex_jungseong: false,
+      separator: None,
+      non_hangul: NonHangul::Keep,
+      expand_enclosed: false,
+    }
+  }
+}
+
+impl DisassembleOptions {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn jamo_form(mut self, jamo_form: JamoForm) -> Self {
+    self.jamo_form = jamo_form;
+    self
+  }
+
+  // 겹받침을 두 자음으로 분리 (ㄺ → ㄹㄱ)
+  pub fn split_complex_jongseong(mut self, split: bool) -> Self {
+    self.split_complex_jongseong = split;
+    self
+  }
+
+  // 이중 모음을 두 모음으로 분리 (ㅘ → ㅗㅏ)
+  pub fn split_complex_jungseong(mut self, split: bool) -> Self {
+    self.split_complex_jungseong = split;
+    self
+  }
+
+  // 연속된 한글 음절 사이에 넣을 문자열
+  pub fn separator(mut self, separator: impl Into<String>) -> Self {
+    self.separator = Some(separator.into());
+    self
+  }
+
+  pub fn non_hangul(mut self, non_hangul: NonHangul) -> Self {
+    self.non_hangul = non_hangul;
+    self
+  }
+
+  // 괄호·원문자 한글을 한글 음절처럼 분해 (㉠ → ㄱ, ㈎ → ㄱㅏ, ㉼ → ㅊㅏㅁㄱㅗ)
+  pub fn expand_enclosed(mut self, expand: bool) -> Self {
+    self.expand_enclosed = expand;
+    self
+  }
+
+  // 한 음절의 자모를 순서대로 `push`에 전달
+  pub(crate) fn push_jamo(&self, letter: &HangulLetter, mut push: impl FnMut(char)) {
+    push(match self.jamo_form {
+      JamoForm::Compatibility => letter.choseong.compatibility_value(),
+      JamoForm::Conjoining => letter.choseong.conjoining_value(),
+    });
+    self.push_jungseong(letter.jungseong, &mut push);
+    if let Some(jongseong) = letter.jongseong {
+      self.push_jongseong(jongseong, &mut push);
+    }
+  }
+
+  // 현대 한글 음절이나 옛한글 음절의 자모를 `push`에 전달, 음절이 아니면 false
+  // 옛한글 음절의 채움 문자는 건너뛰고, 호환형 자모가 없는 옛한글 자모는 조합형 그대로 출력
+  pub(crate) fn push_unit_jamo(&self, unit: &CharUnit, mut push: impl FnMut(char)) -> bool {
+    if let Some(letter) = unit.hangul() {
+      self.push_jamo(&letter, push);
+      return true;
+    }
+
+    if let Some(text) = self.enclosed_text(unit) {
+      for ch in text.chars() {
+        match HangulLetter::parse_from_char(ch) {
+          Some(letter) => self.push_jamo(&letter, &mut push),
+          None => push(self.enclosed_consonant(ch)),
+        }
+      }
+      return true;
+    }
+
+    let Some(cluster) = unit.old_hangul() else {
+      return false;
+    };
+
+    for ch in old_hangul::jamo(cluster.choseong()) {
+      push(self.old_jamo(ch));
+    }
+    for ch in old_hangul::jamo(cluster.jungseong()) {
+      match Jungseong::try_new(ch as u32) {
+        Some(jungseong) => self.push_jungseong(jungseong, &mut push),
+        None => push(self.old_jamo(ch)),
+      }
+    }
+    for ch in old_hangul::jamo(cluster.jongseong()) {
+      match Jongseong::try_new(ch as u32) {
+        Some(jongseong) => self.push_jongseong(jongseong, &mut push),
+        None => push(self.old_jamo(ch)),
+      }
+    }
+
+    true
+  }
+
+  fn push_jungseong(&self, jungseong: Jungseong, push: &mut impl FnMut(char)) {
+    let jungseong = match jungseong.split() {
+      (first, Some(second)) if self.split_complex_jungseong => [Some(first), Some(second)],
+      _ => [Some(jungseong), None],
+    };
+    for jungseong in jungseong.into_iter().flatten() {
+      push(match self.jamo_form {
+        JamoForm::Compatibility => jungseong.compatibility_value(),
+        JamoForm::Conjoining => jungseong.conjoining_value(),
+      });
+    }
+  }
+
+  fn push_jongseong(&self, jongseong: Jongseong, push: &mut impl FnMut(char)) {
+    let jongseong = match jongseong.split() {
+      (first, Some(second)) if self.split_complex_jongseong => [Some(first), Some(second)],
+      _ => [Some(jongseong), None],
+    };
+    for jongseong in jongseong.into_iter().flatten() {
+      push(match self.jamo_form {
+        JamoForm::Compatibility => jongseong.compatibility_value(),
+        JamoForm::Conjoining => jongseong.conjoining_value(),
+      });
+    }
+  }
+
+  fn enclosed_text(&self, unit: &CharUnit) -> Option<&'static str> {
+    if !self.expand_enclosed {
+      return None;
+    }
+    unit.as_str().chars().next().and_then(expand_enclosed)
+  }
+
+  // 괄호·원문자 자음은 호환형이므로 조합형 출력이면 초성으로 바꿈
+  fn enclosed_consonant(&self, ch: char) -> char {
+    match (self.jamo_form, Choseong::try_from(ch)) {
+      (JamoForm::Conjoining, Ok(choseong)) => choseong.conjoining_value(),
+      _ => ch,
+    }
+  }
+
+  fn old_jamo(&self, ch: char) -> char {
+    match self.jamo_form {
+      JamoForm::Compatibility => conjoining_to_compatibility(ch).unwrap_or(ch),
+      JamoForm::Conjoining => ch,
+    }
+  }
+}
+
+// 초성/중성/종성 추출 옵션, 기본값은 `Hangul::get_choseong`과 같음
+// 호환형 자모, 한글이 아닌 문자 유지, 된소리 유지, 종성이 없는 음절은 출력하지 않음
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ExtractOptions {
+  pub(crate) jamo_form: JamoForm,
+  pub(crate) non_hangul: NonHangul,
+  pub(crate) keep_whitespace: bool,
+  pub(crate) fold_double_consonants: bool,
+  pub(crate) missing_jongseong: Option<char>,
+}
+
+impl ExtractOptions {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn jamo_form(mut self, jamo_form: JamoForm) -> Self {
+    self.jamo_form = jamo_form;
+    self
+  }
+
+  pub fn non_hangul(mut self, non_hangul: NonHangul) -> Self {
+    self.non_hangul = non_hangul;
+    self
+  }
+
+  // 한글이 아닌 문자를 지우거나 바꿀 때도 공백은 단어 구분자로 유지
+  pub fn keep_whitespace(mut self, keep: bool) -> Self {
+    self.keep_whitespace = keep;
+    self
+  }
+
+  // 된소리를 예사소리로 바꿈 (ㄲ → ㄱ, ㅆ → ㅅ)
+  pub fn fold_double_consonants(mut self, fold: bool) -> Self {
+    self.fold_double_consonants = fold;
+    self
+  }
+
+  // 종성 추출 시 종성이 없는 음절 자리에 넣을 문자
+  pub fn missing_jongseong(mut self, placeholder: char) -> Self {
+    self.missing_jongseong = Some(placeholder);
+    self
+  }
+
+  pub(crate) fn push_choseong(&self, letter: &HangulLetter, result: &mut String) {
+    let choseong = match self.fold_double_consonants {
+      true => Choseong::new(fold_double_consonant(
+        letter.choseong.compatibility_unicode(),
+      )),
+      false => letter.choseong,
+    };
+
+    result.push(match self.jamo_form {
+      JamoForm::Compatibility => choseong.compatibility_value(),
+      JamoForm::Conjoining => choseong.conjoining_value(),
+    });
+  }
+
+  pub(crate) fn push_jungseong(&self, letter: &HangulLetter, result: &mut String) {
+    result.push(match self.jamo_form {
+      JamoForm::Compatibility => letter.jungseong.compatibility_value(),
+      JamoForm::Conjoining => letter.jungseong.conjoining_value(),
+    });
+  }
+
+  pub(crate) fn push_jongseong(&self, letter: &HangulLetter, result: &mut String) {
+    let Some(jongseong) = letter.jongseong else {
+      if let Some(placeholder) = self.missing_jongseong {
+        result.push(placeholder);
+      }
+      return;
+    };
+
+    let jongseong = match self.fold_double_consonants {
+      true => Jongseong::new(fold_double_consonant(jongseong.compatibility_unicode())),
+      false => jongseong,
+    };
+
+    result.push(match self.jamo_form {
+      JamoForm::Compatibility => jongseong.compatibility_value(),
+      JamoForm::Conjoining => jongseong.conjoining_value(),
+    });
+  }
+}
+
+// 옛한글 음절의 초성/중성/종성 부분을 추출, 호환형 자모가 없으면 조합형 그대로 출력
+impl ExtractOptions {
+  pub(crate) fn push_old_choseong(&self, cluster: &OldHangul, result: &mut String) {
+    self.push_old_part(cluster.choseong(), result);
+  }
+
+  pub(crate) fn push_old_jungseong(&self, cluster: &OldHangul, result: &mut String) {
+    self.push_old_part(cluster.jungseong(), result);
+  }
+
+  pub(crate) fn push_old_jongseong(&self, cluster: &OldHangul, result: &mut String) {
+    match (cluster.has_batchim(), self.missing_jongseong) {
+      (true, _) => self.push_old_part(cluster.jongseong(), result),
+      (false, Some(placeholder)) => result.push(placeholder),
+      (false, None) => {}
+    }
+  }
+
+  fn push_old_part(&self, part: &str, result: &mut String) {
+    for ch in old_hangul::jamo(part) {
+      let ch = match self.fold_double_consonants {
+        true => fold_conjoining_double_consonant(ch),
+        false => ch,
+      };
+
+      result.push(match self.jamo_form {
+        JamoForm::Compatibility => conjoining_to_compatibility(ch).unwrap_or(ch),
+        JamoForm::Conjoining => ch,
+      });
+    }
+  }
+}
+
+// 조합형 된소리 자음을 예사소리로 (ᄁ → ᄀ, ᆩ → ᆨ), 된소리가 아니면 그대로
+fn fold_conjoining_double_consonant(ch: char) -> char {
+  match ch {
+    '\u{1101}' => '\u{1100}',
+    '\u{1104}' => '\u{1103}',
+    '\u{1108}' => '\u{1107}',
+    '\u{110A}' => '\u{1109}',
+    '\u{110D}' => '\u{110C}',
+    '\u{11A9}' => '\u{11A8}',
+    '\u{11BB}' => '\u{11BA}',
+    _ => ch,
+  }
+}
+
+// 호환형 된소리 자음을 예사소리로 (ㄲ → ㄱ), 된소리가 아니면 그대로
+fn fold_double_consonant(unicode: u32) -> u32 {
+  match unicode {
+    0x3132 => 0x3131,
+    0x3138 => 0x3137,
+    0x3143 => 0x3142,
+    0x3146 => 0x3145,
+    0x3149 => 0x3148,
+    _ => unicode,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use alloc::vec::Vec;
+
+  fn jamo(options: &DisassembleOptions, text: &str) -> Vec<char> {
+    let mut result = Vec::new();
+    options.push_jamo(&HangulLetter::parse(text).unwrap(), |ch| result.push(ch));
+    result
+  }
+
+  #[test]
+  fn test_default_options() {
+    let options = DisassembleOptions::new();
+    assert_eq!(options, DisassembleOptions::default());
+    assert_eq!(jamo(&options, "닭"), ['ㄷ', 'ㅏ', 'ㄹ', 'ㄱ']);
+    assert_eq!(jamo(&options, "왜"), ['ㅇ', 'ㅙ']);
+  }
+
+  #[test]
+  fn test_builder() {
+    let options = DisassembleOptions::new()
+      .jamo_form(JamoForm::Conjoining)
+      .split_complex_jongseong(false)
+      .split_complex_jungseong(true);
+
+    assert_eq!(
+      jamo(&options, "괇"),
+      ['\u{1100}', '\u{1169}', '\u{1161}', '\u{11B2}']
+    );
+    assert_eq!(
+      jamo(&options.split_complex_jongseong(true), "괇"),
+      ['\u{1100}', '\u{1169}', '\u{1161}', '\u{11AF}', '\u{11B8}']
+    );
+  }
+
+  #[test]
+  fn test_non_hangul() {
+    let mut result = String::new();
+    assert!(NonHangul::Keep.push("a", false, &mut result));
+    assert!(!NonHangul::Remove.push("b", false, &mut result));
+    assert!(NonHangul::Remove.push(" ", true, &mut result));
+    assert!(NonHangul::Replace('*').push("c", true, &mut result));
+    assert_eq!(result, "a *");
+  }
+
+  #[test]
+  fn test_extract_options() {
+    let letter = HangulLetter::parse("꺾").unwrap();
+    let mut result = String::new();
+
+    let options = ExtractOptions::new().fold_double_consonants(true);
+    options.push_choseong(&letter, &mut result);
+    options.push_jongseong(&letter, &mut result);
+
+    let options = ExtractOptions::new().jamo_form(JamoForm::Conjoining);
+    options.push_choseong(&letter, &mut result);
+    options.push_jungseong(&letter, &mut result);
+
+    let options = ExtractOptions::new().missing_jongseong('_');
+    options.push_jongseong(&HangulLetter::parse("가").unwrap(), &mut result);
+
+    assert_eq!(result, "ㄱㄱ\u{1101}\u{1165}_");
+  }
+}
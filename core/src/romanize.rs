@@ -0,0 +1,207 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hangul::next_unit;
+use crate::hangul_letter::HangulLetter;
+use crate::jongseong::Jongseong;
+
+// 국어의 로마자 표기법 초성 표기 (초성 인덱스 순)
+const INITIALS: [&str; 19] = [
+  "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+  "h",
+];
+
+// 중성 표기 (중성 인덱스 순)
+const VOWELS: [&str; 21] = [
+  "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+  "wi", "yu", "eu", "ui", "i",
+];
+
+// 종성 대표음 표기 (종성 인덱스 1–27 순)
+const FINALS: [&str; 27] = [
+  "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p", "t",
+  "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+// 한글 음절을 국어의 로마자 표기법으로 변환
+// 연음, 비음화, 유음화, 구개음화, 거센소리되기를 반영하며 된소리되기는 표기하지 않음
+// 완성형 음절이 아닌 문자는 그대로 유지
+pub fn romanize(text: &str) -> String {
+  let mut units: Vec<(&str, Option<HangulLetter>)> = Vec::new();
+  let mut start = 0;
+
+  while let Some((len, letter)) = next_unit(&text[start..]) {
+    units.push((&text[start..start + len], letter));
+    start += len;
+  }
+
+  let mut result = String::with_capacity(text.len() * 2);
+  let mut next_initial: Option<&str> = None;
+
+  for (index, &(unit, letter)) in units.iter().enumerate() {
+    let Some(letter) = letter else {
+      result.push_str(unit);
+      next_initial = None;
+      continue;
+    };
+
+    let initial = next_initial
+      .take()
+      .unwrap_or(INITIALS[letter.choseong.index() as usize]);
+    result.push_str(initial);
+    result.push_str(VOWELS[letter.jungseong.index() as usize]);
+
+    let Some(jongseong) = letter.jongseong else {
+      continue;
+    };
+
+    match units.get(index + 1).and_then(|&(_, next)| next) {
+      Some(next) => {
+        let (final_sound, initial_sound) = boundary(jongseong, next);
+        result.push_str(final_sound);
+        next_initial = Some(initial_sound);
+      }
+      None => result.push_str(final_roman(jongseong)),
+    }
+  }
+
+  result
+}
+
+fn final_roman(jongseong: Jongseong) -> &'static str {
+  FINALS[jongseong.index() as usize - 1]
+}
+
+// 받침과 다음 음절 초성이 만날 때의 (받침 표기, 다음 초성 표기)
+fn boundary(jongseong: Jongseong, next: HangulLetter) -> (&'static str, &'static str) {
+  let final_jamo = jongseong.compatibility_value();
+  let initial_jamo = next.choseong.compatibility_value();
+  let before_i = next.jungseong.compatibility_value() == 'ㅣ';
+  let final_sound = final_roman(jongseong);
+
+  match (final_jamo, initial_jamo) {
+    // 연음
+    ('ㅇ', 'ㅇ') => ("ng", ""),
+    ('ㅎ', 'ㅇ') => ("", ""),
+    ('ㄶ', 'ㅇ') => ("", "n"),
+    ('ㅀ', 'ㅇ') => ("", "r"),
+    ('ㄷ', 'ㅇ') if before_i => ("", "j"),
+    ('ㅌ', 'ㅇ') if before_i => ("", "ch"),
+    (_, 'ㅇ') => match jongseong.split() {
+      (first, Some(second)) => (final_roman(first), initial_roman(second)),
+      (single, None) => ("", initial_roman(single)),
+    },
+
+    // 거센소리되기
+    ('ㄱ' | 'ㄲ' | 'ㅋ', 'ㅎ') => ("", "k"),
+    ('ㄺ', 'ㅎ') => ("l", "k"),
+    ('ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ', 'ㅎ') => {
+      ("", if before_i { "ch" } else { "t" })
+    }
+    ('ㄵ', 'ㅎ') => ("n", "ch"),
+    ('ㅂ' | 'ㅍ', 'ㅎ') => ("", "p"),
+    ('ㄼ', 'ㅎ') => ("l", "p"),
+    ('ㅎ', 'ㄱ') => ("", "k"),
+    ('ㅎ', 'ㄷ') => ("", "t"),
+    ('ㅎ', 'ㅈ') => ("", "ch"),
+    ('ㅎ', 'ㅅ') => ("", "ss"),
+    ('ㅎ' | 'ㄶ', 'ㄴ') => ("n", "n"),
+    ('ㄶ', 'ㄱ') => ("n", "k"),
+    ('ㄶ', 'ㄷ') => ("n", "t"),
+    ('ㄶ', 'ㅈ') => ("n", "ch"),
+    ('ㅀ', 'ㄱ') => ("l", "k"),
+    ('ㅀ', 'ㄷ') => ("l", "t"),
+    ('ㅀ', 'ㅈ') => ("l", "ch"),
+    ('ㅀ', 'ㄴ') => ("l", "l"),
+
+    // 유음화·비음화
+    (_, 'ㄹ') => match final_sound {
+      "n" | "l" => ("l", "l"),
+      "m" | "ng" => (final_sound, "n"),
+      "k" => ("ng", "n"),
+      "p" => ("m", "n"),
+      _ => ("n", "n"),
+    },
+    (_, 'ㄴ' | 'ㅁ') => {
+      let initial = initial_roman_of(next);
+      match final_sound {
+        "l" if initial_jamo == 'ㄴ' => ("l", "l"),
+        "k" => ("ng", initial),
+        "t" => ("n", initial),
+        "p" => ("m", initial),
+        _ => (final_sound, initial),
+      }
+    }
+
+    _ => (final_sound, initial_roman_of(next)),
+  }
+}
+
+fn initial_roman_of(letter: HangulLetter) -> &'static str {
+  INITIALS[letter.choseong.index() as usize]
+}
+
+// 받침이 다음 음절 초성으로 넘어갈 때의 표기
+fn initial_roman(jongseong: Jongseong) -> &'static str {
+  match jongseong.compatibility_value() {
+    'ㄱ' => "g",
+    'ㄲ' => "kk",
+    'ㄴ' => "n",
+    'ㄷ' => "d",
+    'ㄹ' => "r",
+    'ㅁ' => "m",
+    'ㅂ' => "b",
+    'ㅅ' => "s",
+    'ㅆ' => "ss",
+    'ㅈ' => "j",
+    'ㅊ' => "ch",
+    'ㅋ' => "k",
+    'ㅌ' => "t",
+    'ㅍ' => "p",
+    _ => "",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_romanize_basic() {
+    assert_eq!(romanize("안녕하세요"), "annyeonghaseyo");
+    assert_eq!(romanize("부산"), "busan");
+    assert_eq!(romanize("서울"), "seoul");
+    assert_eq!(romanize("희망"), "huimang");
+    assert_eq!(romanize("라디오"), "radio");
+  }
+
+  #[test]
+  fn test_romanize_linking() {
+    assert_eq!(romanize("한국어"), "hangugeo");
+    assert_eq!(romanize("닭이"), "dalgi");
+    assert_eq!(romanize("좋아"), "joa");
+    assert_eq!(romanize("많아"), "mana");
+    assert_eq!(romanize("없어"), "eopseo");
+  }
+
+  #[test]
+  fn test_romanize_sound_changes() {
+    assert_eq!(romanize("신라"), "silla");
+    assert_eq!(romanize("설날"), "seollal");
+    assert_eq!(romanize("종로"), "jongno");
+    assert_eq!(romanize("독립"), "dongnip");
+    assert_eq!(romanize("백마"), "baengma");
+    assert_eq!(romanize("같이"), "gachi");
+    assert_eq!(romanize("해돋이"), "haedoji");
+    assert_eq!(romanize("좋고"), "joko");
+    assert_eq!(romanize("놓다"), "nota");
+  }
+
+  #[test]
+  fn test_romanize_mixed() {
+    assert_eq!(romanize("Hello 한글!"), "Hello hangeul!");
+    assert_eq!(romanize("밖 에"), "bak e");
+    assert_eq!(romanize("ㄱ"), "ㄱ");
+    assert_eq!(romanize(""), "");
+  }
+}
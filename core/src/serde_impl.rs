@@ -0,0 +1,201 @@
+// serde 직렬화 형식
+// - `Choseong`, `Jungseong`, `Jongseong`: 호환형 자모 한 글자 문자열 (`"ㄱ"`), 역직렬화는 조합형 자모도 허용
+// - `HangulLetter`: `{ "cho": "ㄱ", "jung": "ㅏ", "jong": "ㄱ" }`, 종성이 없으면 `"jong": null`
+// - `NFD`: 조합형 자모 유니코드 배열 (`[4352, 4449, 4520]`), 종성이 없으면 마지막 값이 `null`
+// - `Hangul`: 원본 문자열 (`"안녕"`)
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::choseong::Choseong;
+use crate::jongseong::Jongseong;
+use crate::jungseong::Jungseong;
+use crate::nfd::NFD;
+
+macro_rules! impl_jamo_serde {
+  ($jamo:ident, $expecting:literal) => {
+    impl Serialize for $jamo {
+      fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buffer = [0; 4];
+        serializer.serialize_str(self.compatibility_value().encode_utf8(&mut buffer))
+      }
+    }
+
+    impl<'de> Deserialize<'de> for $jamo {
+      fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JamoVisitor;
+
+        impl Visitor<'_> for JamoVisitor {
+          type Value = $jamo;
+
+          fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str($expecting)
+          }
+
+          fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            let mut chars = value.chars();
+
+            match (chars.next(), chars.next()) {
+              (Some(ch), None) => $jamo::try_new(ch as u32)
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self)),
+              _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
+            }
+          }
+        }
+
+        deserializer.deserialize_str(JamoVisitor)
+      }
+    }
+  };
+}
+
+impl_jamo_serde!(Choseong, "a single choseong jamo");
+impl_jamo_serde!(Jungseong, "a single jungseong jamo");
+impl_jamo_serde!(Jongseong, "a single jongseong jamo");
+
+impl Serialize for NFD {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    (self.0, self.1, self.2).serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for NFD {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let (cho, jung, jong) = <(u32, u32, Option<u32>)>::deserialize(deserializer)?;
+
+    if !Choseong::is_conjoining_choseong(cho) {
+      return Err(de::Error::invalid_value(
+        Unexpected::Unsigned(cho as u64),
+        &"a conjoining choseong code point",
+      ));
+    }
+
+    if !Jungseong::is_conjoining_jungseong(jung) {
+      return Err(de::Error::invalid_value(
+        Unexpected::Unsigned(jung as u64),
+        &"a conjoining jungseong code point",
+      ));
+    }
+
+    if let Some(jong) = jong.filter(|&jong| !Jongseong::is_conjoining_jongseong(jong)) {
+      return Err(de::Error::invalid_value(
+        Unexpected::Unsigned(jong as u64),
+        &"a conjoining jongseong code point",
+      ));
+    }
+
+    Ok(NFD(cho, jung, jong))
+  }
+}
+
+#[cfg(feature = "alloc")]
+mod hangul {
+  use core::fmt;
+
+  use serde::de::{self, Deserialize, Deserializer, Visitor};
+  use serde::ser::{Serialize, Serializer};
+
+  use crate::hangul::Hangul;
+
+  impl Serialize for Hangul {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.serialize_str(self.original())
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Hangul {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      struct HangulVisitor;
+
+      impl Visitor<'_> for HangulVisitor {
+        type Value = Hangul;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+          formatter.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+          Ok(Hangul::new(value))
+        }
+      }
+
+      deserializer.deserialize_str(HangulVisitor)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::hangul::Hangul;
+  use crate::hangul_letter::HangulLetter;
+
+  #[test]
+  fn test_jamo_json() {
+    assert_eq!(
+      serde_json::to_string(&Choseong::new(0x1100)).unwrap(),
+      r#""ㄱ""#
+    );
+    assert_eq!(
+      serde_json::to_string(&Jungseong::new(0x1174)).unwrap(),
+      r#""ㅢ""#
+    );
+    assert_eq!(
+      serde_json::to_string(&Jongseong::new(0x11B9)).unwrap(),
+      r#""ㅄ""#
+    );
+
+    let choseong: Choseong = serde_json::from_str(r#""ㄲ""#).unwrap();
+    assert_eq!(choseong, Choseong::new(0x3132));
+
+    let choseong: Choseong = serde_json::from_str("\"\u{1101}\"").unwrap();
+    assert_eq!(choseong, Choseong::new(0x3132));
+
+    assert!(serde_json::from_str::<Choseong>(r#""ㅏ""#).is_err());
+    assert!(serde_json::from_str::<Choseong>(r#""ㄱㄴ""#).is_err());
+    assert!(serde_json::from_str::<Jongseong>(r#""ㄸ""#).is_err());
+  }
+
+  #[test]
+  fn test_hangul_letter_json() {
+    let letter = HangulLetter::parse("각").unwrap();
+    let json = serde_json::to_string(&letter).unwrap();
+    assert_eq!(json, r#"{"cho":"ㄱ","jung":"ㅏ","jong":"ㄱ"}"#);
+    assert_eq!(serde_json::from_str::<HangulLetter>(&json).unwrap(), letter);
+
+    let letter = HangulLetter::parse("가").unwrap();
+    let json = serde_json::to_string(&letter).unwrap();
+    assert_eq!(json, r#"{"cho":"ㄱ","jung":"ㅏ","jong":null}"#);
+
+    let parsed: HangulLetter = serde_json::from_str(r#"{"cho":"ㄱ","jung":"ㅏ"}"#).unwrap();
+    assert_eq!(parsed, letter);
+  }
+
+  #[test]
+  fn test_nfd_json() {
+    let nfd = NFD::normalize('한' as u32).unwrap();
+    let json = serde_json::to_string(&nfd).unwrap();
+    assert_eq!(json, "[4370,4449,4523]");
+
+    let NFD(cho, jung, jong) = serde_json::from_str(&json).unwrap();
+    assert_eq!((cho, jung, jong), (0x1112, 0x1161, Some(0x11AB)));
+
+    let NFD(_, _, jong) = serde_json::from_str("[4352,4449,null]").unwrap();
+    assert_eq!(jong, None);
+
+    assert!(serde_json::from_str::<NFD>("[12593,4449,null]").is_err());
+  }
+
+  #[test]
+  fn test_hangul_json() {
+    let hangul = Hangul::new("안녕 Hello");
+    let json = serde_json::to_string(&hangul).unwrap();
+    assert_eq!(json, r#""안녕 Hello""#);
+
+    let parsed: Hangul = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.original(), "안녕 Hello");
+    assert_eq!(parsed.get_choseong(), "ㅇㄴ Hello");
+  }
+}
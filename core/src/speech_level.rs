@@ -0,0 +1,389 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::conjugate::conjugate;
+use crate::hangul_letter::HangulLetter;
+use crate::lemma::Lemmatizer;
+
+// 문장 끝 용언의 상대 높임법 등급
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpeechLevel {
+  // 해체 (먹어, 했어)
+  Hae,
+  // 해요체 (먹어요, 했어요)
+  Haeyo,
+  // 합쇼체 (먹습니다, 했습니다)
+  Hapsyo,
+}
+
+// 등급, 과거, 주체 높임(-시-), 의문형별 어미와 받침 있는·없는 체언 뒤의 이다 활용형
+struct Form {
+  level: SpeechLevel,
+  past: bool,
+  honorific: bool,
+  question: bool,
+  ending: &'static str,
+  copula: [&'static str; 2],
+}
+
+const fn form(
+  level: SpeechLevel,
+  past: bool,
+  honorific: bool,
+  question: bool,
+  ending: &'static str,
+  copula: [&'static str; 2],
+) -> Form {
+  Form {
+    level,
+    past,
+    honorific,
+    question,
+    ending,
+    copula,
+  }
+}
+
+// 해체와 해요체는 평서형과 의문형이 같음
+// 이다는 해체 이야/야, 해요체 이에요/예요로 용언과 다르게 활용함 (학생이야, 의사예요)
+const FORMS: [Form; 16] = [
+  form(
+    SpeechLevel::Hae,
+    false,
+    false,
+    false,
+    "-아/어",
+    ["이야", "야"],
+  ),
+  form(
+    SpeechLevel::Hae,
+    true,
+    false,
+    false,
+    "-았/었어",
+    ["이었어", "였어"],
+  ),
+  form(
+    SpeechLevel::Hae,
+    false,
+    true,
+    false,
+    "-(으)셔",
+    ["이셔", "이셔"],
+  ),
+  form(
+    SpeechLevel::Hae,
+    true,
+    true,
+    false,
+    "-(으)셨어",
+    ["이셨어", "이셨어"],
+  ),
+  form(
+    SpeechLevel::Haeyo,
+    false,
+    false,
+    false,
+    "-아/어요",
+    ["이에요", "예요"],
+  ),
+  form(
+    SpeechLevel::Haeyo,
+    true,
+    false,
+    false,
+    "-았/었어요",
+    ["이었어요", "였어요"],
+  ),
+  form(
+    SpeechLevel::Haeyo,
+    false,
+    true,
+    false,
+    "-(으)세요",
+    ["이세요", "이세요"],
+  ),
+  form(
+    SpeechLevel::Haeyo,
+    true,
+    true,
+    false,
+    "-(으)셨어요",
+    ["이셨어요", "이셨어요"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    false,
+    false,
+    false,
+    "-(스)ㅂ니다",
+    ["입니다", "입니다"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    true,
+    false,
+    false,
+    "-았/었습니다",
+    ["이었습니다", "였습니다"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    false,
+    true,
+    false,
+    "-(으)십니다",
+    ["이십니다", "이십니다"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    true,
+    true,
+    false,
+    "-(으)셨습니다",
+    ["이셨습니다", "이셨습니다"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    false,
+    false,
+    true,
+    "-(스)ㅂ니까",
+    ["입니까", "입니까"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    true,
+    false,
+    true,
+    "-았/었습니까",
+    ["이었습니까", "였습니까"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    false,
+    true,
+    true,
+    "-(으)십니까",
+    ["이십니까", "이십니까"],
+  ),
+  form(
+    SpeechLevel::Hapsyo,
+    true,
+    true,
+    true,
+    "-(으)셨습니까",
+    ["이셨습니까", "이셨습니까"],
+  ),
+];
+
+// 체언 + 이다 활용형과 모양이 같은 -이- 사동·피동사 어간 (보입니다 → 보이다, 보 + 입니다 아님)
+const I_VERB_STEMS: [&str; 20] = [
+  "보이", "먹이", "죽이", "줄이", "속이", "녹이", "높이", "끓이", "붙이", "쌓이", "놓이", "섞이",
+  "쓰이", "깎이", "꺾이", "묶이", "닦이", "볶이", "숙이", "절이",
+];
+
+// 용언의 기본형, 또는 서술격 조사 이다가 붙은 체언
+enum Predicate<'a> {
+  Verb(String),
+  Copula(&'a str),
+}
+
+// 문장 끝 용언의 등급 ("밥을 먹었어요." → 해요체), 알 수 없으면 None
+pub fn speech_level(sentence: &str) -> Option<SpeechLevel> {
+  let (_, predicate, _) = split_predicate(sentence);
+  parse(predicate).map(|(_, form)| form.level)
+}
+
+// 문장 끝 용언을 주어진 등급으로 바꿈 ("먹어" → "먹어요" → "먹습니다", "먹어요?" → "먹습니까?")
+// 나머지 부분과 문장 부호는 그대로 두고, 용언을 알 수 없으면 None
+pub fn to_speech_level(sentence: &str, level: SpeechLevel) -> Option<String> {
+  transform(sentence, |form| (level, form.honorific))
+}
+
+// 등급은 유지하고 주체 높임 -(으)시-를 넣음 ("먹어요" → "먹으세요", "갔습니다" → "가셨습니다")
+pub fn to_honorific(sentence: &str) -> Option<String> {
+  transform(sentence, |form| (form.level, true))
+}
+
+fn transform(sentence: &str, target: impl Fn(&Form) -> (SpeechLevel, bool)) -> Option<String> {
+  let (head, predicate, tail) = split_predicate(sentence);
+  let (predicate, current) = parse(predicate)?;
+
+  let (level, honorific) = target(current);
+  let question = level == SpeechLevel::Hapsyo && (current.question || tail.contains('?'));
+  let form = FORMS.iter().find(|form| {
+    form.level == level
+      && form.past == current.past
+      && form.honorific == honorific
+      && form.question == question
+  })?;
+
+  let mut result = String::from(head);
+  match predicate {
+    Predicate::Verb(lemma) => result.push_str(&conjugate(&lemma, form.ending).ok()?),
+    Predicate::Copula(noun) => {
+      result.push_str(noun);
+      result.push_str(form.copula[usize::from(!has_batchim(noun))]);
+    }
+  }
+  result.push_str(tail);
+  Some(result)
+}
+
+// 활용형에서 기본형과 어미 형식을 찾음, 이다를 먼저 보고 용언은 `lemmatize`가 그럴듯하다고 본 후보를 사용
+fn parse(predicate: &str) -> Option<(Predicate<'_>, &'static Form)> {
+  if let Some(copula) = parse_copula(predicate) {
+    return Some(copula);
+  }
+
+  let endings: Vec<&str> = FORMS.iter().map(|form| form.ending).collect();
+  let lemma = Lemmatizer::with_endings(&endings)
+    .lemmatize(predicate)
+    .into_iter()
+    .next()?;
+  let form = FORMS.iter().find(|form| form.ending == lemma.ending)?;
+
+  Some((Predicate::Verb(lemma.lemma), form))
+}
+
+// 체언 + 이다 ("학생이에요" → 학생, "의사예요" → 의사), 받침 유무에 맞는 활용형만 봄
+// `I_VERB_STEMS`의 어간은 용언으로 봄 (보여요 → 보이다)
+fn parse_copula(predicate: &str) -> Option<(Predicate<'_>, &'static Form)> {
+  FORMS.iter().find_map(|form| {
+    form.copula.iter().enumerate().find_map(|(index, suffix)| {
+      let noun = predicate.strip_suffix(suffix)?;
+      let fits = form.copula[0] == form.copula[1] || has_batchim(noun) == (index == 0);
+      let verb = I_VERB_STEMS
+        .iter()
+        .any(|stem| stem.strip_suffix('이') == Some(noun));
+      (!noun.is_empty() && fits && !verb).then_some((Predicate::Copula(noun), form))
+    })
+  })
+}
+
+fn has_batchim(word: &str) -> bool {
+  word
+    .chars()
+    .last()
+    .and_then(HangulLetter::parse_from_char)
+    .is_some_and(|letter| letter.has_batchim())
+}
+
+// 문장을 마지막 어절 앞부분, 마지막 어절의 한글 부분, 뒤따르는 문장 부호로 나눔
+fn split_predicate(sentence: &str) -> (&str, &str, &str) {
+  let body = sentence.trim_end_matches(|ch| HangulLetter::parse_from_char(ch).is_none());
+  let start = body
+    .char_indices()
+    .rev()
+    .take_while(|&(_, ch)| HangulLetter::parse_from_char(ch).is_some())
+    .last()
+    .map_or(body.len(), |(index, _)| index);
+
+  (&sentence[..start], &body[start..], &sentence[body.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn levels(sentence: &str) -> [String; 3] {
+    [SpeechLevel::Hae, SpeechLevel::Haeyo, SpeechLevel::Hapsyo]
+      .map(|level| to_speech_level(sentence, level).unwrap())
+  }
+
+  #[test]
+  fn test_to_speech_level() {
+    for sentence in ["먹어", "먹어요", "먹습니다"] {
+      assert_eq!(levels(sentence), ["먹어", "먹어요", "먹습니다"]);
+    }
+    for sentence in ["했어", "했어요", "했습니다"] {
+      assert_eq!(levels(sentence), ["했어", "했어요", "했습니다"]);
+    }
+    assert_eq!(levels("해요"), ["해", "해요", "합니다"]);
+    assert_eq!(levels("감사해요"), ["감사해", "감사해요", "감사합니다"]);
+    assert_eq!(levels("써요"), ["써", "써요", "씁니다"]);
+    assert_eq!(levels("예뻐요"), ["예뻐", "예뻐요", "예쁩니다"]);
+    assert_eq!(levels("그래요"), ["그래", "그래요", "그렇습니다"]);
+    assert_eq!(levels("가요"), ["가", "가요", "갑니다"]);
+    assert_eq!(levels("도와줘"), ["도와줘", "도와줘요", "도와줍니다"]);
+    assert_eq!(levels("추웠어요"), ["추웠어", "추웠어요", "추웠습니다"]);
+    assert_eq!(levels("가세요"), ["가셔", "가세요", "가십니다"]);
+    assert_eq!(
+      levels("읽으셨습니다"),
+      ["읽으셨어", "읽으셨어요", "읽으셨습니다"]
+    );
+  }
+
+  #[test]
+  fn test_to_speech_level_copula() {
+    for sentence in ["학생이야", "학생이에요", "학생입니다"] {
+      assert_eq!(levels(sentence), ["학생이야", "학생이에요", "학생입니다"]);
+    }
+    for sentence in ["의사야", "의사예요", "의사입니다"] {
+      assert_eq!(levels(sentence), ["의사야", "의사예요", "의사입니다"]);
+    }
+    assert_eq!(
+      levels("학생이었어요"),
+      ["학생이었어", "학생이었어요", "학생이었습니다"]
+    );
+    assert_eq!(
+      levels("의사였어"),
+      ["의사였어", "의사였어요", "의사였습니다"]
+    );
+    assert_eq!(
+      to_speech_level("저는 학생이에요?", SpeechLevel::Hapsyo).unwrap(),
+      "저는 학생입니까?"
+    );
+  }
+
+  #[test]
+  fn test_to_speech_level_i_verb() {
+    for sentence in ["보여", "보여요", "보입니다"] {
+      assert_eq!(levels(sentence), ["보여", "보여요", "보입니다"]);
+    }
+    assert_eq!(levels("먹입니다"), ["먹여", "먹여요", "먹입니다"]);
+    assert_eq!(levels("줄입니다"), ["줄여", "줄여요", "줄입니다"]);
+    assert_eq!(levels("죽였어요"), ["죽였어", "죽였어요", "죽였습니다"]);
+  }
+
+  #[test]
+  fn test_to_speech_level_sentence() {
+    assert_eq!(
+      to_speech_level("오늘 밥을 먹었어요.", SpeechLevel::Hae).unwrap(),
+      "오늘 밥을 먹었어."
+    );
+    assert_eq!(
+      to_speech_level("어디 가요?", SpeechLevel::Hapsyo).unwrap(),
+      "어디 갑니까?"
+    );
+    assert_eq!(
+      to_speech_level("뭐 먹었습니까?", SpeechLevel::Haeyo).unwrap(),
+      "뭐 먹었어요?"
+    );
+    assert_eq!(to_speech_level("hello", SpeechLevel::Haeyo), None);
+    assert_eq!(to_speech_level("먹고", SpeechLevel::Haeyo), None);
+  }
+
+  #[test]
+  fn test_to_honorific() {
+    assert_eq!(to_honorific("먹어요").unwrap(), "먹으세요");
+    assert_eq!(to_honorific("가요").unwrap(), "가세요");
+    assert_eq!(to_honorific("갔습니다.").unwrap(), "가셨습니다.");
+    assert_eq!(to_honorific("살아").unwrap(), "사셔");
+    assert_eq!(to_honorific("해요").unwrap(), "하세요");
+    assert_eq!(to_honorific("감사해요").unwrap(), "감사하세요");
+    assert_eq!(to_honorific("학생이에요").unwrap(), "학생이세요");
+    assert_eq!(to_honorific("의사였습니다").unwrap(), "의사이셨습니다");
+  }
+
+  #[test]
+  fn test_speech_level() {
+    assert_eq!(speech_level("먹었어"), Some(SpeechLevel::Hae));
+    assert_eq!(speech_level("좋아요!"), Some(SpeechLevel::Haeyo));
+    assert_eq!(speech_level("감사합니다."), Some(SpeechLevel::Hapsyo));
+    assert_eq!(speech_level("학생이야"), Some(SpeechLevel::Hae));
+    assert_eq!(speech_level("의사예요."), Some(SpeechLevel::Haeyo));
+    assert_eq!(speech_level("안녕"), None);
+  }
+}
@@ -0,0 +1,290 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::str;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, Write};
+
+use crate::hangul::{incomplete_tail_start, Hangul};
+
+#[derive(Debug)]
+pub enum StreamError {
+  InvalidUtf8,
+  IncompleteUtf8,
+}
+
+impl fmt::Display for StreamError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      StreamError::InvalidUtf8 => write!(f, "Stream contains invalid UTF-8"),
+      StreamError::IncompleteUtf8 => write!(f, "Stream ended in the middle of a UTF-8 sequence"),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
+
+#[cfg(feature = "std")]
+impl From<StreamError> for io::Error {
+  fn from(error: StreamError) -> Self {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+  }
+}
+
+#[derive(Clone, Copy)]
+enum StreamMode {
+  Disassemble,
+  Choseong,
+}
+
+// 청크 단위 입력을 받아 결과를 점진적으로 출력하는 스트림
+// 청크 경계에 걸친 UTF-8 바이트와 조합형(NFD) 음절은 다음 청크까지 보관
+pub struct HangulStream {
+  mode: StreamMode,
+  pending: String,
+  pending_bytes: Vec<u8>,
+}
+
+impl HangulStream {
+  pub fn disassemble() -> Self {
+    Self::new(StreamMode::Disassemble)
+  }
+
+  pub fn choseong() -> Self {
+    Self::new(StreamMode::Choseong)
+  }
+
+  fn new(mode: StreamMode) -> Self {
+    Self {
+      mode,
+      pending: String::new(),
+      pending_bytes: Vec::new(),
+    }
+  }
+
+  pub fn push_str(&mut self, chunk: &str, output: &mut String) {
+    self.pending.push_str(chunk);
+
+    let tail_start = incomplete_tail_start(&self.pending);
+    self.process(&self.pending[..tail_start], output);
+    self.pending.drain(..tail_start);
+  }
+
+  pub fn push_bytes(&mut self, chunk: &[u8], output: &mut String) -> Result<(), StreamError> {
+    let mut bytes = chunk;
+    let mut joined;
+
+    if !self.pending_bytes.is_empty() {
+      joined = mem::take(&mut self.pending_bytes);
+      joined.extend_from_slice(chunk);
+      bytes = &joined;
+    }
+
+    match str::from_utf8(bytes) {
+      Ok(text) => self.push_str(text, output),
+      Err(error) if error.error_len().is_none() => {
+        let (valid, rest) = bytes.split_at(error.valid_up_to());
+        self.push_str(unsafe { str::from_utf8_unchecked(valid) }, output);
+        self.pending_bytes = rest.to_vec();
+      }
+      Err(_) => return Err(StreamError::InvalidUtf8),
+    }
+
+    Ok(())
+  }
+
+  // 보관 중인 나머지 입력을 모두 출력
+  pub fn finish(&mut self, output: &mut String) -> Result<(), StreamError> {
+    let pending = mem::take(&mut self.pending);
+    self.process(&pending, output);
+
+    if !self.pending_bytes.is_empty() {
+      self.pending_bytes.clear();
+      return Err(StreamError::IncompleteUtf8);
+    }
+
+    Ok(())
+  }
+
+  fn process(&self, text: &str, output: &mut String) {
+    if text.is_empty() {
+      return;
+    }
+
+    let hangul = Hangul::new(text);
+    match self.mode {
+      StreamMode::Disassemble => output.push_str(&hangul.disassemble()),
+      StreamMode::Choseong => output.push_str(&hangul.get_choseong()),
+    }
+  }
+}
+
+// `reader`의 내용을 분해하여 `writer`에 순차적으로 기록
+#[cfg(feature = "std")]
+pub fn disassemble_stream<R: BufRead, W: Write>(reader: R, writer: W) -> io::Result<()> {
+  run_stream(HangulStream::disassemble(), reader, writer)
+}
+
+// `reader`의 내용에서 초성을 추출하여 `writer`에 순차적으로 기록
+#[cfg(feature = "std")]
+pub fn choseong_stream<R: BufRead, W: Write>(reader: R, writer: W) -> io::Result<()> {
+  run_stream(HangulStream::choseong(), reader, writer)
+}
+
+#[cfg(feature = "std")]
+fn run_stream<R: BufRead, W: Write>(
+  mut stream: HangulStream,
+  mut reader: R,
+  mut writer: W,
+) -> io::Result<()> {
+  let mut output = String::new();
+
+  loop {
+    let chunk = reader.fill_buf()?;
+    if chunk.is_empty() {
+      break;
+    }
+
+    let len = chunk.len();
+    stream.push_bytes(chunk, &mut output)?;
+    reader.consume(len);
+
+    writer.write_all(output.as_bytes())?;
+    output.clear();
+  }
+
+  stream.finish(&mut output)?;
+  writer.write_all(output.as_bytes())?;
+  writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn push_all(mut stream: HangulStream, chunks: &[&str]) -> String {
+    let mut output = String::new();
+    for chunk in chunks {
+      stream.push_str(chunk, &mut output);
+    }
+    stream.finish(&mut output).unwrap();
+    output
+  }
+
+  #[test]
+  fn test_push_str() {
+    let output = push_all(HangulStream::disassemble(), &["안녕", " 하세요", "!"]);
+    assert_eq!(output, "ㅇㅏㄴㄴㅕㅇ ㅎㅏㅅㅔㅇㅛ!");
+
+    let output = push_all(HangulStream::choseong(), &["Hello ", "안녕", "!"]);
+    assert_eq!(output, "Hello ㅇㄴ!");
+  }
+
+  #[test]
+  fn test_nfd_cluster_across_chunks() {
+    let chunks = [
+      "\u{1112}",
+      "\u{1161}",
+      "\u{11AB}\u{1100}\u{1173}",
+      "\u{11AF}",
+    ];
+    let output = push_all(HangulStream::disassemble(), &chunks);
+    assert_eq!(output, "ㅎㅏㄴㄱㅡㄹ");
+
+    let output = push_all(HangulStream::choseong(), &chunks);
+    assert_eq!(output, "ㅎㄱ");
+
+    let output = push_all(HangulStream::disassemble(), &["가", "\u{11A8}"]);
+    assert_eq!(output, "ㄱㅏㄱ");
+  }
+
+  #[test]
+  fn test_old_hangul_across_chunks() {
+    // 어두 자음군 ᄀᄀ+ᅡ, 완성형 음절 뒤의 옛한글 종성 가+ᇰ
+    for chunks in [
+      &["\u{1100}\u{1100}", "\u{1161}"][..],
+      &["\u{1100}", "\u{1100}", "\u{1161}"],
+      &["가", "\u{11F0}"],
+    ] {
+      let text: String = chunks.concat();
+      let output = push_all(HangulStream::disassemble(), chunks);
+      assert_eq!(output, Hangul::new(&text).disassemble());
+    }
+
+    let output = push_all(HangulStream::choseong(), &["\u{1100}\u{1100}", "\u{1161}"]);
+    assert_eq!(output, "ㄱㄱ");
+  }
+
+  #[test]
+  fn test_output_is_incremental() {
+    let mut stream = HangulStream::disassemble();
+    let mut output = String::new();
+
+    stream.push_str("안녕 ", &mut output);
+    assert_eq!(output, "ㅇㅏㄴㄴㅕㅇ ");
+
+    stream.push_str("한", &mut output);
+    assert_eq!(output, "ㅇㅏㄴㄴㅕㅇ ");
+
+    stream.finish(&mut output).unwrap();
+    assert_eq!(output, "ㅇㅏㄴㄴㅕㅇ ㅎㅏㄴ");
+  }
+
+  #[test]
+  fn test_push_bytes_split_utf8() {
+    let bytes = "한글".as_bytes();
+    let mut stream = HangulStream::disassemble();
+    let mut output = String::new();
+
+    for byte in bytes {
+      stream.push_bytes(&[*byte], &mut output).unwrap();
+    }
+    stream.finish(&mut output).unwrap();
+
+    assert_eq!(output, "ㅎㅏㄴㄱㅡㄹ");
+  }
+
+  #[test]
+  fn test_push_bytes_errors() {
+    let mut output = String::new();
+
+    let mut stream = HangulStream::disassemble();
+    assert!(matches!(
+      stream.push_bytes(&[0xFF, 0x41], &mut output),
+      Err(StreamError::InvalidUtf8)
+    ));
+
+    let mut stream = HangulStream::disassemble();
+    stream
+      .push_bytes(&"한".as_bytes()[..2], &mut output)
+      .unwrap();
+    assert!(matches!(
+      stream.finish(&mut output),
+      Err(StreamError::IncompleteUtf8)
+    ));
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn test_io_adapters() {
+    let input = "안녕하세요\nHello 한글\n";
+
+    let mut output = Vec::new();
+    let reader = io::BufReader::with_capacity(4, input.as_bytes());
+    disassemble_stream(reader, &mut output).unwrap();
+    assert_eq!(
+      String::from_utf8(output).unwrap(),
+      Hangul::new(input).disassemble()
+    );
+
+    let mut output = Vec::new();
+    let reader = io::BufReader::with_capacity(5, input.as_bytes());
+    choseong_stream(reader, &mut output).unwrap();
+    assert_eq!(
+      String::from_utf8(output).unwrap(),
+      "ㅇㄴㅎㅅㅇ\nHello ㅎㄱ\n"
+    );
+  }
+}
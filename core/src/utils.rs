@@ -3,7 +3,7 @@ const COMPAT_JAMO_LAST: u32 = 0x318E;
 
 // 호환 자모 확인(Compatibility Jamo)
 pub fn is_compatibility_jamo(unicode: u32) -> bool {
-  COMPAT_JAMO_BASE <= unicode && unicode <= COMPAT_JAMO_LAST
+  (COMPAT_JAMO_BASE..=COMPAT_JAMO_LAST).contains(&unicode)
 }
 
 #[cfg(test)]
@@ -1,24 +1,103 @@
 import { useEffect, useState } from "react";
 import "./App.css";
-import initHangul, { disassemble, getChoseong } from "../../../wasm/pkg/hangul";
+import initHangul, {
+	Hangul,
+	assemble,
+	hasBatchim,
+	parseLetter,
+	romanize,
+} from "../../../wasm/pkg/hangul";
 
-type Wasm = {
-	disassemble: (text: string) => string;
-	getChoseong: (text: string) => string;
+type LetterRow = {
+	original: string;
+	isHangul: boolean;
+	choseong?: string;
+	jungseong?: string;
+	jongseong?: string;
+	hasBatchim: boolean;
 };
 
+type Operation = {
+	label: string;
+	run: (text: string) => string;
+};
+
+// wasm 객체는 사용 후 직접 해제해야 함
+const withHangul = <T,>(text: string, fn: (hangul: Hangul) => T): T => {
+	const hangul = new Hangul(text);
+	try {
+		return fn(hangul);
+	} finally {
+		hangul.free();
+	}
+};
+
+const operations: Operation[] = [
+	{ label: "분해하기", run: (text) => withHangul(text, (h) => h.disassemble()) },
+	{ label: "초성 추출", run: (text) => withHangul(text, (h) => h.getChoseong()) },
+	{ label: "NFC 정규화", run: (text) => withHangul(text, (h) => h.toNfc()) },
+	{
+		label: "NFD 정규화",
+		run: (text) =>
+			withHangul(text, (h) =>
+				Array.from(h.toNfd(), (ch) =>
+					ch.codePointAt(0)?.toString(16).toUpperCase(),
+				).join(" "),
+			),
+	},
+	{ label: "자모 조립", run: assemble },
+	{ label: "로마자 표기", run: romanize },
+	{
+		label: "받침 확인",
+		run: (text) => (hasBatchim(text) ? "받침 있음" : "받침 없음"),
+	},
+	{
+		label: "첫 글자 파싱",
+		run: (text) => {
+			const letter = parseLetter(Array.from(text)[0] ?? "");
+			if (!letter) {
+				return "한글 음절이 아닙니다";
+			}
+			const parsed = [letter.choseong, letter.jungseong, letter.jongseong];
+			letter.free();
+			return parsed.filter(Boolean).join(" + ");
+		},
+	},
+];
+
 function App() {
+	const [ready, setReady] = useState(false);
 	const [inputText, setInputText] = useState<string>("안녕하세요");
 	const [result, setResult] = useState<string>("");
-	const [wasm] = useState<Wasm>({
-		disassemble,
-		getChoseong,
-	});
+	const [letters, setLetters] = useState<LetterRow[]>([]);
 
 	useEffect(() => {
-		initHangul();
+		initHangul().then(() => setReady(true));
 	}, []);
 
+	useEffect(() => {
+		if (!ready) {
+			return;
+		}
+
+		setLetters(
+			withHangul(inputText, (hangul) =>
+				hangul.letters().map((letter) => {
+					const row = {
+						original: letter.original,
+						isHangul: letter.isHangul,
+						choseong: letter.choseong,
+						jungseong: letter.jungseong,
+						jongseong: letter.jongseong,
+						hasBatchim: letter.hasBatchim,
+					};
+					letter.free();
+					return row;
+				}),
+			),
+		);
+	}, [ready, inputText]);
+
 	return (
 		<div className="container">
 			<h1>한글 WASM 데모</h1>
@@ -34,34 +113,49 @@ function App() {
 			</div>
 
 			<div className="button-group">
-				<button
-					type="button"
-					onClick={() => {
-						if (inputText) {
-							const disassembled = wasm?.disassemble(inputText);
-							setResult(disassembled);
-						}
-					}}
-				>
-					분해하기
-				</button>
-				<button
-					type="button"
-					onClick={() => {
-						if (inputText) {
-							const choseong = wasm?.getChoseong(inputText);
-							setResult(choseong);
-						}
-					}}
-				>
-					초성 추출
-				</button>
+				{operations.map(({ label, run }) => (
+					<button
+						key={label}
+						type="button"
+						disabled={!ready}
+						onClick={() => setResult(run(inputText))}
+					>
+						{label}
+					</button>
+				))}
 			</div>
 
 			<div className="result">
 				<h3>결과:</h3>
 				<div>{result}</div>
 			</div>
+
+			<div className="result">
+				<h3>글자 구조 ({letters.length}글자):</h3>
+				<table>
+					<thead>
+						<tr>
+							<th>글자</th>
+							<th>초성</th>
+							<th>중성</th>
+							<th>종성</th>
+							<th>받침</th>
+						</tr>
+					</thead>
+					<tbody>
+						{letters.map((letter, index) => (
+							// biome-ignore lint/suspicious/noArrayIndexKey: 같은 글자가 반복될 수 있음
+							<tr key={index}>
+								<td>{letter.original}</td>
+								<td>{letter.choseong ?? "-"}</td>
+								<td>{letter.jungseong ?? "-"}</td>
+								<td>{letter.jongseong ?? "-"}</td>
+								<td>{letter.isHangul ? (letter.hasBatchim ? "O" : "X") : "-"}</td>
+							</tr>
+						))}
+					</tbody>
+				</table>
+			</div>
 		</div>
 	);
 }
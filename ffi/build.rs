@@ -0,0 +1,12 @@
+use std::env;
+
+fn main() {
+  let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+  println!("cargo:rerun-if-changed=src");
+  println!("cargo:rerun-if-changed=cbindgen.toml");
+
+  cbindgen::generate(&crate_dir)
+    .expect("Unable to generate C bindings")
+    .write_to_file("include/hangul.h");
+}
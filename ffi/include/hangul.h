@@ -0,0 +1,186 @@
+#ifndef HANGUL_FFI_H
+#define HANGUL_FFI_H
+
+/* auto-generated by cbindgen, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * 함수 호출 결과 상태 코드
+ */
+typedef enum HangulStatus {
+  HANGUL_STATUS_OK = 0,
+  /**
+   * 필수 포인터 인자가 NULL
+   */
+  HANGUL_STATUS_NULL_POINTER = 1,
+  /**
+   * 입력이 올바른 UTF-8이 아님
+   */
+  HANGUL_STATUS_INVALID_UTF8 = 2,
+  /**
+   * 출력 버퍼가 작음, 필요한 크기는 길이 출력 인자에 기록됨
+   */
+  HANGUL_STATUS_BUFFER_TOO_SMALL = 3,
+  /**
+   * 글자 인덱스가 범위를 벗어남
+   */
+  HANGUL_STATUS_OUT_OF_RANGE = 4,
+  /**
+   * 내부 오류
+   */
+  HANGUL_STATUS_PANIC = 5,
+} HangulStatus;
+
+/**
+ * 한글 문자열 핸들, `hangul_new`로 생성하고 `hangul_free`로 해제
+ */
+typedef struct HangulHandle HangulHandle;
+
+/**
+ * 한 글자의 초성/중성/종성 인덱스
+ */
+typedef struct HangulLetterIndices {
+  /**
+   * 한글 음절이 아니면 false이며 나머지 값은 0
+   */
+  bool is_hangul;
+  /**
+   * 초성 인덱스 (0–18)
+   */
+  uint8_t choseong;
+  /**
+   * 중성 인덱스 (0–20)
+   */
+  uint8_t jungseong;
+  /**
+   * 종성 인덱스 (1–27), 종성이 없으면 0
+   */
+  uint8_t jongseong;
+} HangulLetterIndices;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * UTF-8 문자열 `text`(길이 `len` 바이트)로 핸들을 생성하여 `out`에 기록
+ *
+ * # Safety
+ *
+ * `text`는 `len` 바이트를 읽을 수 있어야 하고(`len`이 0이면 NULL 허용), `out`은 쓰기 가능한 포인터여야 합니다.
+ */
+enum HangulStatus hangul_new(const uint8_t *text,
+                             size_t len,
+                             struct HangulHandle **out);
+
+/**
+ * `hangul_new`로 생성한 핸들을 해제, NULL이면 아무것도 하지 않음
+ *
+ * # Safety
+ *
+ * `handle`은 `hangul_new`가 반환한 포인터이거나 NULL이어야 하며 두 번 해제하면 안 됩니다.
+ */
+void hangul_free(struct HangulHandle *handle);
+
+/**
+ * 글자 수를 `out_len`에 기록
+ *
+ * # Safety
+ *
+ * `handle`은 유효한 핸들이어야 하고 `out_len`은 쓰기 가능한 포인터여야 합니다.
+ */
+enum HangulStatus hangul_len(const struct HangulHandle *handle,
+                             size_t *out_len);
+
+/**
+ * 자모로 분해한 UTF-8 문자열을 `buffer`에 기록 (NUL 종료 없음)
+ *
+ * 결과의 바이트 길이는 항상 `out_len`에 기록되며, `capacity`가 부족하면 `HANGUL_STATUS_BUFFER_TOO_SMALL`을 반환합니다.
+ * `buffer`를 NULL, `capacity`를 0으로 호출하여 필요한 크기를 먼저 구할 수 있습니다.
+ *
+ * # Safety
+ *
+ * `handle`은 유효한 핸들, `buffer`는 `capacity` 바이트를 쓸 수 있는 포인터, `out_len`은 쓰기 가능한 포인터여야 합니다.
+ */
+enum HangulStatus hangul_disassemble(const struct HangulHandle *handle,
+                                     uint8_t *buffer,
+                                     size_t capacity,
+                                     size_t *out_len);
+
+/**
+ * 초성 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+ *
+ * # Safety
+ *
+ * `hangul_disassemble`과 같습니다.
+ */
+enum HangulStatus hangul_get_choseong(const struct HangulHandle *handle,
+                                      uint8_t *buffer,
+                                      size_t capacity,
+                                      size_t *out_len);
+
+/**
+ * 완성형(NFC)으로 바꾼 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+ *
+ * # Safety
+ *
+ * `hangul_disassemble`과 같습니다.
+ */
+enum HangulStatus hangul_to_nfc(const struct HangulHandle *handle,
+                                uint8_t *buffer,
+                                size_t capacity,
+                                size_t *out_len);
+
+/**
+ * 조합형 자모(NFD)로 바꾼 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+ *
+ * # Safety
+ *
+ * `hangul_disassemble`과 같습니다.
+ */
+enum HangulStatus hangul_to_nfd(const struct HangulHandle *handle,
+                                uint8_t *buffer,
+                                size_t capacity,
+                                size_t *out_len);
+
+/**
+ * `index`번째 글자의 초성/중성/종성 인덱스를 `out`에 기록
+ *
+ * # Safety
+ *
+ * `handle`은 유효한 핸들이어야 하고 `out`은 쓰기 가능한 포인터여야 합니다.
+ */
+enum HangulStatus hangul_letter_at(const struct HangulHandle *handle,
+                                   size_t index,
+                                   struct HangulLetterIndices *out);
+
+/**
+ * 모든 글자의 초성/중성/종성 인덱스를 `buffer`에 기록
+ *
+ * 글자 수는 항상 `out_len`에 기록되며, `capacity`(원소 개수)가 부족하면 `HANGUL_STATUS_BUFFER_TOO_SMALL`을 반환합니다.
+ *
+ * # Safety
+ *
+ * `handle`은 유효한 핸들, `buffer`는 `capacity`개의 원소를 쓸 수 있는 포인터, `out_len`은 쓰기 가능한 포인터여야 합니다.
+ */
+enum HangulStatus hangul_letters(const struct HangulHandle *handle,
+                                 struct HangulLetterIndices *buffer,
+                                 size_t capacity,
+                                 size_t *out_len);
+
+/**
+ * 상태 코드에 대한 NUL 종료 설명 문자열, 해제하지 않아야 함
+ * `HangulStatus`에 없는 값은 "unknown status"
+ */
+const char *hangul_status_message(int status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HANGUL_FFI_H */
@@ -0,0 +1,453 @@
+use std::ffi::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+use hangul::{Hangul, HangulLetter};
+
+/// 함수 호출 결과 상태 코드
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HangulStatus {
+  Ok = 0,
+  /// 필수 포인터 인자가 NULL
+  NullPointer = 1,
+  /// 입력이 올바른 UTF-8이 아님
+  InvalidUtf8 = 2,
+  /// 출력 버퍼가 작음, 필요한 크기는 길이 출력 인자에 기록됨
+  BufferTooSmall = 3,
+  /// 글자 인덱스가 범위를 벗어남
+  OutOfRange = 4,
+  /// 내부 오류
+  Panic = 5,
+}
+
+impl HangulStatus {
+  // C에서 받은 정수 상태 코드, 범위를 벗어나면 None
+  fn from_code(code: c_int) -> Option<Self> {
+    [
+      Self::Ok,
+      Self::NullPointer,
+      Self::InvalidUtf8,
+      Self::BufferTooSmall,
+      Self::OutOfRange,
+      Self::Panic,
+    ]
+    .into_iter()
+    .find(|&status| status as c_int == code)
+  }
+}
+
+/// 한글 문자열 핸들, `hangul_new`로 생성하고 `hangul_free`로 해제
+pub struct HangulHandle {
+  hangul: Hangul,
+}
+
+/// 한 글자의 초성/중성/종성 인덱스
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HangulLetterIndices {
+  /// 한글 음절이 아니면 false이며 나머지 값은 0
+  pub is_hangul: bool,
+  /// 초성 인덱스 (0–18)
+  pub choseong: u8,
+  /// 중성 인덱스 (0–20)
+  pub jungseong: u8,
+  /// 종성 인덱스 (1–27), 종성이 없으면 0
+  pub jongseong: u8,
+}
+
+impl From<Option<HangulLetter>> for HangulLetterIndices {
+  fn from(letter: Option<HangulLetter>) -> Self {
+    match letter {
+      Some(letter) => {
+        let (choseong, jungseong, jongseong) = letter.indices();
+        Self {
+          is_hangul: true,
+          choseong,
+          jungseong,
+          jongseong,
+        }
+      }
+      None => Self::default(),
+    }
+  }
+}
+
+// 패닉을 상태 코드로 바꿔 C 호출자에게 전파되지 않도록 함
+fn guard(f: impl FnOnce() -> Result<(), HangulStatus>) -> HangulStatus {
+  match panic::catch_unwind(AssertUnwindSafe(f)) {
+    Ok(Ok(())) => HangulStatus::Ok,
+    Ok(Err(status)) => status,
+    Err(_) => HangulStatus::Panic,
+  }
+}
+
+unsafe fn read_str<'a>(text: *const u8, len: usize) -> Result<&'a str, HangulStatus> {
+  if text.is_null() {
+    return if len == 0 {
+      Ok("")
+    } else {
+      Err(HangulStatus::NullPointer)
+    };
+  }
+
+  str::from_utf8(slice::from_raw_parts(text, len)).map_err(|_| HangulStatus::InvalidUtf8)
+}
+
+unsafe fn hangul_ref<'a>(handle: *const HangulHandle) -> Result<&'a Hangul, HangulStatus> {
+  handle
+    .as_ref()
+    .map(|handle| &handle.hangul)
+    .ok_or(HangulStatus::NullPointer)
+}
+
+unsafe fn write_slice<T: Copy>(
+  output: &[T],
+  buffer: *mut T,
+  capacity: usize,
+  out_len: *mut usize,
+) -> Result<(), HangulStatus> {
+  if out_len.is_null() {
+    return Err(HangulStatus::NullPointer);
+  }
+
+  *out_len = output.len();
+
+  if output.len() > capacity {
+    return Err(HangulStatus::BufferTooSmall);
+  }
+  if output.is_empty() {
+    return Ok(());
+  }
+  if buffer.is_null() {
+    return Err(HangulStatus::NullPointer);
+  }
+
+  ptr::copy_nonoverlapping(output.as_ptr(), buffer, output.len());
+  Ok(())
+}
+
+unsafe fn write_string(
+  handle: *const HangulHandle,
+  buffer: *mut u8,
+  capacity: usize,
+  out_len: *mut usize,
+  f: fn(&Hangul) -> String,
+) -> HangulStatus {
+  guard(|| {
+    let output = f(hangul_ref(handle)?);
+    write_slice(output.as_bytes(), buffer, capacity, out_len)
+  })
+}
+
+/// UTF-8 문자열 `text`(길이 `len` 바이트)로 핸들을 생성하여 `out`에 기록
+///
+/// # Safety
+///
+/// `text`는 `len` 바이트를 읽을 수 있어야 하고(`len`이 0이면 NULL 허용), `out`은 쓰기 가능한 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_new(
+  text: *const u8,
+  len: usize,
+  out: *mut *mut HangulHandle,
+) -> HangulStatus {
+  guard(|| {
+    if out.is_null() {
+      return Err(HangulStatus::NullPointer);
+    }
+
+    let hangul = Hangul::new(read_str(text, len)?);
+    *out = Box::into_raw(Box::new(HangulHandle { hangul }));
+    Ok(())
+  })
+}
+
+/// `hangul_new`로 생성한 핸들을 해제, NULL이면 아무것도 하지 않음
+///
+/// # Safety
+///
+/// `handle`은 `hangul_new`가 반환한 포인터이거나 NULL이어야 하며 두 번 해제하면 안 됩니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_free(handle: *mut HangulHandle) {
+  if !handle.is_null() {
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(handle))));
+  }
+}
+
+/// 글자 수를 `out_len`에 기록
+///
+/// # Safety
+///
+/// `handle`은 유효한 핸들이어야 하고 `out_len`은 쓰기 가능한 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_len(
+  handle: *const HangulHandle,
+  out_len: *mut usize,
+) -> HangulStatus {
+  guard(|| {
+    let hangul = hangul_ref(handle)?;
+    let out_len = out_len.as_mut().ok_or(HangulStatus::NullPointer)?;
+    *out_len = hangul.len();
+    Ok(())
+  })
+}
+
+/// 자모로 분해한 UTF-8 문자열을 `buffer`에 기록 (NUL 종료 없음)
+///
+/// 결과의 바이트 길이는 항상 `out_len`에 기록되며, `capacity`가 부족하면 `HANGUL_STATUS_BUFFER_TOO_SMALL`을 반환합니다.
+/// `buffer`를 NULL, `capacity`를 0으로 호출하여 필요한 크기를 먼저 구할 수 있습니다.
+///
+/// # Safety
+///
+/// `handle`은 유효한 핸들, `buffer`는 `capacity` 바이트를 쓸 수 있는 포인터, `out_len`은 쓰기 가능한 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_disassemble(
+  handle: *const HangulHandle,
+  buffer: *mut u8,
+  capacity: usize,
+  out_len: *mut usize,
+) -> HangulStatus {
+  write_string(handle, buffer, capacity, out_len, Hangul::disassemble)
+}
+
+/// 초성 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+///
+/// # Safety
+///
+/// `hangul_disassemble`과 같습니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_get_choseong(
+  handle: *const HangulHandle,
+  buffer: *mut u8,
+  capacity: usize,
+  out_len: *mut usize,
+) -> HangulStatus {
+  write_string(handle, buffer, capacity, out_len, Hangul::get_choseong)
+}
+
+/// 완성형(NFC)으로 바꾼 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+///
+/// # Safety
+///
+/// `hangul_disassemble`과 같습니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_to_nfc(
+  handle: *const HangulHandle,
+  buffer: *mut u8,
+  capacity: usize,
+  out_len: *mut usize,
+) -> HangulStatus {
+  write_string(handle, buffer, capacity, out_len, Hangul::to_nfc)
+}
+
+/// 조합형 자모(NFD)로 바꾼 문자열을 `buffer`에 기록, 버퍼 규칙은 `hangul_disassemble`과 같음
+///
+/// # Safety
+///
+/// `hangul_disassemble`과 같습니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_to_nfd(
+  handle: *const HangulHandle,
+  buffer: *mut u8,
+  capacity: usize,
+  out_len: *mut usize,
+) -> HangulStatus {
+  write_string(handle, buffer, capacity, out_len, Hangul::to_nfd)
+}
+
+/// `index`번째 글자의 초성/중성/종성 인덱스를 `out`에 기록
+///
+/// # Safety
+///
+/// `handle`은 유효한 핸들이어야 하고 `out`은 쓰기 가능한 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_letter_at(
+  handle: *const HangulHandle,
+  index: usize,
+  out: *mut HangulLetterIndices,
+) -> HangulStatus {
+  guard(|| {
+    let hangul = hangul_ref(handle)?;
+    let out = out.as_mut().ok_or(HangulStatus::NullPointer)?;
+    let unit = hangul.get(index).ok_or(HangulStatus::OutOfRange)?;
+
+    *out = unit.hangul().into();
+    Ok(())
+  })
+}
+
+/// 모든 글자의 초성/중성/종성 인덱스를 `buffer`에 기록
+///
+/// 글자 수는 항상 `out_len`에 기록되며, `capacity`(원소 개수)가 부족하면 `HANGUL_STATUS_BUFFER_TOO_SMALL`을 반환합니다.
+///
+/// # Safety
+///
+/// `handle`은 유효한 핸들, `buffer`는 `capacity`개의 원소를 쓸 수 있는 포인터, `out_len`은 쓰기 가능한 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn hangul_letters(
+  handle: *const HangulHandle,
+  buffer: *mut HangulLetterIndices,
+  capacity: usize,
+  out_len: *mut usize,
+) -> HangulStatus {
+  guard(|| {
+    let letters: Vec<HangulLetterIndices> = hangul_ref(handle)?
+      .iter()
+      .map(|unit| unit.hangul().into())
+      .collect();
+
+    write_slice(&letters, buffer, capacity, out_len)
+  })
+}
+
+/// 상태 코드에 대한 NUL 종료 설명 문자열, 해제하지 않아야 함
+/// `HangulStatus`에 없는 값은 "unknown status"
+#[no_mangle]
+pub extern "C" fn hangul_status_message(status: c_int) -> *const c_char {
+  let message: &'static [u8] = match HangulStatus::from_code(status) {
+    Some(HangulStatus::Ok) => b"ok\0",
+    Some(HangulStatus::NullPointer) => b"null pointer argument\0",
+    Some(HangulStatus::InvalidUtf8) => b"input is not valid UTF-8\0",
+    Some(HangulStatus::BufferTooSmall) => b"output buffer is too small\0",
+    Some(HangulStatus::OutOfRange) => b"letter index is out of range\0",
+    Some(HangulStatus::Panic) => b"internal error\0",
+    None => b"unknown status\0",
+  };
+
+  message.as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::ffi::CStr;
+
+  use super::*;
+
+  fn new_handle(text: &str) -> *mut HangulHandle {
+    let mut handle = ptr::null_mut();
+    let status = unsafe { hangul_new(text.as_ptr(), text.len(), &mut handle) };
+    assert_eq!(status, HangulStatus::Ok);
+    handle
+  }
+
+  fn read_string(
+    handle: *const HangulHandle,
+    f: unsafe extern "C" fn(*const HangulHandle, *mut u8, usize, *mut usize) -> HangulStatus,
+  ) -> String {
+    let mut len = 0;
+    let status = unsafe { f(handle, ptr::null_mut(), 0, &mut len) };
+    assert!(status == HangulStatus::Ok || status == HangulStatus::BufferTooSmall);
+
+    let mut buffer = vec![0u8; len];
+    let status = unsafe { f(handle, buffer.as_mut_ptr(), buffer.len(), &mut len) };
+    assert_eq!(status, HangulStatus::Ok);
+    String::from_utf8(buffer).unwrap()
+  }
+
+  #[test]
+  fn test_string_outputs() {
+    let handle = new_handle("Hello 한글");
+
+    assert_eq!(
+      read_string(handle, hangul_disassemble),
+      "Hello ㅎㅏㄴㄱㅡㄹ"
+    );
+    assert_eq!(read_string(handle, hangul_get_choseong), "Hello ㅎㄱ");
+    assert_eq!(
+      read_string(handle, hangul_to_nfd),
+      "Hello \u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+    );
+    assert_eq!(read_string(handle, hangul_to_nfc), "Hello 한글");
+
+    let mut len = 0;
+    assert_eq!(unsafe { hangul_len(handle, &mut len) }, HangulStatus::Ok);
+    assert_eq!(len, 8);
+
+    unsafe { hangul_free(handle) };
+  }
+
+  #[test]
+  fn test_buffer_too_small() {
+    let handle = new_handle("안녕");
+    let mut buffer = [0u8; 4];
+    let mut len = 0;
+
+    let status = unsafe { hangul_disassemble(handle, buffer.as_mut_ptr(), buffer.len(), &mut len) };
+    assert_eq!(status, HangulStatus::BufferTooSmall);
+    assert_eq!(len, "ㅇㅏㄴㄴㅕㅇ".len());
+
+    unsafe { hangul_free(handle) };
+  }
+
+  #[test]
+  fn test_letters() {
+    let handle = new_handle("닭a");
+
+    let mut letter = HangulLetterIndices::default();
+    assert_eq!(
+      unsafe { hangul_letter_at(handle, 0, &mut letter) },
+      HangulStatus::Ok
+    );
+    assert_eq!(
+      letter,
+      HangulLetterIndices {
+        is_hangul: true,
+        choseong: 3,
+        jungseong: 0,
+        jongseong: 9,
+      }
+    );
+
+    assert_eq!(
+      unsafe { hangul_letter_at(handle, 2, &mut letter) },
+      HangulStatus::OutOfRange
+    );
+
+    let mut letters = [HangulLetterIndices::default(); 2];
+    let mut len = 0;
+    let status = unsafe { hangul_letters(handle, letters.as_mut_ptr(), letters.len(), &mut len) };
+    assert_eq!(status, HangulStatus::Ok);
+    assert_eq!(len, 2);
+    assert!(letters[0].is_hangul);
+    assert!(!letters[1].is_hangul);
+
+    unsafe { hangul_free(handle) };
+  }
+
+  #[test]
+  fn test_errors() {
+    let mut handle = ptr::null_mut();
+    let invalid = [0xFFu8, 0xFE];
+
+    assert_eq!(
+      unsafe { hangul_new(invalid.as_ptr(), invalid.len(), &mut handle) },
+      HangulStatus::InvalidUtf8
+    );
+    assert_eq!(
+      unsafe { hangul_new(ptr::null(), 3, &mut handle) },
+      HangulStatus::NullPointer
+    );
+    assert_eq!(
+      unsafe { hangul_new(ptr::null(), 0, &mut handle) },
+      HangulStatus::Ok
+    );
+    unsafe { hangul_free(handle) };
+
+    let mut len = 0;
+    assert_eq!(
+      unsafe { hangul_len(ptr::null(), &mut len) },
+      HangulStatus::NullPointer
+    );
+    unsafe { hangul_free(ptr::null_mut()) };
+
+    let message = |status| unsafe { CStr::from_ptr(hangul_status_message(status)) };
+    assert_eq!(
+      message(HangulStatus::InvalidUtf8 as c_int)
+        .to_str()
+        .unwrap(),
+      "input is not valid UTF-8"
+    );
+    assert_eq!(message(-1).to_str().unwrap(), "unknown status");
+    assert_eq!(message(6).to_str().unwrap(), "unknown status");
+  }
+}
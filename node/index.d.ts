@@ -2,6 +2,159 @@
 /* eslint-disable */
 export declare class Hangul {
   constructor(text: string)
+  get length(): number
+  get original(): string
+  letters(): Array<Letter>
   disassemble(): string
+  disassembleWith(options: DisassembleOptions): string
+  disassembleGrouped(options?: DisassembleOptions | undefined | null): Array<Array<string>>
   getChoseong(): string
+  getChoseongWith(options: ExtractOptions): string
+  getJungseong(): string
+  getJungseongWith(options: ExtractOptions): string
+  getJongseong(): string
+  getJongseongWith(options: ExtractOptions): string
+  withChoseong(index: number, choseong: string): Hangul | null
+  withJungseong(index: number, jungseong: string): Hangul | null
+  withJongseong(index: number, jongseong: string): Hangul | null
+  removeJongseong(index: number): Hangul | null
+  removeLastJamo(): Hangul
+  foldHalfwidth(): Hangul
+  hanjaToHangul(): Hangul
 }
+
+export declare class Lemmatizer {
+  constructor()
+  ending(ending: string): Lemmatizer
+  lemma(lemma: string): Lemmatizer
+  lemmas(lemmas: Array<string>): Lemmatizer
+  lemmatize(word: string): Array<Lemma>
+}
+
+export declare class ModerationFilter {
+  constructor(blocklist: Array<string>)
+  findMatches(text: string): Array<ModerationMatch>
+  isMatch(text: string): boolean
+}
+
+export declare function applyDueum(word: string): string
+
+export declare function classifyJamo(ch: string): JamoInfo
+
+export declare function confusableSkeleton(text: string): string
+
+export declare function conjugate(predicate: string, ending: string): string
+
+export declare function conjugateAs(predicate: string, ending: string, className: ConjugationClass): string
+
+export declare function conjugationClass(predicate: string): ConjugationClass
+
+export type ConjugationClass = 'regular' | 'bieupIrregular' | 'digeutIrregular' | 'siotIrregular' | 'hieutIrregular' | 'reuIrregular' | 'reoIrregular' | 'uIrregular' | 'yeoIrregular'
+
+export declare function disassembleBatch(texts: Array<string>): Array<string>
+
+export declare function disassembleBatchAsync(texts: Array<string>): Promise<Array<string>>
+
+export interface DisassembleOptions {
+  jamoForm?: JamoForm
+  splitComplexJongseong?: boolean
+  splitComplexJungseong?: boolean
+  separator?: string
+  nonHangul?: NonHangul
+  replacement?: string
+  expandEnclosed?: boolean
+}
+
+export declare function dueumOriginals(word: string): Array<string>
+
+export declare function enclosedJamo(index: number, style: EnclosedStyle): string | null
+
+export type EnclosedStyle = 'circled' | 'parenthesized'
+
+export declare function enclosedSyllable(index: number, style: EnclosedStyle): string | null
+
+export declare function expandEnclosed(ch: string): string | null
+
+export interface ExtractOptions {
+  jamoForm?: JamoForm
+  nonHangul?: NonHangul
+  replacement?: string
+  keepWhitespace?: boolean
+  foldDoubleConsonants?: boolean
+  missingJongseong?: string
+}
+
+export declare function fillIncompleteSyllables(text: string): string
+
+export declare function foldHalfwidth(text: string): string
+
+export declare function fromIndices(choseong: number, jungseong: number, jongseong: number): string
+
+export declare function fromJamo(choseong: string, jungseong: string, jongseong?: string | undefined | null): string
+
+export declare function getChoseongBatch(texts: Array<string>): Array<string>
+
+export declare function getChoseongBatchAsync(texts: Array<string>): Promise<Array<string>>
+
+export declare function hanjaReading(ch: string): string | null
+
+export declare function hanjaToHangul(text: string): string
+
+export declare function isConfusable(a: string, b: string): boolean
+
+export type JamoBlock = 'conjoining' | 'compatibility' | 'halfwidth' | 'extendedA' | 'extendedB'
+
+export type JamoForm = 'compatibility' | 'conjoining'
+
+export interface JamoInfo {
+  block?: JamoBlock
+  isConsonant: boolean
+  isVowel: boolean
+  canBeChoseong: boolean
+  canBeJongseong: boolean
+  isDoubleConsonant: boolean
+  isComplexJongseong: boolean
+  isComplexVowel: boolean
+}
+
+export interface Lemma {
+  lemma: string
+  ending: string
+}
+
+export declare function lemmatize(word: string): Array<Lemma>
+
+export interface Letter {
+  original: string
+  isHangul: boolean
+  choseong?: string
+  jungseong?: string
+  jongseong?: string
+  hasBatchim: boolean
+}
+
+export interface ModerationMatch {
+  word: string
+  start: number
+  end: number
+}
+
+export declare function moderationSkeleton(text: string): string
+
+export type NonHangul = 'keep' | 'remove' | 'replace'
+
+export type NormalizationForm = 'NFC' | 'NFD'
+
+export declare function normalizeBatch(texts: Array<string>, form?: NormalizationForm | undefined | null): Array<string>
+
+export declare function normalizeBatchAsync(texts: Array<string>, form?: NormalizationForm | undefined | null): Promise<Array<string>>
+
+export declare function speechLevel(sentence: string): SpeechLevel | null
+
+export type SpeechLevel = 'hae' | 'haeyo' | 'hapsyo'
+
+export declare function toEnclosed(text: string, style: EnclosedStyle): string | null
+
+export declare function toHonorific(sentence: string): string | null
+
+export declare function toSpeechLevel(sentence: string, level: SpeechLevel): string | null
@@ -375,3 +375,34 @@ if (!nativeBinding) {
 }
 
 module.exports.Hangul = nativeBinding.Hangul
+module.exports.Lemmatizer = nativeBinding.Lemmatizer
+module.exports.ModerationFilter = nativeBinding.ModerationFilter
+module.exports.applyDueum = nativeBinding.applyDueum
+module.exports.classifyJamo = nativeBinding.classifyJamo
+module.exports.confusableSkeleton = nativeBinding.confusableSkeleton
+module.exports.conjugate = nativeBinding.conjugate
+module.exports.conjugateAs = nativeBinding.conjugateAs
+module.exports.conjugationClass = nativeBinding.conjugationClass
+module.exports.disassembleBatch = nativeBinding.disassembleBatch
+module.exports.disassembleBatchAsync = nativeBinding.disassembleBatchAsync
+module.exports.dueumOriginals = nativeBinding.dueumOriginals
+module.exports.enclosedJamo = nativeBinding.enclosedJamo
+module.exports.enclosedSyllable = nativeBinding.enclosedSyllable
+module.exports.expandEnclosed = nativeBinding.expandEnclosed
+module.exports.fillIncompleteSyllables = nativeBinding.fillIncompleteSyllables
+module.exports.foldHalfwidth = nativeBinding.foldHalfwidth
+module.exports.fromIndices = nativeBinding.fromIndices
+module.exports.fromJamo = nativeBinding.fromJamo
+module.exports.getChoseongBatch = nativeBinding.getChoseongBatch
+module.exports.getChoseongBatchAsync = nativeBinding.getChoseongBatchAsync
+module.exports.hanjaReading = nativeBinding.hanjaReading
+module.exports.hanjaToHangul = nativeBinding.hanjaToHangul
+module.exports.isConfusable = nativeBinding.isConfusable
+module.exports.lemmatize = nativeBinding.lemmatize
+module.exports.moderationSkeleton = nativeBinding.moderationSkeleton
+module.exports.normalizeBatch = nativeBinding.normalizeBatch
+module.exports.normalizeBatchAsync = nativeBinding.normalizeBatchAsync
+module.exports.speechLevel = nativeBinding.speechLevel
+module.exports.toEnclosed = nativeBinding.toEnclosed
+module.exports.toHonorific = nativeBinding.toHonorific
+module.exports.toSpeechLevel = nativeBinding.toSpeechLevel
@@ -0,0 +1,139 @@
+use napi::bindgen_prelude::*;
+
+// 정규화 형식
+#[napi(string_enum = "UPPERCASE")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+  Nfc,
+  Nfd,
+}
+
+#[derive(Clone, Copy)]
+enum BatchOperation {
+  Disassemble,
+  Choseong,
+  Nfc,
+  Nfd,
+}
+
+impl BatchOperation {
+  fn normalize(form: Option<NormalizationForm>) -> Self {
+    match form {
+      None | Some(NormalizationForm::Nfc) => BatchOperation::Nfc,
+      Some(NormalizationForm::Nfd) => BatchOperation::Nfd,
+    }
+  }
+
+  fn apply(self, text: &str) -> String {
+    let hangul = hangul::Hangul::new(text);
+
+    match self {
+      BatchOperation::Disassemble => hangul.disassemble(),
+      BatchOperation::Choseong => hangul.get_choseong(),
+      BatchOperation::Nfc => hangul.to_nfc(),
+      BatchOperation::Nfd => hangul.to_nfd(),
+    }
+  }
+
+  fn run(self, texts: &[String]) -> Vec<String> {
+    texts.iter().map(|text| self.apply(text)).collect()
+  }
+}
+
+// libuv 스레드 풀에서 배치 변환을 수행하는 작업
+pub struct BatchTask {
+  operation: BatchOperation,
+  texts: Vec<String>,
+}
+
+#[napi]
+impl Task for BatchTask {
+  type Output = Vec<String>;
+  type JsValue = Vec<String>;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    Ok(self.operation.run(&self.texts))
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+fn task(operation: BatchOperation, texts: Vec<String>) -> AsyncTask<BatchTask> {
+  AsyncTask::new(BatchTask { operation, texts })
+}
+
+#[napi]
+pub fn disassemble_batch(texts: Vec<String>) -> Vec<String> {
+  BatchOperation::Disassemble.run(&texts)
+}
+
+#[napi]
+pub fn get_choseong_batch(texts: Vec<String>) -> Vec<String> {
+  BatchOperation::Choseong.run(&texts)
+}
+
+// `form`의 기본값은 "NFC"
+#[napi]
+pub fn normalize_batch(texts: Vec<String>, form: Option<NormalizationForm>) -> Vec<String> {
+  BatchOperation::normalize(form).run(&texts)
+}
+
+#[napi]
+pub fn disassemble_batch_async(texts: Vec<String>) -> AsyncTask<BatchTask> {
+  task(BatchOperation::Disassemble, texts)
+}
+
+#[napi]
+pub fn get_choseong_batch_async(texts: Vec<String>) -> AsyncTask<BatchTask> {
+  task(BatchOperation::Choseong, texts)
+}
+
+#[napi]
+pub fn normalize_batch_async(
+  texts: Vec<String>,
+  form: Option<NormalizationForm>,
+) -> AsyncTask<BatchTask> {
+  task(BatchOperation::normalize(form), texts)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn texts(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|text| text.to_string()).collect()
+  }
+
+  #[test]
+  fn test_batch() {
+    let input = texts(&["안녕", "Hello 한글", ""]);
+
+    assert_eq!(
+      disassemble_batch(input.clone()),
+      ["ㅇㅏㄴㄴㅕㅇ", "Hello ㅎㅏㄴㄱㅡㄹ", ""]
+    );
+    assert_eq!(get_choseong_batch(input), ["ㅇㄴ", "Hello ㅎㄱ", ""]);
+  }
+
+  #[test]
+  fn test_normalize_batch() {
+    let nfd = "\u{1112}\u{1161}\u{11AB}";
+
+    assert_eq!(normalize_batch(texts(&[nfd]), None), ["한"]);
+    assert_eq!(
+      normalize_batch(texts(&["한"]), Some(NormalizationForm::Nfd)),
+      [nfd]
+    );
+  }
+
+  #[test]
+  fn test_batch_task_compute() {
+    let mut task = BatchTask {
+      operation: BatchOperation::Choseong,
+      texts: texts(&["안녕", "한글"]),
+    };
+    assert_eq!(task.compute().unwrap(), ["ㅇㄴ", "ㅎㄱ"]);
+  }
+}
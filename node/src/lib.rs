@@ -3,6 +3,42 @@
 #[macro_use]
 extern crate napi_derive;
 
+// 테스트 빌드에서는 napi 등록 코드가 생성되지 않아 비동기 함수가 사용되지 않음
+#[cfg_attr(test, allow(dead_code))]
+mod batch;
+mod options;
+
+use napi::bindgen_prelude::*;
+
+use crate::options::{
+  single_char, ConjugationClass, DisassembleOptions, EnclosedStyle, ExtractOptions, SpeechLevel,
+};
+
+#[napi(object)]
+pub struct Letter {
+  pub original: String,
+  pub is_hangul: bool,
+  pub choseong: Option<String>,
+  pub jungseong: Option<String>,
+  pub jongseong: Option<String>,
+  pub has_batchim: bool,
+}
+
+impl Letter {
+  fn new(original: &str, letter: Option<hangul::HangulLetter>) -> Self {
+    Self {
+      original: original.to_string(),
+      is_hangul: letter.is_some(),
+      choseong: letter.map(|letter| letter.choseong.compatibility_value().to_string()),
+      jungseong: letter.map(|letter| letter.jungseong.compatibility_value().to_string()),
+      jongseong: letter
+        .and_then(|letter| letter.jongseong)
+        .map(|jongseong| jongseong.compatibility_value().to_string()),
+      has_batchim: letter.is_some_and(|letter| letter.has_batchim()),
+    }
+  }
+}
+
 #[napi]
 pub struct Hangul {
   hangul: hangul::Hangul,
@@ -17,21 +53,506 @@ impl Hangul {
     }
   }
 
+  #[napi(getter)]
+  pub fn length(&self) -> u32 {
+    self.hangul.len() as u32
+  }
+
+  #[napi(getter)]
+  pub fn original(&self) -> String {
+    self.hangul.original().to_string()
+  }
+
+  #[napi]
+  pub fn letters(&self) -> Vec<Letter> {
+    self
+      .hangul
+      .iter()
+      .map(|unit| Letter::new(unit.as_str(), unit.hangul()))
+      .collect()
+  }
+
   #[napi]
   pub fn disassemble(&self) -> String {
     self.hangul.disassemble()
   }
 
+  #[napi]
+  pub fn disassemble_with(&self, options: DisassembleOptions) -> Result<String> {
+    Ok(self.hangul.disassemble_with(&options.to_core()?))
+  }
+
+  // 글자마다 분해한 자모 배열
+  #[napi]
+  pub fn disassemble_grouped(
+    &self,
+    options: Option<DisassembleOptions>,
+  ) -> Result<Vec<Vec<String>>> {
+    let options = options.unwrap_or_default().to_core()?;
+
+    Ok(
+      self
+        .hangul
+        .disassemble_grouped(&options)
+        .into_iter()
+        .map(|group| group.into_iter().map(String::from).collect())
+        .collect(),
+    )
+  }
+
   #[napi]
   pub fn get_choseong(&self) -> String {
     self.hangul.get_choseong()
   }
+
+  #[napi]
+  pub fn get_choseong_with(&self, options: ExtractOptions) -> Result<String> {
+    Ok(self.hangul.get_choseong_with(&options.to_core()?))
+  }
+
+  #[napi]
+  pub fn get_jungseong(&self) -> String {
+    self.hangul.get_jungseong()
+  }
+
+  #[napi]
+  pub fn get_jungseong_with(&self, options: ExtractOptions) -> Result<String> {
+    Ok(self.hangul.get_jungseong_with(&options.to_core()?))
+  }
+
+  #[napi]
+  pub fn get_jongseong(&self) -> String {
+    self.hangul.get_jongseong()
+  }
+
+  // 종성이 없는 음절은 `missingJongseong`을 지정하지 않으면 건너뜀
+  #[napi]
+  pub fn get_jongseong_with(&self, options: ExtractOptions) -> Result<String> {
+    Ok(self.hangul.get_jongseong_with(&options.to_core()?))
+  }
+
+  // `index`번째 음절의 초성을 바꾼 새 객체, 한글 음절이 아니면 `null`
+  #[napi]
+  pub fn with_choseong(&self, index: u32, choseong: String) -> Result<Option<Hangul>> {
+    let choseong = jamo("choseong", &choseong)?;
+    Ok(Self::wrap(
+      self.hangul.with_choseong(index as usize, choseong),
+    ))
+  }
+
+  #[napi]
+  pub fn with_jungseong(&self, index: u32, jungseong: String) -> Result<Option<Hangul>> {
+    let jungseong = jamo("jungseong", &jungseong)?;
+    Ok(Self::wrap(
+      self.hangul.with_jungseong(index as usize, jungseong),
+    ))
+  }
+
+  #[napi]
+  pub fn with_jongseong(&self, index: u32, jongseong: String) -> Result<Option<Hangul>> {
+    let jongseong = jamo("jongseong", &jongseong)?;
+    Ok(Self::wrap(
+      self.hangul.with_jongseong(index as usize, jongseong),
+    ))
+  }
+
+  #[napi]
+  pub fn remove_jongseong(&self, index: u32) -> Option<Hangul> {
+    Self::wrap(self.hangul.remove_jongseong(index as usize))
+  }
+
+  // 끝에서 자모 하나를 지운 새 객체 (닭 → 달 → 다 → ㄷ)
+  #[napi]
+  pub fn remove_last_jamo(&self) -> Hangul {
+    Self {
+      hangul: self.hangul.remove_last_jamo(),
+    }
+  }
+
+  // 반각 자모(ﾡ)를 호환형 자모(ㄱ)로 바꾼 새 객체
+  #[napi]
+  pub fn fold_halfwidth(&self) -> Hangul {
+    Self {
+      hangul: self.hangul.fold_halfwidth(),
+    }
+  }
+
+  // 한자를 한글 독음으로 바꾼 새 객체 (大韓 → 대한)
+  #[napi]
+  pub fn hanja_to_hangul(&self) -> Hangul {
+    Self {
+      hangul: self.hangul.hanja_to_hangul(),
+    }
+  }
+
+  fn wrap(hangul: Option<hangul::Hangul>) -> Option<Hangul> {
+    hangul.map(|hangul| Self { hangul })
+  }
+}
+
+// 활용형에서 찾은 기본형과 떼어 낸 어미
+#[napi(object)]
+pub struct Lemma {
+  pub lemma: String,
+  pub ending: String,
+}
+
+impl From<hangul::Lemma> for Lemma {
+  fn from(lemma: hangul::Lemma) -> Self {
+    Self {
+      lemma: lemma.lemma,
+      ending: lemma.ending,
+    }
+  }
+}
+
+// 규칙 기반 기본형 추출기, 어미나 기본형 사전을 추가한 새 객체를 반환
+#[napi]
+pub struct Lemmatizer {
+  lemmatizer: hangul::Lemmatizer,
+}
+
+#[napi]
+impl Lemmatizer {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      lemmatizer: hangul::Lemmatizer::new(),
+    }
+  }
+
+  // 어미 추가 ("-더라고요")
+  #[napi]
+  pub fn ending(&self, ending: String) -> Lemmatizer {
+    Self {
+      lemmatizer: self.lemmatizer.clone().ending(&ending),
+    }
+  }
+
+  // 기본형 사전에 추가, 사전이 비어 있지 않으면 사전에 있는 기본형만 반환
+  #[napi]
+  pub fn lemma(&self, lemma: String) -> Lemmatizer {
+    Self {
+      lemmatizer: self.lemmatizer.clone().lemma(&lemma),
+    }
+  }
+
+  #[napi]
+  pub fn lemmas(&self, lemmas: Vec<String>) -> Lemmatizer {
+    Self {
+      lemmatizer: self
+        .lemmatizer
+        .clone()
+        .lemmas(lemmas.iter().map(String::as_str)),
+    }
+  }
+
+  #[napi]
+  pub fn lemmatize(&self, word: String) -> Vec<Lemma> {
+    self
+      .lemmatizer
+      .lemmatize(&word)
+      .into_iter()
+      .map(Lemma::from)
+      .collect()
+  }
+}
+
+impl Default for Lemmatizer {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// 블록리스트 단어가 나타난 원본 문자열의 범위, `text.slice(start, end)`와 같은 UTF-16 인덱스
+#[napi(object)]
+pub struct ModerationMatch {
+  pub word: String,
+  pub start: u32,
+  pub end: u32,
+}
+
+// 자모 분리, 띄어쓰기·기호 삽입, 비슷한 문자 치환을 무시하고 블록리스트 단어를 찾음
+#[napi]
+pub struct ModerationFilter {
+  filter: hangul::ModerationFilter,
+}
+
+#[napi]
+impl ModerationFilter {
+  #[napi(constructor)]
+  pub fn new(blocklist: Vec<String>) -> Self {
+    Self {
+      filter: hangul::ModerationFilter::new(blocklist.iter().map(String::as_str)),
+    }
+  }
+
+  #[napi]
+  pub fn find_matches(&self, text: String) -> Vec<ModerationMatch> {
+    let utf16_offset = |byte: usize| text[..byte].encode_utf16().count() as u32;
+
+    self
+      .filter
+      .find_matches(&text)
+      .into_iter()
+      .map(|found| ModerationMatch {
+        start: utf16_offset(found.range.start),
+        end: utf16_offset(found.range.end),
+        word: found.word,
+      })
+      .collect()
+  }
+
+  #[napi]
+  pub fn is_match(&self, text: String) -> bool {
+    self.filter.is_match(&text)
+  }
+}
+
+// 자모가 속한 유니코드 블록
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JamoBlock {
+  Conjoining,
+  Compatibility,
+  Halfwidth,
+  ExtendedA,
+  ExtendedB,
+}
+
+#[napi(object)]
+pub struct JamoInfo {
+  // 자모 블록이 아니면 없음
+  pub block: Option<JamoBlock>,
+  pub is_consonant: bool,
+  pub is_vowel: bool,
+  pub can_be_choseong: bool,
+  pub can_be_jongseong: bool,
+  pub is_double_consonant: bool,
+  pub is_complex_jongseong: bool,
+  pub is_complex_vowel: bool,
+}
+
+// 단어 첫 음절에 두음법칙을 적용 (녀자 → 여자, 리설주 → 이설주)
+#[napi]
+pub fn apply_dueum(word: String) -> String {
+  hangul::apply_dueum(&word)
+}
+
+// 한 글자의 자모 분류
+#[napi]
+pub fn classify_jamo(ch: String) -> Result<JamoInfo> {
+  let ch = single_char("ch", &ch)?;
+
+  Ok(JamoInfo {
+    block: hangul::jamo_block(ch).map(|block| match block {
+      hangul::JamoBlock::Conjoining => JamoBlock::Conjoining,
+      hangul::JamoBlock::Compatibility => JamoBlock::Compatibility,
+      hangul::JamoBlock::Halfwidth => JamoBlock::Halfwidth,
+      hangul::JamoBlock::ExtendedA => JamoBlock::ExtendedA,
+      hangul::JamoBlock::ExtendedB => JamoBlock::ExtendedB,
+    }),
+    is_consonant: hangul::is_consonant(ch),
+    is_vowel: hangul::is_vowel(ch),
+    can_be_choseong: hangul::can_be_choseong(ch),
+    can_be_jongseong: hangul::can_be_jongseong(ch),
+    is_double_consonant: hangul::is_double_consonant(ch),
+    is_complex_jongseong: hangul::is_complex_jongseong(ch),
+    is_complex_vowel: hangul::is_complex_vowel(ch),
+  })
+}
+
+// 혼동 문자 골격, 같으면 눈으로 구별하기 어려운 문자열 ("o一o" → "ㅇㅡㅇ")
+#[napi]
+pub fn confusable_skeleton(text: String) -> String {
+  hangul::confusable_skeleton(&text)
+}
+
+// 두 문자열의 혼동 문자 골격이 같은지 확인 ("ㅇㅡㅇ", "o一o" → true)
+#[napi]
+pub fn is_confusable(a: String, b: String) -> bool {
+  hangul::is_confusable(&a, &b)
+}
+
+// 기본형에 어미를 붙인 활용형 ("먹다", "-아/어요" → "먹어요", "돕다", "-(으)면" → "도우면")
+#[napi]
+pub fn conjugate(predicate: String, ending: String) -> Result<String> {
+  hangul::conjugate(&predicate, &ending).map_err(conjugate_error)
+}
+
+// 활용 유형을 지정해 활용 (묻다를 "regular"로: 묻어요)
+#[napi]
+pub fn conjugate_as(
+  predicate: String,
+  ending: String,
+  class_name: ConjugationClass,
+) -> Result<String> {
+  hangul::conjugate_as(&predicate, &ending, class_name.into()).map_err(conjugate_error)
+}
+
+// 기본형의 활용 유형 ("돕다" → "bieupIrregular")
+#[napi]
+pub fn conjugation_class(predicate: String) -> Result<ConjugationClass> {
+  let class = hangul::conjugation_class(&predicate).map_err(conjugate_error)?;
+  Ok(class.into())
+}
+
+// 문장 끝 용언의 등급, 알 수 없으면 null
+#[napi]
+pub fn speech_level(sentence: String) -> Option<SpeechLevel> {
+  hangul::speech_level(&sentence).map(SpeechLevel::from)
+}
+
+// 문장 끝 용언을 주어진 등급으로 바꿈 ("먹어요", "hapsyo" → "먹습니다"), 용언을 알 수 없으면 null
+#[napi]
+pub fn to_speech_level(sentence: String, level: SpeechLevel) -> Option<String> {
+  hangul::to_speech_level(&sentence, level.into())
+}
+
+// 등급은 유지하고 주체 높임 -(으)시-를 넣음 ("먹어요" → "먹으세요")
+#[napi]
+pub fn to_honorific(sentence: String) -> Option<String> {
+  hangul::to_honorific(&sentence)
+}
+
+// 비교용 자모 문자열 ("시 발" → "ㅅㅣㅂㅏㄹ")
+#[napi]
+pub fn moderation_skeleton(text: String) -> String {
+  hangul::moderation_skeleton(&text)
+}
+
+// 두음법칙을 적용하면 `word`가 되는 본래 단어 목록 (이설주 → ["리설주", "니설주"])
+#[napi]
+pub fn dueum_originals(word: String) -> Vec<String> {
+  hangul::dueum_originals(&word)
+}
+
+// 괄호·원문자 한글이 나타내는 자모나 음절 (㉠ → "ㄱ", ㈎ → "가"), 아니면 null
+#[napi]
+pub fn expand_enclosed(ch: String) -> Result<Option<String>> {
+  let ch = single_char("ch", &ch)?;
+  Ok(hangul::expand_enclosed(ch).map(str::to_string))
+}
+
+// 자모나 음절을 괄호·원문자로 바꿈
+#[napi]
+pub fn to_enclosed(text: String, style: EnclosedStyle) -> Option<String> {
+  hangul::to_enclosed(&text, style.into()).map(String::from)
+}
+
+// 목록 번호용 괄호·원문자 자음, index는 0부터 (0 → ㉠)
+#[napi]
+pub fn enclosed_jamo(index: u32, style: EnclosedStyle) -> Option<String> {
+  hangul::enclosed_jamo(index as usize, style.into()).map(String::from)
+}
+
+// 목록 번호용 괄호·원문자 음절, index는 0부터 (0 → ㉮)
+#[napi]
+pub fn enclosed_syllable(index: u32, style: EnclosedStyle) -> Option<String> {
+  hangul::enclosed_syllable(index as usize, style.into()).map(String::from)
+}
+
+// KS X 1026-1에 따라 불완전한 조합형 음절에 채움 문자를 넣음
+#[napi]
+pub fn fill_incomplete_syllables(text: String) -> String {
+  hangul::fill_incomplete_syllables(&text)
+}
+
+// 문자열의 반각 자모를 호환형 자모로 바꿈 (NFKC와 같은 결과)
+#[napi]
+pub fn fold_halfwidth(text: String) -> String {
+  hangul::fold_halfwidth(&text)
+}
+
+// 한자의 본음 (李 → "리"), 독음표에 없는 한자이면 null
+#[napi]
+pub fn hanja_reading(ch: String) -> Result<Option<String>> {
+  let ch = single_char("ch", &ch)?;
+  Ok(hangul::hanja_reading(ch).map(String::from))
+}
+
+// 한자를 두음법칙에 따라 한글 독음으로 바꿈 (李舜臣 → 이순신)
+#[napi]
+pub fn hanja_to_hangul(text: String) -> String {
+  hangul::hanja_to_hangul(&text)
+}
+
+// 활용형의 기본형 후보, 그럴듯한 것부터 ("들었어" → 들다, 듣다)
+#[napi]
+pub fn lemmatize(word: String) -> Vec<Lemma> {
+  hangul::lemmatize(&word)
+    .into_iter()
+    .map(Lemma::from)
+    .collect()
+}
+
+// 조합형 또는 호환형 자모로 완성형 음절을 만듦 ("ㅎ", "ㅏ", "ㄴ" → "한")
+#[napi]
+pub fn from_jamo(choseong: String, jungseong: String, jongseong: Option<String>) -> Result<String> {
+  let letter = hangul::HangulLetter::from_jamo(
+    single_char("choseong", &choseong)?,
+    single_char("jungseong", &jungseong)?,
+    jongseong
+      .map(|jongseong| single_char("jongseong", &jongseong))
+      .transpose()?,
+  )
+  .map_err(jamo_error)?;
+
+  Ok(letter.value().to_string())
+}
+
+// (초성 0–18, 중성 0–20, 종성 0–27) 인덱스로 완성형 음절을 만듦, 종성 0은 종성 없음
+#[napi]
+pub fn from_indices(choseong: u8, jungseong: u8, jongseong: u8) -> Result<String> {
+  let letter =
+    hangul::HangulLetter::from_indices(choseong, jungseong, jongseong).map_err(jamo_error)?;
+
+  Ok(letter.value().to_string())
+}
+
+// 자모 한 글자 문자열을 초성/중성/종성으로 변환
+fn jamo<T: TryFrom<char, Error = hangul::JamoError>>(name: &str, value: &str) -> Result<T> {
+  T::try_from(single_char(name, value)?).map_err(jamo_error)
+}
+
+fn jamo_error(error: hangul::JamoError) -> Error {
+  Error::new(Status::InvalidArg, error.to_string())
+}
+
+fn conjugate_error(error: hangul::ConjugateError) -> Error {
+  Error::new(Status::InvalidArg, error.to_string())
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_length_and_original() {
+    let hangul = Hangul::new("Hello 안녕!".to_string());
+    assert_eq!(hangul.length(), 9);
+    assert_eq!(hangul.original(), "Hello 안녕!");
+  }
+
+  #[test]
+  fn test_letters() {
+    let letters = Hangul::new("닭a".to_string()).letters();
+    assert_eq!(letters.len(), 2);
+
+    assert_eq!(letters[0].original, "닭");
+    assert!(letters[0].is_hangul);
+    assert_eq!(letters[0].choseong.as_deref(), Some("ㄷ"));
+    assert_eq!(letters[0].jungseong.as_deref(), Some("ㅏ"));
+    assert_eq!(letters[0].jongseong.as_deref(), Some("ㄺ"));
+    assert!(letters[0].has_batchim);
+
+    assert_eq!(letters[1].original, "a");
+    assert!(!letters[1].is_hangul);
+    assert_eq!(letters[1].choseong, None);
+    assert_eq!(letters[1].jongseong, None);
+    assert!(!letters[1].has_batchim);
+  }
+
   #[test]
   fn test_disassemble_basic() {
     let hangul = Hangul::new("안녕".to_string());
@@ -77,6 +598,229 @@ mod tests {
     assert_eq!(hangul.disassemble(), "ㅇㅏㄴㄴㅕㅇ ㅎㅏㅅㅔㅇㅛ");
   }
 
+  #[test]
+  fn test_disassemble_with_options() {
+    let hangul = Hangul::new("닭과 ok".to_string());
+    let options = DisassembleOptions {
+      split_complex_jungseong: Some(true),
+      separator: Some("/".to_string()),
+      non_hangul: Some(options::NonHangul::Remove),
+      ..Default::default()
+    };
+    assert_eq!(hangul.disassemble_with(options).unwrap(), "ㄷㅏㄹㄱ/ㄱㅗㅏ");
+
+    let grouped = hangul.disassemble_grouped(None).unwrap();
+    assert_eq!(grouped[0], ["ㄷ", "ㅏ", "ㄹ", "ㄱ"]);
+    assert_eq!(grouped[2], [" "]);
+  }
+
+  #[test]
+  fn test_extract_with_options() {
+    let hangul = Hangul::new("까치, 닭!".to_string());
+    let options = ExtractOptions {
+      non_hangul: Some(options::NonHangul::Remove),
+      keep_whitespace: Some(true),
+      fold_double_consonants: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(hangul.get_choseong_with(options).unwrap(), "ㄱㅊ ㄷ");
+    assert_eq!(hangul.get_jungseong(), "ㅏㅣ, ㅏ!");
+
+    let options = ExtractOptions {
+      missing_jongseong: Some("_".to_string()),
+      ..Default::default()
+    };
+    assert_eq!(hangul.get_jongseong_with(options).unwrap(), "__, ㄺ!");
+  }
+
+  #[test]
+  fn test_edit_components() {
+    let hangul = Hangul::new("한글".to_string());
+    let edited = hangul.with_choseong(0, "ㄱ".to_string()).unwrap().unwrap();
+    assert_eq!(edited.original(), "간글");
+    let edited = hangul.with_jongseong(1, "ㄺ".to_string()).unwrap().unwrap();
+    assert_eq!(edited.original(), "한긁");
+    assert!(hangul.with_jongseong(1, "ㄸ".to_string()).is_err());
+    assert!(hangul
+      .with_jungseong(2, "ㅏ".to_string())
+      .unwrap()
+      .is_none());
+
+    assert_eq!(hangul.remove_jongseong(0).unwrap().original(), "하글");
+    assert_eq!(hangul.remove_last_jamo().original(), "한그");
+  }
+
+  #[test]
+  fn test_classify_jamo() {
+    let info = classify_jamo("ㄸ".to_string()).unwrap();
+    assert_eq!(info.block, Some(JamoBlock::Compatibility));
+    assert!(info.is_consonant && info.can_be_choseong && info.is_double_consonant);
+    assert!(!info.can_be_jongseong);
+
+    let info = classify_jamo("가".to_string()).unwrap();
+    assert!(info.block.is_none() && !info.is_consonant && !info.is_vowel);
+    assert!(classify_jamo("ㄱㄴ".to_string()).is_err());
+  }
+
+  #[test]
+  fn test_old_hangul() {
+    let hangul = Hangul::new("말\u{110A}\u{119E}미".to_string());
+    assert_eq!(hangul.length(), 3);
+    assert_eq!(hangul.get_choseong(), "ㅁㅆㅁ");
+    assert_eq!(
+      fill_incomplete_syllables("\u{1100}".to_string()),
+      "\u{1100}\u{1160}"
+    );
+  }
+
+  #[test]
+  fn test_enclosed() {
+    assert_eq!(
+      expand_enclosed("㉠".to_string()).unwrap().as_deref(),
+      Some("ㄱ")
+    );
+    assert_eq!(expand_enclosed("가".to_string()).unwrap(), None);
+    assert_eq!(
+      to_enclosed("가".to_string(), EnclosedStyle::Parenthesized).as_deref(),
+      Some("㈎")
+    );
+    assert_eq!(
+      enclosed_jamo(1, EnclosedStyle::Circled).as_deref(),
+      Some("㉡")
+    );
+    assert_eq!(
+      enclosed_syllable(0, EnclosedStyle::Circled).as_deref(),
+      Some("㉮")
+    );
+    assert_eq!(enclosed_jamo(14, EnclosedStyle::Circled), None);
+  }
+
+  #[test]
+  fn test_lemmatize() {
+    let lemmas = |lemmas: Vec<Lemma>| {
+      lemmas
+        .into_iter()
+        .map(|lemma| lemma.lemma)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(lemmas(lemmatize("들었어".to_string())), ["들다", "듣다"]);
+    assert_eq!(lemmatize("해요".to_string())[0].ending, "-아/어요");
+
+    let lemmatizer = Lemmatizer::new()
+      .ending("-더라고요".to_string())
+      .lemmas(vec!["듣다".to_string()]);
+    assert_eq!(lemmas(lemmatizer.lemmatize("들었어".to_string())), ["듣다"]);
+    assert!(lemmatizer.lemmatize("먹더라고요".to_string()).is_empty());
+    assert_eq!(
+      lemmas(
+        Lemmatizer::new()
+          .ending("-더라고요".to_string())
+          .lemmatize("먹더라고요".to_string())
+      ),
+      ["먹다"]
+    );
+  }
+
+  #[test]
+  fn test_speech_level() {
+    let to_level = |sentence: &str, level| to_speech_level(sentence.to_string(), level);
+    assert_eq!(
+      to_level("먹어요", SpeechLevel::Hapsyo).as_deref(),
+      Some("먹습니다")
+    );
+    assert_eq!(
+      to_level("학생이에요", SpeechLevel::Hae).as_deref(),
+      Some("학생이야")
+    );
+    assert_eq!(to_level("hello", SpeechLevel::Haeyo), None);
+    assert_eq!(
+      to_honorific("감사해요".to_string()).as_deref(),
+      Some("감사하세요")
+    );
+    assert_eq!(speech_level("먹었어".to_string()), Some(SpeechLevel::Hae));
+    assert_eq!(speech_level("안녕".to_string()), None);
+  }
+
+  #[test]
+  fn test_moderation_filter() {
+    let filter = ModerationFilter::new(vec!["시발".to_string()]);
+    let matches = filter.find_matches("야 ㅅ ㅣ 발".to_string());
+
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].word, "시발");
+    assert_eq!((matches[0].start, matches[0].end), (2, 7));
+    assert!(filter.is_match("ㅅ1발".to_string()));
+    assert!(!filter.is_match("시계 발견".to_string()));
+    assert_eq!(moderation_skeleton("시 . 발".to_string()), "ㅅㅣㅂㅏㄹ");
+  }
+
+  #[test]
+  fn test_confusable() {
+    assert_eq!(confusable_skeleton("o一o".to_string()), "ㅇㅡㅇ");
+    assert!(is_confusable("ㅣㅣ".to_string(), "l|".to_string()));
+    assert!(!is_confusable("한".to_string(), "ㅎㅏㄴ".to_string()));
+  }
+
+  #[test]
+  fn test_conjugate() {
+    let conjugated = |ending: &str| conjugate("돕다".to_string(), ending.to_string()).unwrap();
+    assert_eq!(conjugated("-아/어요"), "도와요");
+    assert_eq!(conjugated("-(으)면"), "도우면");
+    assert_eq!(
+      conjugate_as(
+        "묻다".to_string(),
+        "-아/어요".to_string(),
+        ConjugationClass::Regular
+      )
+      .unwrap(),
+      "묻어요"
+    );
+    assert_eq!(
+      conjugation_class("듣다".to_string()).unwrap(),
+      ConjugationClass::DigeutIrregular
+    );
+    assert!(conjugate("먹".to_string(), "-고".to_string()).is_err());
+  }
+
+  #[test]
+  fn test_dueum() {
+    assert_eq!(apply_dueum("리설주".to_string()), "이설주");
+    assert_eq!(dueum_originals("이설주".to_string()), ["리설주", "니설주"]);
+  }
+
+  #[test]
+  fn test_hanja() {
+    assert_eq!(
+      hanja_reading("李".to_string()).unwrap().as_deref(),
+      Some("리")
+    );
+    assert_eq!(hanja_reading("가".to_string()).unwrap(), None);
+    assert_eq!(hanja_to_hangul("男女".to_string()), "남녀");
+
+    let hangul = Hangul::new("大韓民國".to_string());
+    assert_eq!(hangul.hanja_to_hangul().get_choseong(), "ㄷㅎㅁㄱ");
+  }
+
+  #[test]
+  fn test_fold_halfwidth() {
+    let hangul = Hangul::new("\u{FFA1}\u{FFC2}\u{FFA4}".to_string());
+    assert_eq!(hangul.fold_halfwidth().disassemble(), "ㄱㅏㄴ");
+    assert_eq!(fold_halfwidth("\u{FFBE}\u{FFC2}".to_string()), "ㅎㅏ");
+  }
+
+  #[test]
+  fn test_from_jamo_and_indices() {
+    assert_eq!(
+      from_jamo("ㄷ".to_string(), "ㅏ".to_string(), Some("ㄺ".to_string())).unwrap(),
+      "닭"
+    );
+    assert!(from_jamo("ㄱ".to_string(), "ㅏ".to_string(), Some("ㄸ".to_string())).is_err());
+    assert!(from_jamo("ㄱㄱ".to_string(), "ㅏ".to_string(), None).is_err());
+
+    assert_eq!(from_indices(18, 20, 27).unwrap(), "힣");
+    assert!(from_indices(19, 0, 0).is_err());
+  }
+
   #[test]
   fn test_get_choseong_basic() {
     let hangul = Hangul::new("안녕".to_string());
@@ -0,0 +1,287 @@
+use napi::bindgen_prelude::*;
+
+// 생략한 항목은 `Hangul.disassemble()`과 같은 기본값을 사용
+#[napi(object)]
+#[derive(Default)]
+pub struct DisassembleOptions {
+  // 기본값은 "compatibility"
+  pub jamo_form: Option<JamoForm>,
+  pub split_complex_jongseong: Option<bool>,
+  pub split_complex_jungseong: Option<bool>,
+  pub separator: Option<String>,
+  // 기본값은 "keep"
+  pub non_hangul: Option<NonHangul>,
+  // nonHangul이 "replace"일 때 넣을 한 글자
+  pub replacement: Option<String>,
+  // 괄호·원문자 한글(㉠, ㈎)을 음절처럼 분해
+  pub expand_enclosed: Option<bool>,
+}
+
+impl DisassembleOptions {
+  pub(crate) fn to_core(&self) -> Result<hangul::DisassembleOptions> {
+    let mut options = hangul::DisassembleOptions::new()
+      .jamo_form(self.jamo_form.unwrap_or(JamoForm::Compatibility).into())
+      .non_hangul(non_hangul(self.non_hangul, self.replacement.as_deref())?);
+
+    if let Some(split) = self.split_complex_jongseong {
+      options = options.split_complex_jongseong(split);
+    }
+    if let Some(split) = self.split_complex_jungseong {
+      options = options.split_complex_jungseong(split);
+    }
+    if let Some(separator) = &self.separator {
+      options = options.separator(separator.as_str());
+    }
+    if let Some(expand) = self.expand_enclosed {
+      options = options.expand_enclosed(expand);
+    }
+
+    Ok(options)
+  }
+}
+
+// 생략한 항목은 `Hangul.getChoseong()`과 같은 기본값을 사용
+#[napi(object)]
+#[derive(Default)]
+pub struct ExtractOptions {
+  // 기본값은 "compatibility"
+  pub jamo_form: Option<JamoForm>,
+  // 기본값은 "keep"
+  pub non_hangul: Option<NonHangul>,
+  // nonHangul이 "replace"일 때 넣을 한 글자
+  pub replacement: Option<String>,
+  pub keep_whitespace: Option<bool>,
+  pub fold_double_consonants: Option<bool>,
+  // 종성이 없는 음절 자리에 넣을 한 글자
+  pub missing_jongseong: Option<String>,
+}
+
+impl ExtractOptions {
+  pub(crate) fn to_core(&self) -> Result<hangul::ExtractOptions> {
+    let mut options = hangul::ExtractOptions::new()
+      .jamo_form(self.jamo_form.unwrap_or(JamoForm::Compatibility).into())
+      .non_hangul(non_hangul(self.non_hangul, self.replacement.as_deref())?)
+      .keep_whitespace(self.keep_whitespace.unwrap_or(false))
+      .fold_double_consonants(self.fold_double_consonants.unwrap_or(false));
+
+    if let Some(placeholder) = &self.missing_jongseong {
+      options = options.missing_jongseong(single_char("missingJongseong", placeholder)?);
+    }
+
+    Ok(options)
+  }
+}
+
+// 자모 형식
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JamoForm {
+  Compatibility,
+  Conjoining,
+}
+
+impl From<JamoForm> for hangul::JamoForm {
+  fn from(form: JamoForm) -> Self {
+    match form {
+      JamoForm::Compatibility => Self::Compatibility,
+      JamoForm::Conjoining => Self::Conjoining,
+    }
+  }
+}
+
+// 한글이 아닌 문자 처리, "replace"는 `replacement`와 함께 사용
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonHangul {
+  Keep,
+  Remove,
+  Replace,
+}
+
+fn non_hangul(value: Option<NonHangul>, replacement: Option<&str>) -> Result<hangul::NonHangul> {
+  match value {
+    None | Some(NonHangul::Keep) => Ok(hangul::NonHangul::Keep),
+    Some(NonHangul::Remove) => Ok(hangul::NonHangul::Remove),
+    Some(NonHangul::Replace) => Ok(hangul::NonHangul::Replace(single_char(
+      "replacement",
+      replacement.unwrap_or_default(),
+    )?)),
+  }
+}
+
+// 원문자(㉠, ㉮) 또는 괄호 문자(㈀, ㈎)
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnclosedStyle {
+  Circled,
+  Parenthesized,
+}
+
+impl From<EnclosedStyle> for hangul::EnclosedStyle {
+  fn from(style: EnclosedStyle) -> Self {
+    match style {
+      EnclosedStyle::Circled => Self::Circled,
+      EnclosedStyle::Parenthesized => Self::Parenthesized,
+    }
+  }
+}
+
+// 활용 유형 ("regular", "bieupIrregular", "digeutIrregular" 등)
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConjugationClass {
+  Regular,
+  BieupIrregular,
+  DigeutIrregular,
+  SiotIrregular,
+  HieutIrregular,
+  ReuIrregular,
+  ReoIrregular,
+  UIrregular,
+  YeoIrregular,
+}
+
+impl From<ConjugationClass> for hangul::ConjugationClass {
+  fn from(class: ConjugationClass) -> Self {
+    match class {
+      ConjugationClass::Regular => Self::Regular,
+      ConjugationClass::BieupIrregular => Self::BieupIrregular,
+      ConjugationClass::DigeutIrregular => Self::DigeutIrregular,
+      ConjugationClass::SiotIrregular => Self::SiotIrregular,
+      ConjugationClass::HieutIrregular => Self::HieutIrregular,
+      ConjugationClass::ReuIrregular => Self::ReuIrregular,
+      ConjugationClass::ReoIrregular => Self::ReoIrregular,
+      ConjugationClass::UIrregular => Self::UIrregular,
+      ConjugationClass::YeoIrregular => Self::YeoIrregular,
+    }
+  }
+}
+
+impl From<hangul::ConjugationClass> for ConjugationClass {
+  fn from(class: hangul::ConjugationClass) -> Self {
+    match class {
+      hangul::ConjugationClass::Regular => Self::Regular,
+      hangul::ConjugationClass::BieupIrregular => Self::BieupIrregular,
+      hangul::ConjugationClass::DigeutIrregular => Self::DigeutIrregular,
+      hangul::ConjugationClass::SiotIrregular => Self::SiotIrregular,
+      hangul::ConjugationClass::HieutIrregular => Self::HieutIrregular,
+      hangul::ConjugationClass::ReuIrregular => Self::ReuIrregular,
+      hangul::ConjugationClass::ReoIrregular => Self::ReoIrregular,
+      hangul::ConjugationClass::UIrregular => Self::UIrregular,
+      hangul::ConjugationClass::YeoIrregular => Self::YeoIrregular,
+    }
+  }
+}
+
+// 상대 높임법 등급, "hae"(해체), "haeyo"(해요체), "hapsyo"(합쇼체)
+#[napi(string_enum = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeechLevel {
+  Hae,
+  Haeyo,
+  Hapsyo,
+}
+
+impl From<SpeechLevel> for hangul::SpeechLevel {
+  fn from(level: SpeechLevel) -> Self {
+    match level {
+      SpeechLevel::Hae => Self::Hae,
+      SpeechLevel::Haeyo => Self::Haeyo,
+      SpeechLevel::Hapsyo => Self::Hapsyo,
+    }
+  }
+}
+
+impl From<hangul::SpeechLevel> for SpeechLevel {
+  fn from(level: hangul::SpeechLevel) -> Self {
+    match level {
+      hangul::SpeechLevel::Hae => Self::Hae,
+      hangul::SpeechLevel::Haeyo => Self::Haeyo,
+      hangul::SpeechLevel::Hapsyo => Self::Hapsyo,
+    }
+  }
+}
+
+pub(crate) fn single_char(name: &str, value: &str) -> Result<char> {
+  let mut chars = value.chars();
+
+  match (chars.next(), chars.next()) {
+    (Some(ch), None) => Ok(ch),
+    _ => Err(invalid_option(name, value)),
+  }
+}
+
+fn invalid_option(name: &str, value: &str) -> Error {
+  Error::new(
+    Status::InvalidArg,
+    format!("Invalid value for {}: {}", name, value),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_core() {
+    assert_eq!(
+      DisassembleOptions::default().to_core().unwrap(),
+      hangul::DisassembleOptions::default()
+    );
+
+    let options = DisassembleOptions {
+      jamo_form: Some(JamoForm::Conjoining),
+      split_complex_jongseong: Some(false),
+      separator: Some("/".to_string()),
+      non_hangul: Some(NonHangul::Remove),
+      expand_enclosed: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(
+      options.to_core().unwrap(),
+      hangul::DisassembleOptions::new()
+        .jamo_form(hangul::JamoForm::Conjoining)
+        .split_complex_jongseong(false)
+        .separator("/")
+        .non_hangul(hangul::NonHangul::Remove)
+        .expand_enclosed(true)
+    );
+  }
+
+  #[test]
+  fn test_extract_options_to_core() {
+    assert_eq!(
+      ExtractOptions::default().to_core().unwrap(),
+      hangul::ExtractOptions::default()
+    );
+
+    let options = ExtractOptions {
+      non_hangul: Some(NonHangul::Replace),
+      replacement: Some("*".to_string()),
+      keep_whitespace: Some(true),
+      fold_double_consonants: Some(true),
+      missing_jongseong: Some("_".to_string()),
+      ..Default::default()
+    };
+    assert_eq!(
+      options.to_core().unwrap(),
+      hangul::ExtractOptions::new()
+        .non_hangul(hangul::NonHangul::Replace('*'))
+        .keep_whitespace(true)
+        .fold_double_consonants(true)
+        .missing_jongseong('_')
+    );
+
+    let missing_replacement = ExtractOptions {
+      non_hangul: Some(NonHangul::Replace),
+      ..Default::default()
+    };
+    assert!(missing_replacement.to_core().is_err());
+
+    let long_placeholder = ExtractOptions {
+      missing_jongseong: Some("__".to_string()),
+      ..Default::default()
+    };
+    assert!(long_placeholder.to_core().is_err());
+  }
+}
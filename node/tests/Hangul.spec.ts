@@ -1,5 +1,38 @@
 import { describe, it, expect } from "vitest";
-import { Hangul } from "../index";
+import {
+	Hangul,
+	Lemmatizer,
+	ModerationFilter,
+	applyDueum,
+	classifyJamo,
+	confusableSkeleton,
+	conjugate,
+	conjugateAs,
+	conjugationClass,
+	disassembleBatch,
+	disassembleBatchAsync,
+	dueumOriginals,
+	enclosedJamo,
+	enclosedSyllable,
+	expandEnclosed,
+	fillIncompleteSyllables,
+	foldHalfwidth,
+	fromIndices,
+	fromJamo,
+	getChoseongBatch,
+	getChoseongBatchAsync,
+	hanjaReading,
+	hanjaToHangul,
+	isConfusable,
+	lemmatize,
+	moderationSkeleton,
+	normalizeBatch,
+	normalizeBatchAsync,
+	speechLevel,
+	toEnclosed,
+	toHonorific,
+	toSpeechLevel,
+} from "../index";
 
 describe("Hangul class", () => {
 	describe("disassemble method", () => {
@@ -40,6 +73,41 @@ describe("Hangul class", () => {
 		});
 	});
 
+	describe("disassembleWith method", () => {
+		// 옵션에 따라 분해 형식이 바뀌는지 테스트
+		it("should apply disassemble options", () => {
+			const hangul = new Hangul("닭과 ok");
+			expect(hangul.disassembleWith({})).toBe(hangul.disassemble());
+			expect(hangul.disassembleWith({ splitComplexJongseong: false })).toBe(
+				"ㄷㅏㄺㄱㅘ ok",
+			);
+			expect(
+				hangul.disassembleWith({
+					splitComplexJungseong: true,
+					separator: "/",
+					nonHangul: "remove",
+				}),
+			).toBe("ㄷㅏㄹㄱ/ㄱㅗㅏ");
+			expect(new Hangul("한").disassembleWith({ jamoForm: "conjoining" })).toBe(
+				"\u1112\u1161\u11AB",
+			);
+		});
+
+		// 잘못된 옵션 값은 예외를 던지는지 테스트
+		it("should throw on invalid option values", () => {
+			expect(() => new Hangul("한").disassembleWith({ jamoForm: "x" })).toThrow();
+		});
+
+		// 글자마다 자모 배열을 반환하는지 테스트
+		it("should group jamo per letter", () => {
+			expect(new Hangul("값 a").disassembleGrouped()).toEqual([
+				["ㄱ", "ㅏ", "ㅂ", "ㅅ"],
+				[" "],
+				["a"],
+			]);
+		});
+	});
+
 	describe("getChoseong method", () => {
 		// 기본 한글 문자에서 초성을 올바르게 추출하는지 테스트
 		it("should correctly extract choseong from basic Hangul characters", () => {
@@ -71,4 +139,355 @@ describe("Hangul class", () => {
 			expect(new Hangul("안녕 하세요").getChoseong()).toBe("ㅇㄴ ㅎㅅㅇ");
 		});
 	});
+
+	describe("extraction options", () => {
+		// 옵션에 따라 한글이 아닌 문자와 된소리를 처리하는지 테스트
+		it("should apply extraction options to choseong", () => {
+			const hangul = new Hangul("까치, 뛰다!");
+			expect(hangul.getChoseongWith({})).toBe(hangul.getChoseong());
+			expect(
+				hangul.getChoseongWith({
+					nonHangul: "remove",
+					keepWhitespace: true,
+					foldDoubleConsonants: true,
+				}),
+			).toBe("ㄱㅊ ㄷㄷ");
+			expect(
+				hangul.getChoseongWith({ nonHangul: "replace", replacement: "?" }),
+			).toBe("ㄲㅊ??ㄸㄷ?");
+		});
+
+		// 중성과 종성을 추출하는지 테스트
+		it("should extract jungseong and jongseong", () => {
+			const hangul = new Hangul("닭과 밖에");
+			expect(hangul.getJungseong()).toBe("ㅏㅘ ㅏㅔ");
+			expect(hangul.getJongseong()).toBe("ㄺ ㄲ");
+			expect(hangul.getJongseongWith({ missingJongseong: "_" })).toBe("ㄺ_ ㄲ_");
+		});
+
+		// 잘못된 옵션 값은 예외를 던지는지 테스트
+		it("should throw on invalid option values", () => {
+			expect(() => new Hangul("a").getChoseongWith({ nonHangul: "replace" })).toThrow();
+			expect(() => new Hangul("가").getJongseongWith({ missingJongseong: "__" })).toThrow();
+		});
+	});
+
+	describe("letters method", () => {
+		// 글자마다 초성/중성/종성 구조를 반환하는지 테스트
+		it("should return per-letter structure", () => {
+			expect(new Hangul("닭a").letters()).toEqual([
+				{
+					original: "닭",
+					isHangul: true,
+					choseong: "ㄷ",
+					jungseong: "ㅏ",
+					jongseong: "ㄺ",
+					hasBatchim: true,
+				},
+				{ original: "a", isHangul: false, hasBatchim: false },
+			]);
+		});
+
+		// 받침이 없는 음절은 종성이 없는지 테스트
+		it("should omit jongseong for letters without batchim", () => {
+			const [letter] = new Hangul("가").letters();
+			expect(letter.jongseong).toBeUndefined();
+			expect(letter.hasBatchim).toBe(false);
+		});
+	});
+
+	describe("component editing", () => {
+		// 지정한 음절의 초성/중성/종성을 바꾸는지 테스트
+		it("should replace components of a syllable", () => {
+			const hangul = new Hangul("한글");
+			expect(hangul.withChoseong(0, "ㄱ")?.original).toBe("간글");
+			expect(hangul.withJungseong(1, "ㅣ")?.original).toBe("한길");
+			expect(hangul.withJongseong(1, "ㄺ")?.original).toBe("한긁");
+			expect(hangul.removeJongseong(0)?.original).toBe("하글");
+			expect(hangul.removeJongseong(5)).toBeNull();
+			expect(() => hangul.withJongseong(0, "ㄸ")).toThrow();
+		});
+
+		// 끝에서 자모를 하나씩 지우는지 테스트
+		it("should remove the last jamo like backspace", () => {
+			let hangul = new Hangul("닭");
+			const steps: string[] = [];
+			while (hangul.length > 0) {
+				hangul = hangul.removeLastJamo();
+				steps.push(hangul.original);
+			}
+			expect(steps).toEqual(["달", "다", "ㄷ", ""]);
+			expect(new Hangul("사과").removeLastJamo().original).toBe("사고");
+		});
+	});
+
+	describe("length and original getters", () => {
+		// 글자 수와 원본 문자열을 반환하는지 테스트
+		it("should return letter count and original text", () => {
+			const hangul = new Hangul("Hello 안녕!");
+			expect(hangul.length).toBe(9);
+			expect(hangul.original).toBe("Hello 안녕!");
+		});
+	});
+});
+
+describe("batch functions", () => {
+	const texts = ["안녕", "Hello 한글", ""];
+	const nfd = "\u1112\u1161\u11AB";
+
+	// 배열 단위로 분해와 초성 추출을 수행하는지 테스트
+	it("should disassemble and extract choseong for every item", () => {
+		expect(disassembleBatch(texts)).toEqual([
+			"ㅇㅏㄴㄴㅕㅇ",
+			"Hello ㅎㅏㄴㄱㅡㄹ",
+			"",
+		]);
+		expect(getChoseongBatch(texts)).toEqual(["ㅇㄴ", "Hello ㅎㄱ", ""]);
+	});
+
+	// NFC/NFD 정규화와 잘못된 정규화 형식을 처리하는지 테스트
+	it("should normalize every item", () => {
+		expect(normalizeBatch([nfd])).toEqual(["한"]);
+		expect(normalizeBatch(["한"], "NFD")).toEqual([nfd]);
+		// @ts-expect-error 목록에 없는 값은 런타임에 거부
+		expect(() => normalizeBatch(["한"], "NFKC")).toThrow();
+	});
+
+	// 비동기 함수가 같은 결과를 반환하는지 테스트
+	it("should resolve the same results asynchronously", async () => {
+		await expect(disassembleBatchAsync(texts)).resolves.toEqual(
+			disassembleBatch(texts),
+		);
+		await expect(getChoseongBatchAsync(texts)).resolves.toEqual(
+			getChoseongBatch(texts),
+		);
+		await expect(normalizeBatchAsync([nfd])).resolves.toEqual(["한"]);
+	});
+});
+
+describe("syllable construction", () => {
+	// 호환형/조합형 자모와 인덱스로 음절을 만드는지 테스트
+	it("should compose syllables from jamo and indices", () => {
+		expect(fromJamo("ㄷ", "ㅏ", "ㄺ")).toBe("닭");
+		expect(fromJamo("\u1104", "\u1161")).toBe("따");
+		expect(fromIndices(18, 20, 27)).toBe("힣");
+		expect(fromIndices(0, 0, 0)).toBe("가");
+	});
+
+	// 종성이 될 수 없는 자음이나 범위를 벗어난 인덱스는 예외를 던지는지 테스트
+	it("should throw on invalid components", () => {
+		expect(() => fromJamo("ㄱ", "ㅏ", "ㄸ")).toThrow();
+		expect(() => fromJamo("ㄳ", "ㅏ")).toThrow();
+		expect(() => fromIndices(19, 0, 0)).toThrow();
+	});
+});
+
+describe("classifyJamo", () => {
+	// 자모 블록과 위치별 사용 가능 여부를 반환하는지 테스트
+	it("should classify jamo", () => {
+		expect(classifyJamo("ㄸ")).toEqual({
+			block: "compatibility",
+			isConsonant: true,
+			isVowel: false,
+			canBeChoseong: true,
+			canBeJongseong: false,
+			isDoubleConsonant: true,
+			isComplexJongseong: false,
+			isComplexVowel: false,
+		});
+		expect(classifyJamo("\u11AA").isComplexJongseong).toBe(true);
+		expect(classifyJamo("ㅘ").isComplexVowel).toBe(true);
+		expect(classifyJamo("가").block).toBeUndefined();
+	});
+
+	// 한 글자가 아니면 예외를 던지는지 테스트
+	it("should throw when not given a single character", () => {
+		expect(() => classifyJamo("ㄱㄴ")).toThrow();
+	});
+});
+
+describe("old Hangul", () => {
+	// 완성형이 없는 옛한글 음절을 한 글자로 다루는지 테스트
+	it("should treat old Hangul clusters as single letters", () => {
+		const hangul = new Hangul("나랏말\u110A\u119E미");
+		expect(hangul.length).toBe(5);
+		expect(hangul.disassemble()).toBe("ㄴㅏㄹㅏㅅㅁㅏㄹㅆㆍㅁㅣ");
+		expect(hangul.getChoseong()).toBe("ㄴㄹㅁㅆㅁ");
+	});
+
+	// 불완전한 음절에 채움 문자를 넣는지 테스트
+	it("should fill incomplete syllables", () => {
+		expect(fillIncompleteSyllables("\u1100")).toBe("\u1100\u1160");
+		expect(fillIncompleteSyllables("\u11A8")).toBe("\u115F\u1160\u11A8");
+	});
+});
+
+describe("halfwidth jamo", () => {
+	// 반각 자모를 호환형 자모로 바꾸는지 테스트
+	it("should fold halfwidth jamo", () => {
+		expect(foldHalfwidth("\uFFA1\uFFC2\uFFA4")).toBe("ㄱㅏㄴ");
+		expect(foldHalfwidth("한글 ok")).toBe("한글 ok");
+	});
+
+	// 반각 자모를 접은 뒤 일반 자모처럼 분해하는지 테스트
+	it("should disassemble folded halfwidth jamo", () => {
+		const hangul = new Hangul("\uFFA1\uFFC2\uFFA4").foldHalfwidth();
+		expect(hangul.original).toBe("ㄱㅏㄴ");
+		expect(hangul.disassemble()).toBe("ㄱㅏㄴ");
+	});
+});
+
+describe("enclosed Hangul", () => {
+	// 괄호·원문자 한글을 자모나 음절로 바꾸는지 테스트
+	it("should expand enclosed Hangul", () => {
+		expect(expandEnclosed("㉠")).toBe("ㄱ");
+		expect(expandEnclosed("㈎")).toBe("가");
+		expect(expandEnclosed("㈜")).toBe("주");
+		expect(expandEnclosed("가")).toBeNull();
+	});
+
+	// 분해 옵션으로 괄호·원문자 한글을 분해하는지 테스트
+	it("should disassemble enclosed Hangul with expandEnclosed", () => {
+		const hangul = new Hangul("㉠ ㈎호");
+		expect(hangul.disassemble()).toBe("㉠ ㈎ㅎㅗ");
+		expect(hangul.disassembleWith({ expandEnclosed: true })).toBe("ㄱ ㄱㅏㅎㅗ");
+	});
+
+	// 목록 번호용 괄호·원문자를 만드는지 테스트
+	it("should generate enclosed list markers", () => {
+		expect(toEnclosed("ㄱ", "circled")).toBe("㉠");
+		expect(toEnclosed("가", "parenthesized")).toBe("㈎");
+		expect(enclosedJamo(1, "circled")).toBe("㉡");
+		expect(enclosedSyllable(2, "parenthesized")).toBe("㈐");
+		expect(enclosedSyllable(14, "circled")).toBeNull();
+		// @ts-expect-error 목록에 없는 값은 런타임에 거부
+		expect(() => enclosedJamo(0, "square")).toThrow();
+	});
+});
+
+describe("hanja", () => {
+	// 한자의 본음을 반환하는지 테스트
+	it("should return the reading of a hanja", () => {
+		expect(hanjaReading("李")).toBe("리");
+		expect(hanjaReading("女")).toBe("녀");
+		expect(hanjaReading("가")).toBeNull();
+	});
+
+	// 두음법칙을 적용해 한글로 바꾸는지 테스트
+	it("should convert hanja with the initial sound rule", () => {
+		expect(hanjaToHangul("李舜臣")).toBe("이순신");
+		expect(hanjaToHangul("女子 男女")).toBe("여자 남녀");
+		expect(hanjaToHangul("大韓民國")).toBe("대한민국");
+	});
+
+	// 한자가 섞인 문자열에서 초성을 추출하는지 테스트
+	it("should extract choseong from hanja", () => {
+		expect(new Hangul("大韓民國").hanjaToHangul().getChoseong()).toBe("ㄷㅎㅁㄱ");
+	});
+});
+
+describe("dueum", () => {
+	// 단어 첫 음절에 두음법칙을 적용하는지 테스트
+	it("should apply the initial sound rule", () => {
+		expect(applyDueum("녀자")).toBe("여자");
+		expect(applyDueum("래일")).toBe("내일");
+		expect(applyDueum("리설주")).toBe("이설주");
+		expect(applyDueum("남녀")).toBe("남녀");
+	});
+
+	// 두음법칙을 적용하기 전의 단어 후보를 반환하는지 테스트
+	it("should list the originals", () => {
+		expect(dueumOriginals("이설주")).toEqual(["리설주", "니설주"]);
+		expect(dueumOriginals("노인")).toEqual(["로인"]);
+		expect(dueumOriginals("김정은")).toEqual([]);
+	});
+});
+
+describe("conjugate", () => {
+	// 기본형에 어미를 붙여 활용하는지 테스트
+	it("should conjugate predicates", () => {
+		expect(conjugate("먹다", "-아/어요")).toBe("먹어요");
+		expect(conjugate("돕다", "-(으)면")).toBe("도우면");
+		expect(conjugate("하다", "-았/었습니다")).toBe("했습니다");
+		expect(conjugate("부르다", "-아/어")).toBe("불러");
+	});
+
+	// 활용 유형을 지정하거나 확인하는지 테스트
+	it("should use the given conjugation class", () => {
+		expect(conjugateAs("묻다", "-아/어요", "regular")).toBe("묻어요");
+		expect(conjugateAs("묻다", "-아/어요", "digeutIrregular")).toBe("물어요");
+		expect(conjugationClass("돕다")).toBe("bieupIrregular");
+		expect(() => conjugateAs("묻다", "-고", "unknown")).toThrow();
+		expect(() => conjugate("먹", "-고")).toThrow();
+	});
+});
+
+describe("lemmatize", () => {
+	// 활용형의 기본형을 그럴듯한 것부터 찾는지 테스트
+	it("should find lemmas", () => {
+		expect(lemmatize("먹었습니다")).toEqual([{ lemma: "먹다", ending: "-았/었습니다" }]);
+		expect(lemmatize("들었어").map(({ lemma }) => lemma)).toEqual(["들다", "듣다"]);
+		expect(lemmatize("해요").map(({ lemma }) => lemma)).toEqual(["하다"]);
+		expect(lemmatize("hello")).toEqual([]);
+	});
+
+	// 어미와 기본형 사전을 추가한 추출기 테스트
+	it("should use custom endings and dictionary", () => {
+		const lemmatizer = new Lemmatizer().ending("-더라고요");
+		expect(lemmatizer.lemmatize("먹더라고요")[0].lemma).toBe("먹다");
+		expect(
+			new Lemmatizer()
+				.lemmas(["듣다"])
+				.lemmatize("들었어")
+				.map(({ lemma }) => lemma),
+		).toEqual(["듣다"]);
+	});
+});
+
+describe("speech level", () => {
+	// 문장 끝 용언의 등급을 바꾸는지 테스트
+	it("should convert speech levels", () => {
+		expect(toSpeechLevel("먹어요", "hapsyo")).toBe("먹습니다");
+		expect(toSpeechLevel("감사해요", "hae")).toBe("감사해");
+		expect(toSpeechLevel("어디 가요?", "hapsyo")).toBe("어디 갑니까?");
+		expect(toSpeechLevel("학생이에요", "hapsyo")).toBe("학생입니다");
+		expect(toSpeechLevel("hello", "haeyo")).toBeNull();
+		// @ts-expect-error 목록에 없는 값은 런타임에 거부
+		expect(() => toSpeechLevel("먹어요", "formal")).toThrow();
+	});
+
+	// 주체 높임과 등급 확인 테스트
+	it("should add honorifics and detect levels", () => {
+		expect(toHonorific("먹어요")).toBe("먹으세요");
+		expect(toHonorific("갔습니다.")).toBe("가셨습니다.");
+		expect(speechLevel("먹었어")).toBe("hae");
+		expect(speechLevel("감사합니다.")).toBe("hapsyo");
+		expect(speechLevel("안녕")).toBeNull();
+	});
+});
+
+describe("moderation", () => {
+	// 자모 분리나 기호 삽입을 무시하고 블록리스트 단어를 찾는지 테스트
+	it("should find blocklisted words", () => {
+		const filter = new ModerationFilter(["시발", "개새끼"]);
+		const text = "야 ㅅ ㅣ ㅂ ㅏ ㄹ, 개 새 끼야";
+		const matches = filter.findMatches(text);
+
+		expect(matches.map(({ word }) => word)).toEqual(["시발", "개새끼"]);
+		expect(text.slice(matches[0].start, matches[0].end)).toBe("ㅅ ㅣ ㅂ ㅏ ㄹ");
+		expect(filter.isMatch("ㅅ1발")).toBe(true);
+		expect(filter.isMatch("시계 발견")).toBe(false);
+		expect(moderationSkeleton("시 . 발")).toBe("ㅅㅣㅂㅏㄹ");
+	});
+});
+
+describe("confusable", () => {
+	// 모양이 비슷한 문자열을 같은 골격으로 보는지 테스트
+	it("should compare confusable skeletons", () => {
+		expect(confusableSkeleton("o一o")).toBe("ㅇㅡㅇ");
+		expect(confusableSkeleton("\u1112\u1161\u11AB글")).toBe("한글");
+		expect(isConfusable("HELLO", "hello")).toBe(true);
+		expect(isConfusable("ㅣㅣ", "l|")).toBe(true);
+		expect(isConfusable("한", "ㅎㅏㄴ")).toBe(false);
+	});
 });
@@ -1,12 +1,941 @@
-use hangul::Hangul;
+use js_sys::Array;
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen(js_name = Hangul)]
+pub struct WasmHangul {
+  hangul: hangul::Hangul,
+}
+
+#[wasm_bindgen(js_class = Hangul)]
+impl WasmHangul {
+  #[wasm_bindgen(constructor)]
+  pub fn new(text: &str) -> Self {
+    Self {
+      hangul: hangul::Hangul::new(text),
+    }
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn length(&self) -> usize {
+    self.hangul.len()
+  }
+
+  #[wasm_bindgen(getter)]
+  pub fn original(&self) -> String {
+    self.hangul.original().to_string()
+  }
+
+  pub fn letters(&self) -> Vec<Letter> {
+    self
+      .hangul
+      .iter()
+      .map(|unit| Letter::new(unit.as_str(), unit.hangul()))
+      .collect()
+  }
+
+  pub fn disassemble(&self) -> String {
+    self.hangul.disassemble()
+  }
+
+  #[wasm_bindgen(js_name = disassembleWith)]
+  pub fn disassemble_with(&self, options: &DisassembleOptions) -> Result<String, JsError> {
+    Ok(self.hangul.disassemble_with(&options.to_core()?))
+  }
+
+  // 글자마다 분해한 자모 배열 (`string[][]`)
+  #[wasm_bindgen(js_name = disassembleGrouped)]
+  pub fn disassemble_grouped(&self, options: &DisassembleOptions) -> Result<Array, JsError> {
+    Ok(
+      self
+        .hangul
+        .disassemble_grouped(&options.to_core()?)
+        .into_iter()
+        .map(|group| {
+          group
+            .into_iter()
+            .map(|ch| JsValue::from(String::from(ch)))
+            .collect::<Array>()
+        })
+        .collect(),
+    )
+  }
+
+  #[wasm_bindgen(js_name = getChoseong)]
+  pub fn get_choseong(&self) -> String {
+    self.hangul.get_choseong()
+  }
+
+  #[wasm_bindgen(js_name = getChoseongWith)]
+  pub fn get_choseong_with(&self, options: &ExtractOptions) -> Result<String, JsError> {
+    Ok(self.hangul.get_choseong_with(&options.to_core()?))
+  }
+
+  #[wasm_bindgen(js_name = getJungseong)]
+  pub fn get_jungseong(&self) -> String {
+    self.hangul.get_jungseong()
+  }
+
+  #[wasm_bindgen(js_name = getJungseongWith)]
+  pub fn get_jungseong_with(&self, options: &ExtractOptions) -> Result<String, JsError> {
+    Ok(self.hangul.get_jungseong_with(&options.to_core()?))
+  }
+
+  #[wasm_bindgen(js_name = getJongseong)]
+  pub fn get_jongseong(&self) -> String {
+    self.hangul.get_jongseong()
+  }
+
+  #[wasm_bindgen(js_name = getJongseongWith)]
+  pub fn get_jongseong_with(&self, options: &ExtractOptions) -> Result<String, JsError> {
+    Ok(self.hangul.get_jongseong_with(&options.to_core()?))
+  }
+
+  // `index`번째 음절의 초성을 바꾼 새 객체, 한글 음절이 아니면 `undefined`
+  #[wasm_bindgen(js_name = withChoseong)]
+  pub fn with_choseong(&self, index: usize, choseong: &str) -> Result<Option<WasmHangul>, JsError> {
+    let choseong = jamo("choseong", choseong)?;
+    Ok(Self::wrap(self.hangul.with_choseong(index, choseong)))
+  }
+
+  #[wasm_bindgen(js_name = withJungseong)]
+  pub fn with_jungseong(
+    &self,
+    index: usize,
+    jungseong: &str,
+  ) -> Result<Option<WasmHangul>, JsError> {
+    let jungseong = jamo("jungseong", jungseong)?;
+    Ok(Self::wrap(self.hangul.with_jungseong(index, jungseong)))
+  }
+
+  #[wasm_bindgen(js_name = withJongseong)]
+  pub fn with_jongseong(
+    &self,
+    index: usize,
+    jongseong: &str,
+  ) -> Result<Option<WasmHangul>, JsError> {
+    let jongseong = jamo("jongseong", jongseong)?;
+    Ok(Self::wrap(self.hangul.with_jongseong(index, jongseong)))
+  }
+
+  #[wasm_bindgen(js_name = removeJongseong)]
+  pub fn remove_jongseong(&self, index: usize) -> Option<WasmHangul> {
+    Self::wrap(self.hangul.remove_jongseong(index))
+  }
+
+  // 끝에서 자모 하나를 지운 새 객체 (닭 → 달 → 다 → ㄷ)
+  #[wasm_bindgen(js_name = removeLastJamo)]
+  pub fn remove_last_jamo(&self) -> WasmHangul {
+    Self {
+      hangul: self.hangul.remove_last_jamo(),
+    }
+  }
+
+  // 반각 자모(ﾡ)를 호환형 자모(ㄱ)로 바꾼 새 객체
+  #[wasm_bindgen(js_name = foldHalfwidth)]
+  pub fn fold_halfwidth(&self) -> WasmHangul {
+    Self {
+      hangul: self.hangul.fold_halfwidth(),
+    }
+  }
+
+  // 한자를 한글 독음으로 바꾼 새 객체 (大韓 → 대한)
+  #[wasm_bindgen(js_name = hanjaToHangul)]
+  pub fn hanja_to_hangul(&self) -> WasmHangul {
+    Self {
+      hangul: self.hangul.hanja_to_hangul(),
+    }
+  }
+
+  #[wasm_bindgen(js_name = toNfc)]
+  pub fn to_nfc(&self) -> String {
+    self.hangul.to_nfc()
+  }
+
+  #[wasm_bindgen(js_name = toNfd)]
+  pub fn to_nfd(&self) -> String {
+    self.hangul.to_nfd()
+  }
+}
+
+impl WasmHangul {
+  fn wrap(hangul: Option<hangul::Hangul>) -> Option<WasmHangul> {
+    hangul.map(|hangul| Self { hangul })
+  }
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct Letter {
+  pub original: String,
+  #[wasm_bindgen(js_name = isHangul)]
+  pub is_hangul: bool,
+  pub choseong: Option<String>,
+  pub jungseong: Option<String>,
+  pub jongseong: Option<String>,
+  #[wasm_bindgen(js_name = hasBatchim)]
+  pub has_batchim: bool,
+}
+
+impl Letter {
+  fn new(original: &str, letter: Option<hangul::HangulLetter>) -> Self {
+    Self {
+      original: original.to_string(),
+      is_hangul: letter.is_some(),
+      choseong: letter.map(|letter| letter.choseong.compatibility_value().to_string()),
+      jungseong: letter.map(|letter| letter.jungseong.compatibility_value().to_string()),
+      jongseong: letter
+        .and_then(|letter| letter.jongseong)
+        .map(|jongseong| jongseong.compatibility_value().to_string()),
+      has_batchim: letter.is_some_and(|letter| letter.has_batchim()),
+    }
+  }
+}
+
+// `Hangul.disassembleWith` 옵션, 생성 시 `Hangul.disassemble()`과 같은 기본값을 가짐
+#[wasm_bindgen(getter_with_clone)]
+pub struct DisassembleOptions {
+  // "compatibility" 또는 "conjoining"
+  #[wasm_bindgen(js_name = jamoForm)]
+  pub jamo_form: String,
+  #[wasm_bindgen(js_name = splitComplexJongseong)]
+  pub split_complex_jongseong: bool,
+  #[wasm_bindgen(js_name = splitComplexJungseong)]
+  pub split_complex_jungseong: bool,
+  pub separator: Option<String>,
+  // "keep", "remove" 또는 "replace"
+  #[wasm_bindgen(js_name = nonHangul)]
+  pub non_hangul: String,
+  // nonHangul이 "replace"일 때 넣을 한 글자
+  pub replacement: Option<String>,
+  // 괄호·원문자 한글(㉠, ㈎)을 음절처럼 분해
+  #[wasm_bindgen(js_name = expandEnclosed)]
+  pub expand_enclosed: bool,
+}
+
+#[wasm_bindgen]
+impl DisassembleOptions {
+  #[wasm_bindgen(constructor)]
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> Self {
+    Self {
+      jamo_form: "compatibility".to_string(),
+      split_complex_jongseong: true,
+      split_complex_jungseong: false,
+      separator: None,
+      non_hangul: "keep".to_string(),
+      replacement: None,
+      expand_enclosed: false,
+    }
+  }
+}
+
+impl DisassembleOptions {
+  fn to_core(&self) -> Result<hangul::DisassembleOptions, JsError> {
+    let jamo_form = jamo_form(&self.jamo_form)?;
+    let non_hangul = non_hangul(&self.non_hangul, self.replacement.as_deref())?;
+
+    let mut options = hangul::DisassembleOptions::new()
+      .jamo_form(jamo_form)
+      .split_complex_jongseong(self.split_complex_jongseong)
+      .split_complex_jungseong(self.split_complex_jungseong)
+      .non_hangul(non_hangul)
+      .expand_enclosed(self.expand_enclosed);
+
+    if let Some(separator) = &self.separator {
+      options = options.separator(separator.as_str());
+    }
+
+    Ok(options)
+  }
+}
+
+// `Hangul.getChoseongWith` 등의 옵션, 생성 시 `Hangul.getChoseong()`과 같은 기본값을 가짐
+#[wasm_bindgen(getter_with_clone)]
+pub struct ExtractOptions {
+  // "compatibility" 또는 "conjoining"
+  #[wasm_bindgen(js_name = jamoForm)]
+  pub jamo_form: String,
+  // "keep", "remove" 또는 "replace"
+  #[wasm_bindgen(js_name = nonHangul)]
+  pub non_hangul: String,
+  // nonHangul이 "replace"일 때 넣을 한 글자
+  pub replacement: Option<String>,
+  #[wasm_bindgen(js_name = keepWhitespace)]
+  pub keep_whitespace: bool,
+  #[wasm_bindgen(js_name = foldDoubleConsonants)]
+  pub fold_double_consonants: bool,
+  // 종성이 없는 음절 자리에 넣을 한 글자
+  #[wasm_bindgen(js_name = missingJongseong)]
+  pub missing_jongseong: Option<String>,
+}
+
+#[wasm_bindgen]
+impl ExtractOptions {
+  #[wasm_bindgen(constructor)]
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> Self {
+    Self {
+      jamo_form: "compatibility".to_string(),
+      non_hangul: "keep".to_string(),
+      replacement: None,
+      keep_whitespace: false,
+      fold_double_consonants: false,
+      missing_jongseong: None,
+    }
+  }
+}
+
+impl ExtractOptions {
+  fn to_core(&self) -> Result<hangul::ExtractOptions, JsError> {
+    let mut options = hangul::ExtractOptions::new()
+      .jamo_form(jamo_form(&self.jamo_form)?)
+      .non_hangul(non_hangul(&self.non_hangul, self.replacement.as_deref())?)
+      .keep_whitespace(self.keep_whitespace)
+      .fold_double_consonants(self.fold_double_consonants);
+
+    if let Some(placeholder) = &self.missing_jongseong {
+      options = options.missing_jongseong(single_char("missingJongseong", placeholder)?);
+    }
+
+    Ok(options)
+  }
+}
+
+fn jamo_form(value: &str) -> Result<hangul::JamoForm, JsError> {
+  match value {
+    "compatibility" => Ok(hangul::JamoForm::Compatibility),
+    "conjoining" => Ok(hangul::JamoForm::Conjoining),
+    value => Err(invalid_option("jamoForm", value)),
+  }
+}
+
+fn non_hangul(value: &str, replacement: Option<&str>) -> Result<hangul::NonHangul, JsError> {
+  match value {
+    "keep" => Ok(hangul::NonHangul::Keep),
+    "remove" => Ok(hangul::NonHangul::Remove),
+    "replace" => Ok(hangul::NonHangul::Replace(single_char(
+      "replacement",
+      replacement.unwrap_or_default(),
+    )?)),
+    value => Err(invalid_option("nonHangul", value)),
+  }
+}
+
+// "circled" 또는 "parenthesized"
+fn enclosed_style(value: &str) -> Result<hangul::EnclosedStyle, JsError> {
+  match value {
+    "circled" => Ok(hangul::EnclosedStyle::Circled),
+    "parenthesized" => Ok(hangul::EnclosedStyle::Parenthesized),
+    value => Err(invalid_option("style", value)),
+  }
+}
+
+// 활용 유형 이름
+const CONJUGATION_CLASSES: [(&str, hangul::ConjugationClass); 9] = [
+  ("regular", hangul::ConjugationClass::Regular),
+  ("bieupIrregular", hangul::ConjugationClass::BieupIrregular),
+  ("digeutIrregular", hangul::ConjugationClass::DigeutIrregular),
+  ("siotIrregular", hangul::ConjugationClass::SiotIrregular),
+  ("hieutIrregular", hangul::ConjugationClass::HieutIrregular),
+  ("reuIrregular", hangul::ConjugationClass::ReuIrregular),
+  ("reoIrregular", hangul::ConjugationClass::ReoIrregular),
+  ("uIrregular", hangul::ConjugationClass::UIrregular),
+  ("yeoIrregular", hangul::ConjugationClass::YeoIrregular),
+];
+
+// "regular", "bieupIrregular", "digeutIrregular" 등
+fn class_from_name(value: &str) -> Result<hangul::ConjugationClass, JsError> {
+  CONJUGATION_CLASSES
+    .iter()
+    .find(|&&(name, _)| name == value)
+    .map(|&(_, class)| class)
+    .ok_or_else(|| invalid_option("class", value))
+}
+
+fn class_name(class: hangul::ConjugationClass) -> &'static str {
+  CONJUGATION_CLASSES
+    .iter()
+    .find(|&&(_, candidate)| candidate == class)
+    .map_or("regular", |&(name, _)| name)
+}
+
+const SPEECH_LEVELS: [(&str, hangul::SpeechLevel); 3] = [
+  ("hae", hangul::SpeechLevel::Hae),
+  ("haeyo", hangul::SpeechLevel::Haeyo),
+  ("hapsyo", hangul::SpeechLevel::Hapsyo),
+];
+
+// "hae"(해체), "haeyo"(해요체), "hapsyo"(합쇼체)
+fn level_from_name(value: &str) -> Result<hangul::SpeechLevel, JsError> {
+  SPEECH_LEVELS
+    .iter()
+    .find(|&&(name, _)| name == value)
+    .map(|&(_, level)| level)
+    .ok_or_else(|| invalid_option("level", value))
+}
+
+fn level_name(level: hangul::SpeechLevel) -> &'static str {
+  SPEECH_LEVELS
+    .iter()
+    .find(|&&(_, candidate)| candidate == level)
+    .map_or("hae", |&(name, _)| name)
+}
+
+fn single_char(name: &str, value: &str) -> Result<char, JsError> {
+  let mut chars = value.chars();
+
+  match (chars.next(), chars.next()) {
+    (Some(ch), None) => Ok(ch),
+    _ => Err(invalid_option(name, value)),
+  }
+}
+
+// 자모 한 글자 문자열을 초성/중성/종성으로 변환
+fn jamo<T: TryFrom<char, Error = hangul::JamoError>>(
+  name: &str,
+  value: &str,
+) -> Result<T, JsError> {
+  Ok(T::try_from(single_char(name, value)?)?)
+}
+
+fn invalid_option(name: &str, value: &str) -> JsError {
+  JsError::new(&format!("Invalid value for {}: {}", name, value))
+}
+
 #[wasm_bindgen(js_name = disassemble)]
 pub fn disassemble(text: &str) -> String {
-  Hangul::new(text).disassemble()
+  hangul::Hangul::new(text).disassemble()
 }
 
 #[wasm_bindgen(js_name = getChoseong)]
 pub fn get_choseong(text: &str) -> String {
-  Hangul::new(text).get_choseong()
+  hangul::Hangul::new(text).get_choseong()
+}
+
+#[wasm_bindgen(js_name = toNfc)]
+pub fn to_nfc(text: &str) -> String {
+  hangul::Hangul::new(text).to_nfc()
+}
+
+#[wasm_bindgen(js_name = toNfd)]
+pub fn to_nfd(text: &str) -> String {
+  hangul::Hangul::new(text).to_nfd()
+}
+
+#[wasm_bindgen(js_name = assemble)]
+pub fn assemble(text: &str) -> String {
+  hangul::assemble(text)
+}
+
+#[wasm_bindgen(js_name = romanize)]
+pub fn romanize(text: &str) -> String {
+  hangul::romanize(text)
+}
+
+// 한 글자(NFC 또는 NFD)를 파싱, 한글 음절이 아니면 `undefined`
+#[wasm_bindgen(js_name = parseLetter)]
+pub fn parse_letter(text: &str) -> Option<Letter> {
+  hangul::HangulLetter::parse(text).map(|letter| Letter::new(text, Some(letter)))
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct JamoInfo {
+  // "conjoining", "compatibility", "halfwidth", "extendedA", "extendedB", 자모 블록이 아니면 `undefined`
+  pub block: Option<String>,
+  #[wasm_bindgen(js_name = isConsonant)]
+  pub is_consonant: bool,
+  #[wasm_bindgen(js_name = isVowel)]
+  pub is_vowel: bool,
+  #[wasm_bindgen(js_name = canBeChoseong)]
+  pub can_be_choseong: bool,
+  #[wasm_bindgen(js_name = canBeJongseong)]
+  pub can_be_jongseong: bool,
+  #[wasm_bindgen(js_name = isDoubleConsonant)]
+  pub is_double_consonant: bool,
+  #[wasm_bindgen(js_name = isComplexJongseong)]
+  pub is_complex_jongseong: bool,
+  #[wasm_bindgen(js_name = isComplexVowel)]
+  pub is_complex_vowel: bool,
+}
+
+// 한 글자의 자모 분류
+#[wasm_bindgen(js_name = classifyJamo)]
+pub fn classify_jamo(ch: &str) -> Result<JamoInfo, JsError> {
+  let ch = single_char("ch", ch)?;
+
+  Ok(JamoInfo {
+    block: hangul::jamo_block(ch).map(|block| {
+      match block {
+        hangul::JamoBlock::Conjoining => "conjoining",
+        hangul::JamoBlock::Compatibility => "compatibility",
+        hangul::JamoBlock::Halfwidth => "halfwidth",
+        hangul::JamoBlock::ExtendedA => "extendedA",
+        hangul::JamoBlock::ExtendedB => "extendedB",
+      }
+      .to_string()
+    }),
+    is_consonant: hangul::is_consonant(ch),
+    is_vowel: hangul::is_vowel(ch),
+    can_be_choseong: hangul::can_be_choseong(ch),
+    can_be_jongseong: hangul::can_be_jongseong(ch),
+    is_double_consonant: hangul::is_double_consonant(ch),
+    is_complex_jongseong: hangul::is_complex_jongseong(ch),
+    is_complex_vowel: hangul::is_complex_vowel(ch),
+  })
+}
+
+// 혼동 문자 골격, 같으면 눈으로 구별하기 어려운 문자열 ("o一o" → "ㅇㅡㅇ")
+#[wasm_bindgen(js_name = confusableSkeleton)]
+pub fn confusable_skeleton(text: &str) -> String {
+  hangul::confusable_skeleton(text)
+}
+
+// 두 문자열의 혼동 문자 골격이 같은지 확인 ("ㅇㅡㅇ", "o一o" → true)
+#[wasm_bindgen(js_name = isConfusable)]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+  hangul::is_confusable(a, b)
+}
+
+// 기본형에 어미를 붙인 활용형 ("먹다", "-아/어요" → "먹어요", "돕다", "-(으)면" → "도우면")
+#[wasm_bindgen(js_name = conjugate)]
+pub fn conjugate(predicate: &str, ending: &str) -> Result<String, JsError> {
+  Ok(hangul::conjugate(predicate, ending)?)
+}
+
+// 활용 유형을 지정해 활용, className은 "regular", "bieupIrregular" 등 (묻다를 "regular"로: 묻어요)
+#[wasm_bindgen(js_name = conjugateAs)]
+pub fn conjugate_as(predicate: &str, ending: &str, class_name: &str) -> Result<String, JsError> {
+  let class = class_from_name(class_name)?;
+  Ok(hangul::conjugate_as(predicate, ending, class)?)
+}
+
+// 기본형의 활용 유형 ("돕다" → "bieupIrregular")
+#[wasm_bindgen(js_name = conjugationClass)]
+pub fn conjugation_class(predicate: &str) -> Result<String, JsError> {
+  Ok(class_name(hangul::conjugation_class(predicate)?).to_string())
+}
+
+// 활용형에서 찾은 기본형과 떼어 낸 어미
+#[wasm_bindgen(getter_with_clone)]
+pub struct Lemma {
+  pub lemma: String,
+  pub ending: String,
+}
+
+impl From<hangul::Lemma> for Lemma {
+  fn from(lemma: hangul::Lemma) -> Self {
+    Self {
+      lemma: lemma.lemma,
+      ending: lemma.ending,
+    }
+  }
+}
+
+// 규칙 기반 기본형 추출기, 어미나 기본형 사전을 추가한 새 객체를 반환
+#[wasm_bindgen(js_name = Lemmatizer)]
+pub struct WasmLemmatizer {
+  lemmatizer: hangul::Lemmatizer,
+}
+
+#[wasm_bindgen(js_class = Lemmatizer)]
+impl WasmLemmatizer {
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> Self {
+    Self {
+      lemmatizer: hangul::Lemmatizer::new(),
+    }
+  }
+
+  // 어미 추가 ("-더라고요")
+  pub fn ending(&self, ending: &str) -> WasmLemmatizer {
+    Self {
+      lemmatizer: self.lemmatizer.clone().ending(ending),
+    }
+  }
+
+  // 기본형 사전에 추가, 사전이 비어 있지 않으면 사전에 있는 기본형만 반환
+  pub fn lemma(&self, lemma: &str) -> WasmLemmatizer {
+    Self {
+      lemmatizer: self.lemmatizer.clone().lemma(lemma),
+    }
+  }
+
+  pub fn lemmas(&self, lemmas: Vec<String>) -> WasmLemmatizer {
+    Self {
+      lemmatizer: self
+        .lemmatizer
+        .clone()
+        .lemmas(lemmas.iter().map(String::as_str)),
+    }
+  }
+
+  pub fn lemmatize(&self, word: &str) -> Vec<Lemma> {
+    self
+      .lemmatizer
+      .lemmatize(word)
+      .into_iter()
+      .map(Lemma::from)
+      .collect()
+  }
+}
+
+impl Default for WasmLemmatizer {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// 블록리스트 단어가 나타난 원본 문자열의 범위, `text.slice(start, end)`와 같은 UTF-16 인덱스
+#[wasm_bindgen(getter_with_clone)]
+pub struct ModerationMatch {
+  pub word: String,
+  pub start: usize,
+  pub end: usize,
+}
+
+// 자모 분리, 띄어쓰기·기호 삽입, 비슷한 문자 치환을 무시하고 블록리스트 단어를 찾음
+#[wasm_bindgen(js_name = ModerationFilter)]
+pub struct WasmModerationFilter {
+  filter: hangul::ModerationFilter,
+}
+
+#[wasm_bindgen(js_class = ModerationFilter)]
+impl WasmModerationFilter {
+  #[wasm_bindgen(constructor)]
+  pub fn new(blocklist: Vec<String>) -> Self {
+    Self {
+      filter: hangul::ModerationFilter::new(blocklist.iter().map(String::as_str)),
+    }
+  }
+
+  #[wasm_bindgen(js_name = findMatches)]
+  pub fn find_matches(&self, text: &str) -> Vec<ModerationMatch> {
+    let utf16_offset = |byte: usize| text[..byte].encode_utf16().count();
+
+    self
+      .filter
+      .find_matches(text)
+      .into_iter()
+      .map(|found| ModerationMatch {
+        start: utf16_offset(found.range.start),
+        end: utf16_offset(found.range.end),
+        word: found.word,
+      })
+      .collect()
+  }
+
+  #[wasm_bindgen(js_name = isMatch)]
+  pub fn is_match(&self, text: &str) -> bool {
+    self.filter.is_match(text)
+  }
+}
+
+// 비교용 자모 문자열 ("시 발" → "ㅅㅣㅂㅏㄹ")
+#[wasm_bindgen(js_name = moderationSkeleton)]
+pub fn moderation_skeleton(text: &str) -> String {
+  hangul::moderation_skeleton(text)
+}
+
+// 활용형의 기본형 후보, 그럴듯한 것부터 ("들었어" → 들다, 듣다)
+#[wasm_bindgen(js_name = lemmatize)]
+pub fn lemmatize(word: &str) -> Vec<Lemma> {
+  hangul::lemmatize(word)
+    .into_iter()
+    .map(Lemma::from)
+    .collect()
+}
+
+// 문장 끝 용언의 등급, "hae", "haeyo", "hapsyo" 중 하나이고 알 수 없으면 undefined
+#[wasm_bindgen(js_name = speechLevel)]
+pub fn speech_level(sentence: &str) -> Option<String> {
+  hangul::speech_level(sentence).map(|level| level_name(level).to_string())
+}
+
+// 문장 끝 용언을 주어진 등급으로 바꿈 ("먹어요", "hapsyo" → "먹습니다"), 용언을 알 수 없으면 undefined
+#[wasm_bindgen(js_name = toSpeechLevel)]
+pub fn to_speech_level(sentence: &str, level: &str) -> Result<Option<String>, JsError> {
+  Ok(hangul::to_speech_level(sentence, level_from_name(level)?))
+}
+
+// 등급은 유지하고 주체 높임 -(으)시-를 넣음 ("먹어요" → "먹으세요")
+#[wasm_bindgen(js_name = toHonorific)]
+pub fn to_honorific(sentence: &str) -> Option<String> {
+  hangul::to_honorific(sentence)
+}
+
+// 단어 첫 음절에 두음법칙을 적용 (녀자 → 여자, 리설주 → 이설주)
+#[wasm_bindgen(js_name = applyDueum)]
+pub fn apply_dueum(word: &str) -> String {
+  hangul::apply_dueum(word)
+}
+
+// 두음법칙을 적용하면 `word`가 되는 본래 단어 목록 (이설주 → ["리설주", "니설주"])
+#[wasm_bindgen(js_name = dueumOriginals)]
+pub fn dueum_originals(word: &str) -> Vec<String> {
+  hangul::dueum_originals(word)
+}
+
+// 괄호·원문자 한글이 나타내는 자모나 음절 (㉠ → "ㄱ", ㈎ → "가"), 아니면 undefined
+#[wasm_bindgen(js_name = expandEnclosed)]
+pub fn expand_enclosed(ch: &str) -> Result<Option<String>, JsError> {
+  let ch = single_char("ch", ch)?;
+  Ok(hangul::expand_enclosed(ch).map(str::to_string))
+}
+
+// 자모나 음절을 괄호·원문자로 바꿈, style은 "circled" 또는 "parenthesized"
+#[wasm_bindgen(js_name = toEnclosed)]
+pub fn to_enclosed(text: &str, style: &str) -> Result<Option<String>, JsError> {
+  let style = enclosed_style(style)?;
+  Ok(hangul::to_enclosed(text, style).map(String::from))
+}
+
+// 목록 번호용 괄호·원문자 자음, index는 0부터 (0 → ㉠)
+#[wasm_bindgen(js_name = enclosedJamo)]
+pub fn enclosed_jamo(index: usize, style: &str) -> Result<Option<String>, JsError> {
+  let style = enclosed_style(style)?;
+  Ok(hangul::enclosed_jamo(index, style).map(String::from))
+}
+
+// 목록 번호용 괄호·원문자 음절, index는 0부터 (0 → ㉮)
+#[wasm_bindgen(js_name = enclosedSyllable)]
+pub fn enclosed_syllable(index: usize, style: &str) -> Result<Option<String>, JsError> {
+  let style = enclosed_style(style)?;
+  Ok(hangul::enclosed_syllable(index, style).map(String::from))
+}
+
+// KS X 1026-1에 따라 불완전한 조합형 음절에 채움 문자를 넣음
+#[wasm_bindgen(js_name = fillIncompleteSyllables)]
+pub fn fill_incomplete_syllables(text: &str) -> String {
+  hangul::fill_incomplete_syllables(text)
+}
+
+// 문자열의 반각 자모를 호환형 자모로 바꿈 (NFKC와 같은 결과)
+#[wasm_bindgen(js_name = foldHalfwidth)]
+pub fn fold_halfwidth(text: &str) -> String {
+  hangul::fold_halfwidth(text)
+}
+
+// 한자의 본음 (李 → "리"), 독음표에 없는 한자이면 undefined
+#[wasm_bindgen(js_name = hanjaReading)]
+pub fn hanja_reading(ch: &str) -> Result<Option<String>, JsError> {
+  let ch = single_char("ch", ch)?;
+  Ok(hangul::hanja_reading(ch).map(String::from))
+}
+
+// 한자를 두음법칙에 따라 한글 독음으로 바꿈 (李舜臣 → 이순신)
+#[wasm_bindgen(js_name = hanjaToHangul)]
+pub fn hanja_to_hangul(text: &str) -> String {
+  hangul::hanja_to_hangul(text)
+}
+
+// 조합형 또는 호환형 자모로 완성형 음절을 만듦 ("ㅎ", "ㅏ", "ㄴ" → "한")
+#[wasm_bindgen(js_name = fromJamo)]
+pub fn from_jamo(
+  choseong: &str,
+  jungseong: &str,
+  jongseong: Option<String>,
+) -> Result<String, JsError> {
+  let letter = hangul::HangulLetter::from_jamo(
+    single_char("choseong", choseong)?,
+    single_char("jungseong", jungseong)?,
+    jongseong
+      .map(|jongseong| single_char("jongseong", &jongseong))
+      .transpose()?,
+  )?;
+
+  Ok(letter.value().to_string())
+}
+
+// (초성 0–18, 중성 0–20, 종성 0–27) 인덱스로 완성형 음절을 만듦, 종성 0은 종성 없음
+#[wasm_bindgen(js_name = fromIndices)]
+pub fn from_indices(choseong: u8, jungseong: u8, jongseong: u8) -> Result<String, JsError> {
+  Ok(
+    hangul::HangulLetter::from_indices(choseong, jungseong, jongseong)?
+      .value()
+      .to_string(),
+  )
+}
+
+// 마지막 글자가 받침이 있는 한글 음절인지 확인
+#[wasm_bindgen(js_name = hasBatchim)]
+pub fn has_batchim(text: &str) -> bool {
+  hangul::Hangul::new(text)
+    .iter()
+    .next_back()
+    .and_then(|unit| unit.hangul())
+    .is_some_and(|letter| letter.has_batchim())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_hangul_class() {
+    let hangul = WasmHangul::new("닭 a");
+    assert_eq!(hangul.length(), 3);
+    assert_eq!(hangul.original(), "닭 a");
+    assert_eq!(hangul.disassemble(), "ㄷㅏㄹㄱ a");
+    assert_eq!(hangul.get_choseong(), "ㄷ a");
+
+    let letters = hangul.letters();
+    assert_eq!(letters[0].jongseong.as_deref(), Some("ㄺ"));
+    assert!(letters[0].has_batchim);
+    assert!(!letters[2].is_hangul);
+  }
+
+  #[test]
+  fn test_disassemble_with() {
+    let hangul = WasmHangul::new("닭과 ok");
+    let mut options = DisassembleOptions::new();
+    assert_eq!(
+      hangul.disassemble_with(&options).ok(),
+      Some(hangul.disassemble())
+    );
+
+    options.split_complex_jungseong = true;
+    options.separator = Some("/".to_string());
+    options.non_hangul = "remove".to_string();
+    assert_eq!(
+      hangul.disassemble_with(&options).ok().as_deref(),
+      Some("ㄷㅏㄹㄱ/ㄱㅗㅏ")
+    );
+  }
+
+  #[test]
+  fn test_extract_with_options() {
+    let hangul = WasmHangul::new("까치, 닭!");
+    let mut options = ExtractOptions::new();
+    assert_eq!(
+      hangul.get_choseong_with(&options).ok(),
+      Some(hangul.get_choseong())
+    );
+    assert_eq!(hangul.get_jungseong(), "ㅏㅣ, ㅏ!");
+    assert_eq!(hangul.get_jongseong(), ", ㄺ!");
+
+    options.non_hangul = "remove".to_string();
+    options.keep_whitespace = true;
+    options.fold_double_consonants = true;
+    options.missing_jongseong = Some("_".to_string());
+    assert_eq!(
+      hangul.get_choseong_with(&options).ok().as_deref(),
+      Some("ㄱㅊ ㄷ")
+    );
+    assert_eq!(
+      hangul.get_jongseong_with(&options).ok().as_deref(),
+      Some("__ ㄺ")
+    );
+  }
+
+  #[test]
+  fn test_edit_components() {
+    let hangul = WasmHangul::new("한글");
+    let edited = hangul.with_choseong(0, "ㄱ").ok().flatten().unwrap();
+    assert_eq!(edited.original(), "간글");
+    assert_eq!(hangul.remove_jongseong(0).unwrap().original(), "하글");
+    assert!(hangul.remove_jongseong(2).is_none());
+    assert_eq!(hangul.remove_last_jamo().original(), "한그");
+  }
+
+  #[test]
+  fn test_free_functions() {
+    assert_eq!(to_nfc("\u{1112}\u{1161}\u{11AB}"), "한");
+    assert_eq!(to_nfd("한"), "\u{1112}\u{1161}\u{11AB}");
+    assert_eq!(assemble("ㅎㅏㄴㄱㅡㄹ"), "한글");
+    assert_eq!(romanize("한글"), "hangeul");
+
+    assert_eq!(
+      parse_letter("각").and_then(|letter| letter.choseong),
+      Some("ㄱ".to_string())
+    );
+    assert!(parse_letter("a").is_none());
+
+    assert_eq!(
+      from_jamo("ㄷ", "ㅏ", Some("ㄺ".to_string()))
+        .ok()
+        .as_deref(),
+      Some("닭")
+    );
+    assert_eq!(from_indices(18, 20, 27).ok().as_deref(), Some("힣"));
+
+    let info = classify_jamo("ㄸ").ok().unwrap();
+    assert_eq!(info.block.as_deref(), Some("compatibility"));
+    assert!(info.can_be_choseong && !info.can_be_jongseong);
+
+    assert_eq!(get_choseong("말\u{110A}\u{119E}미"), "ㅁㅆㅁ");
+    assert_eq!(fill_incomplete_syllables("\u{1161}"), "\u{115F}\u{1161}");
+    assert_eq!(fold_halfwidth("\u{FFA1}\u{FFC2}"), "ㄱㅏ");
+    assert_eq!(hanja_reading("女").ok().flatten().as_deref(), Some("녀"));
+    assert_eq!(hanja_to_hangul("女子"), "여자");
+    assert_eq!(apply_dueum("로인"), "노인");
+    assert_eq!(dueum_originals("노인"), ["로인"]);
+    assert_eq!(expand_enclosed("㈎").ok().flatten().as_deref(), Some("가"));
+    assert_eq!(
+      to_enclosed("ㄱ", "circled").ok().flatten().as_deref(),
+      Some("㉠")
+    );
+    assert_eq!(
+      enclosed_syllable(1, "parenthesized")
+        .ok()
+        .flatten()
+        .as_deref(),
+      Some("㈏")
+    );
+
+    assert_eq!(
+      conjugate("돕다", "-아/어요").ok().as_deref(),
+      Some("도와요")
+    );
+    assert_eq!(
+      conjugate_as("묻다", "-아/어요", "regular").ok().as_deref(),
+      Some("묻어요")
+    );
+    assert_eq!(
+      conjugation_class("부르다").ok().as_deref(),
+      Some("reuIrregular")
+    );
+
+    assert_eq!(
+      to_speech_level("먹어요", "hapsyo")
+        .ok()
+        .flatten()
+        .as_deref(),
+      Some("먹습니다")
+    );
+    assert_eq!(to_honorific("해요").as_deref(), Some("하세요"));
+    assert_eq!(speech_level("학생이에요").as_deref(), Some("haeyo"));
+
+    let lemmas = |lemmas: Vec<Lemma>| {
+      lemmas
+        .into_iter()
+        .map(|lemma| lemma.lemma)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(lemmas(lemmatize("들었어")), ["들다", "듣다"]);
+    assert_eq!(
+      lemmas(WasmLemmatizer::new().lemma("듣다").lemmatize("들었어")),
+      ["듣다"]
+    );
+    assert_eq!(
+      lemmas(
+        WasmLemmatizer::new()
+          .ending("-더라고요")
+          .lemmatize("먹더라고요")
+      ),
+      ["먹다"]
+    );
+
+    let filter = WasmModerationFilter::new(vec!["시발".to_string()]);
+    let matches = filter.find_matches("야 ㅅ ㅣ 발");
+    assert_eq!((matches[0].start, matches[0].end), (2, 7));
+    assert!(filter.is_match("ㅅ1발"));
+    assert_eq!(moderation_skeleton("시 . 발"), "ㅅㅣㅂㅏㄹ");
+
+    assert_eq!(confusable_skeleton("o一o"), "ㅇㅡㅇ");
+    assert!(is_confusable("ㅣㅣ", "l|"));
+    assert!(!is_confusable("한", "ㅎㅏㄴ"));
+
+    assert!(has_batchim("사람"));
+    assert!(!has_batchim("사과"));
+    assert!(!has_batchim(""));
+  }
 }